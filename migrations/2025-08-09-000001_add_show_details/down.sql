@@ -0,0 +1,4 @@
+-- Rollback Migration 6: Remove show details
+
+ALTER TABLE shows DROP COLUMN show_day;
+ALTER TABLE shows DROP COLUMN venue;
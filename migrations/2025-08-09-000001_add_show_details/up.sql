@@ -0,0 +1,5 @@
+-- Migration 6: Add show details
+-- Adds optional venue and broadcast day to shows
+
+ALTER TABLE shows ADD COLUMN venue TEXT NULL;
+ALTER TABLE shows ADD COLUMN show_day TEXT NULL;
@@ -0,0 +1,3 @@
+-- Rollback Migration 7: Remove signature move activity flag
+
+ALTER TABLE signature_moves DROP COLUMN is_active;
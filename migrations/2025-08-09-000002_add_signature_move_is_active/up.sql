@@ -0,0 +1,4 @@
+-- Migration 7: Soft-retire signature moves
+-- Existing moves default to active
+
+ALTER TABLE signature_moves ADD COLUMN is_active BOOLEAN NOT NULL DEFAULT TRUE;
@@ -0,0 +1,3 @@
+-- Rollback Migration 8: Remove participant roles
+
+ALTER TABLE match_participants DROP COLUMN role;
@@ -0,0 +1,5 @@
+-- Migration 8: Add participant roles
+-- Separates wrestlers competing in a match from those involved around it
+
+ALTER TABLE match_participants ADD COLUMN role TEXT NOT NULL DEFAULT 'Competitor'
+    CHECK (role IN ('Competitor', 'Interference', 'Manager', 'Referee'));
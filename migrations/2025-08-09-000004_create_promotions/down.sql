@@ -0,0 +1,6 @@
+-- Rollback Migration 9: Drop promotions
+
+DROP INDEX IF EXISTS idx_shows_promotion_id;
+ALTER TABLE shows DROP COLUMN promotion_id;
+DROP TRIGGER IF EXISTS update_promotions_updated_at;
+DROP TABLE IF EXISTS promotions;
@@ -0,0 +1,23 @@
+-- Migration 9: Create promotions
+-- Promotions group shows under a single company (e.g., WWE owning RAW and SmackDown)
+
+CREATE TABLE promotions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT NOT NULL UNIQUE,
+    description TEXT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Add trigger to automatically update updated_at timestamp
+CREATE TRIGGER update_promotions_updated_at 
+    AFTER UPDATE ON promotions
+    FOR EACH ROW
+    BEGIN
+        UPDATE promotions SET updated_at = CURRENT_TIMESTAMP WHERE id = NEW.id;
+    END;
+
+-- Shows optionally belong to a promotion; deleting the promotion leaves them unassigned
+ALTER TABLE shows ADD COLUMN promotion_id INTEGER NULL REFERENCES promotions(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_shows_promotion_id ON shows(promotion_id);
@@ -0,0 +1,3 @@
+-- Rollback Migration 10: Remove multi-match flag from shows
+
+ALTER TABLE shows DROP COLUMN allow_multi_match;
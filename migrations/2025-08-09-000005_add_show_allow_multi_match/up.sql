@@ -0,0 +1,4 @@
+-- Migration 10: Allow multi-match bookings per show
+-- When set, a wrestler may compete in more than one match on the same card
+
+ALTER TABLE shows ADD COLUMN allow_multi_match BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,3 @@
+-- Rollback Migration 11: Remove wrestler status
+
+ALTER TABLE wrestlers DROP COLUMN status;
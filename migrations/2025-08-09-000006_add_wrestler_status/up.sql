@@ -0,0 +1,5 @@
+-- Migration 11: Add wrestler status
+-- Tracks whether a wrestler is available, injured or retired; existing wrestlers are active
+
+ALTER TABLE wrestlers ADD COLUMN status TEXT NOT NULL DEFAULT 'Active'
+    CHECK (status IN ('Active', 'Injured', 'Retired'));
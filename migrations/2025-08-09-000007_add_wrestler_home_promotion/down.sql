@@ -0,0 +1,4 @@
+-- Rollback Migration 12: Remove wrestler home promotion
+
+DROP INDEX IF EXISTS idx_wrestlers_home_promotion_id;
+ALTER TABLE wrestlers DROP COLUMN home_promotion_id;
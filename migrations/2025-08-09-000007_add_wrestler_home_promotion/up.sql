@@ -0,0 +1,6 @@
+-- Migration 12: Add wrestler home promotion
+-- A nominal home brand for display and filtering; show roster assignment is unaffected
+
+ALTER TABLE wrestlers ADD COLUMN home_promotion_id INTEGER NULL REFERENCES promotions(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_wrestlers_home_promotion_id ON wrestlers(home_promotion_id);
@@ -0,0 +1,3 @@
+-- Rollback Migration 13: Remove title display order
+
+ALTER TABLE titles DROP COLUMN display_order;
@@ -0,0 +1,8 @@
+-- Migration 13: Add title display order
+-- Custom belt order within a prestige tier; existing titles keep their alphabetical order
+
+ALTER TABLE titles ADD COLUMN display_order INTEGER NOT NULL DEFAULT 0;
+
+UPDATE titles SET display_order = (
+    SELECT COUNT(*) FROM titles AS earlier WHERE earlier.name < titles.name
+);
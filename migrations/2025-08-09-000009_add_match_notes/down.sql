@@ -0,0 +1,3 @@
+-- Rollback Migration 14: Remove match notes
+
+ALTER TABLE matches DROP COLUMN notes;
@@ -0,0 +1,4 @@
+-- Migration 14: Add match notes
+-- Free-form storyline notes bookers can attach to a match
+
+ALTER TABLE matches ADD COLUMN notes TEXT NULL;
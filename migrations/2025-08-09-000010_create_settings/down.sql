@@ -0,0 +1,3 @@
+-- Rollback Migration 15: Drop settings
+
+DROP TABLE settings;
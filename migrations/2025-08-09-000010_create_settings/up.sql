@@ -0,0 +1,7 @@
+-- Migration 15: Create settings
+-- Key/value store for app preferences such as the roster assignment mode
+
+CREATE TABLE settings (
+    key TEXT PRIMARY KEY NOT NULL,
+    value TEXT NOT NULL
+);
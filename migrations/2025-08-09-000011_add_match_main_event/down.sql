@@ -0,0 +1,3 @@
+-- Rollback Migration 16: Remove main event flag from matches
+
+ALTER TABLE matches DROP COLUMN is_main_event;
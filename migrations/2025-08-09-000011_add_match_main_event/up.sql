@@ -0,0 +1,4 @@
+-- Migration 16: Add main event flag to matches
+-- Bookers mark one match per show as the main event
+
+ALTER TABLE matches ADD COLUMN is_main_event BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,4 @@
+-- Rollback Migration 17: Remove show metrics
+
+ALTER TABLE shows DROP COLUMN show_rating;
+ALTER TABLE shows DROP COLUMN attendance;
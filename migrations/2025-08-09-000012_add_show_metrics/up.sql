@@ -0,0 +1,5 @@
+-- Migration 17: Add show metrics
+-- Optional attendance and a 0-5 rating for show analytics
+
+ALTER TABLE shows ADD COLUMN attendance INTEGER NULL;
+ALTER TABLE shows ADD COLUMN show_rating REAL NULL;
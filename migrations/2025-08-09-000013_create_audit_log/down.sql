@@ -0,0 +1,4 @@
+-- Rollback Migration 18: Drop audit log
+
+DROP INDEX IF EXISTS idx_audit_log_timestamp;
+DROP TABLE audit_log;
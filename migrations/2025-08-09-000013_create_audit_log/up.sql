@@ -0,0 +1,13 @@
+-- Migration 18: Create audit log
+-- Best-effort record of key mutations such as title changes and roster moves
+
+CREATE TABLE audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    timestamp TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    operation TEXT NOT NULL,
+    entity TEXT NOT NULL,
+    entity_id INTEGER,
+    details TEXT
+);
+
+CREATE INDEX idx_audit_log_timestamp ON audit_log(timestamp);
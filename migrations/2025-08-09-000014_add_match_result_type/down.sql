@@ -0,0 +1,3 @@
+-- Rollback Migration 19: Remove match result type
+
+ALTER TABLE matches DROP COLUMN result_type;
@@ -0,0 +1,7 @@
+-- Migration 19: Add match result type
+-- Distinguishes a decided match from a no-contest or draw; NULL means the match hasn't concluded
+
+ALTER TABLE matches ADD COLUMN result_type TEXT NULL
+    CHECK (result_type IN ('Win', 'NoContest', 'Draw'));
+
+UPDATE matches SET result_type = 'Win' WHERE winner_id IS NOT NULL;
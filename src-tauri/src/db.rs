@@ -1,14 +1,27 @@
+use crate::error::AppError;
+use crate::types::{normalize_title_gender, normalize_wrestler_gender};
 use crate::models::{
-    Match, MatchData, NewMatch, MatchParticipant, NewMatchParticipant,
-    NewShowRoster, NewShow, NewSignatureMove, NewTitle, NewTitleHolder, NewUser, NewWrestler, NewEnhancedWrestler, ShowRoster, Show, ShowData, SignatureMove, Title, TitleData, TitleHolder, TitleWithHolders, TitleHolderInfo, User, UserData,
-    Wrestler, WrestlerData, EnhancedWrestlerData,
+    parse_height_inches, Accolades, CareerReign, ChampionEntry, Comparison, ConsistencyIssue,
+    DeletionImpact, EnhancedWrestlerData, ExperienceExtremes, FreeAgent, Match, MatchData,
+    MatchParticipant, MatchSummary, MoveType, NewEnhancedWrestler, NewMatch, NewMatchParticipant,
+    NewOperationLog, NewPromotion, NewShow, NewShowRoster, NewSignatureMove, NewTitle,
+    NewTitleHolder, NewUser, NewWrestler, OperationLog, PastReign, Promotion, PromotionOverview,
+    RankPosition, RatingAverages, RatingDeltas, RecentMatch, RosterBalance, RosterMember, Setting,
+    Show, ShowData, ShowRoster, SignatureMove, TimelineSegment, Title, TitleData, TitleHolder,
+    TitleHolderInfo, TitleStats, TitleWithHolders, UniverseValidation, User, UserData,
+    VacancyEvent, WinnerSuggestion, Wrestler, WrestlerData, ACTIVE_STATUS, COMPETITOR_ROLE,
+    INJURED_STATUS, MATCH_RESULT_TYPES, PARTICIPANT_ROLES, RATING_FIELDS, RETIRED_STATUS,
+    ROSTER_MODES, ROSTER_MODE_EXCLUSIVE, ROSTER_MODE_KEY, ROSTER_MODE_MULTI, WIN_RESULT,
+    WRESTLER_STATUSES,
 };
+use diesel::connection::SimpleConnection;
 use diesel::prelude::*;
 use diesel::r2d2::{self, ConnectionManager};
 use diesel::result::Error as DieselError;
 use dotenvy::dotenv;
-use log::{error, info};
+use log::{error, info, warn};
 use std::env;
+use std::time::Duration;
 use tauri::State;
 use chrono::Utc;
 
@@ -24,6 +37,77 @@ pub struct DbState {
     pub pool: Pool,
 }
 
+/// Default maximum number of pooled connections (override with `DB_POOL_MAX_SIZE`)
+const DEFAULT_POOL_MAX_SIZE: u32 = 10;
+
+/// Default seconds to wait for a free connection (override with `DB_POOL_TIMEOUT_SECS`)
+const DEFAULT_POOL_TIMEOUT_SECS: u64 = 30;
+
+/// Default milliseconds SQLite retries a locked database (override with `DB_BUSY_TIMEOUT_MS`)
+const DEFAULT_BUSY_TIMEOUT_MS: u32 = 5000;
+
+/// Connection customizer that applies SQLite pragmas to every new pooled connection
+/// 
+/// SQLite disables foreign key enforcement per connection by default, so the
+/// `ON DELETE CASCADE` / `SET NULL` rules in the migrations only fire when
+/// `PRAGMA foreign_keys = ON` has been run on that connection.
+/// 
+/// WAL journaling lets readers continue while another connection writes, and
+/// the busy timeout makes a writer wait for the lock instead of failing
+/// immediately with "database is locked".
+#[derive(Debug)]
+pub struct SqliteConnectionCustomizer {
+    /// Milliseconds to wait on a locked database before giving up
+    pub busy_timeout_ms: u32,
+}
+
+impl r2d2::CustomizeConnection<SqliteConnection, r2d2::Error> for SqliteConnectionCustomizer {
+    fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<(), r2d2::Error> {
+        conn.batch_execute(&format!(
+            "PRAGMA busy_timeout = {}; PRAGMA journal_mode = WAL; PRAGMA foreign_keys = ON;",
+            self.busy_timeout_ms
+        ))
+        .map_err(r2d2::Error::QueryError)
+    }
+}
+
+/// Reads a numeric setting from the environment, falling back to a default
+/// 
+/// # Arguments
+/// * `key` - Environment variable name
+/// * `default` - Value used when the variable is missing or not a valid number
+fn env_setting<T: std::str::FromStr>(key: &str, default: T) -> T {
+    env::var(key)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(default)
+}
+
+/// Creates a configured connection pool for the given SQLite database
+/// 
+/// # Arguments
+/// * `database_url` - Path or URL of the SQLite database
+/// 
+/// # Returns
+/// * `Ok(Pool)` - Pool sized from `DB_POOL_MAX_SIZE` with a `DB_POOL_TIMEOUT_SECS` checkout timeout
+/// * `Err(r2d2::PoolError)` - If the initial connections cannot be established
+/// 
+/// # Note
+/// Every connection has foreign key enforcement and WAL journaling enabled,
+/// with a `DB_BUSY_TIMEOUT_MS` busy timeout
+pub fn create_pool(database_url: &str) -> Result<Pool, r2d2::PoolError> {
+    let max_size = env_setting("DB_POOL_MAX_SIZE", DEFAULT_POOL_MAX_SIZE).max(1);
+    let timeout_secs = env_setting("DB_POOL_TIMEOUT_SECS", DEFAULT_POOL_TIMEOUT_SECS);
+    let busy_timeout_ms = env_setting("DB_BUSY_TIMEOUT_MS", DEFAULT_BUSY_TIMEOUT_MS);
+
+    let manager = ConnectionManager::<SqliteConnection>::new(database_url);
+    r2d2::Pool::builder()
+        .max_size(max_size)
+        .connection_timeout(Duration::from_secs(timeout_secs))
+        .connection_customizer(Box::new(SqliteConnectionCustomizer { busy_timeout_ms }))
+        .build(manager)
+}
+
 /// Establishes a connection pool to the SQLite database
 /// 
 /// # Returns
@@ -37,10 +121,7 @@ pub fn establish_connection() -> Pool {
     dotenv().expect("Error loading .env file");
     let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
 
-    let manager = ConnectionManager::<SqliteConnection>::new(database_url);
-    r2d2::Pool::builder()
-        .build(manager)
-        .expect("Failed to create database connection pool")
+    create_pool(&database_url).expect("Failed to create database connection pool")
 }
 
 /// Gets a database connection from the pool
@@ -58,6 +139,23 @@ fn get_connection(state: &State<'_, DbState>) -> Result<DbConnection, String> {
     })
 }
 
+/// Rejects ids that can never match a row before a command touches the database
+/// 
+/// # Arguments
+/// * `id` - The id passed to the command
+/// * `name` - Parameter name used as the field in the error message
+/// 
+/// # Returns
+/// * `Ok(())` - If the id is positive
+/// * `Err(AppError::Validation)` - Message naming the invalid parameter
+pub fn validate_id(id: i32, name: &str) -> Result<(), AppError> {
+    if id <= 0 {
+        return Err(AppError::Validation(format!("{}: Invalid id {}", name, id)));
+    }
+
+    Ok(())
+}
+
 // ===== Show Operations =====
 
 /// Creates a new show in the database (internal function for tests and commands)
@@ -78,6 +176,34 @@ pub fn internal_create_show(
     let new_show = NewShow {
         name: name.to_string(),
         description: description.to_string(),
+        venue: None,
+        show_day: None,
+    };
+
+    diesel::insert_into(crate::schema::shows::dsl::shows)
+        .values(&new_show)
+        .returning(Show::as_returning())
+        .get_result(conn)
+}
+
+/// Creates a new show including its venue and air day
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_data` - Name, description, venue and show day of the new show
+/// 
+/// # Returns
+/// * `Ok(Show)` - The newly created show with generated ID
+/// * `Err(DieselError)` - Database error if creation fails
+pub fn internal_create_show_with_details(
+    conn: &mut SqliteConnection,
+    show_data: &ShowData,
+) -> Result<Show, DieselError> {
+    let new_show = NewShow {
+        name: show_data.name.clone(),
+        description: show_data.description.clone(),
+        venue: show_data.venue.clone(),
+        show_day: show_data.show_day.clone(),
     };
 
     diesel::insert_into(crate::schema::shows::dsl::shows)
@@ -101,11 +227,151 @@ pub fn internal_get_shows(conn: &mut SqliteConnection) -> Result<Vec<Show>, Dies
         .load::<Show>(conn)
 }
 
+/// Gets a specific show by ID (internal function for tests and commands)
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - The ID of the show to retrieve
+/// 
+/// # Returns
+/// * `Ok(Some(Show))` - The show if found
+/// * `Ok(None)` - If no show with the given ID exists
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_show_by_id(conn: &mut SqliteConnection, show_id: i32) -> Result<Option<Show>, DieselError> {
+    use crate::schema::shows::dsl::*;
+    shows.filter(id.eq(show_id)).first::<Show>(conn).optional()
+}
+
+/// Updates a show's name, description, venue and show day
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show to update
+/// * `show_data` - ShowData struct with the new values (None clears venue/show day)
+/// 
+/// # Returns
+/// * `Ok(Show)` - The updated show
+/// * `Err(DieselError)` - Database error if update fails or the show does not exist
+pub fn internal_update_show(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+    show_data: &ShowData,
+) -> Result<Show, DieselError> {
+    use crate::schema::shows::dsl::*;
+
+    diesel::update(shows.filter(id.eq(show_id)))
+        .set((
+            name.eq(&show_data.name),
+            description.eq(&show_data.description),
+            venue.eq(&show_data.venue),
+            show_day.eq(&show_data.show_day),
+        ))
+        .returning(Show::as_returning())
+        .get_result(conn)
+}
+
+/// Sets whether wrestlers may be booked in more than one match on a show
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// * `allow` - Whether multi-match bookings are allowed
+/// 
+/// # Returns
+/// * `Ok(Show)` - The updated show
+/// * `Err(DieselError)` - Database error if update fails or the show does not exist
+pub fn internal_set_show_allow_multi_match(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+    allow: bool,
+) -> Result<Show, DieselError> {
+    use crate::schema::shows::dsl::*;
+
+    diesel::update(shows.filter(id.eq(show_id)))
+        .set(allow_multi_match.eq(allow))
+        .returning(Show::as_returning())
+        .get_result(conn)
+}
+
+/// Highest rating a show can be given
+pub const MAX_SHOW_RATING: f64 = 5.0;
+
+/// Records a show's attendance and rating
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// * `attendance` - Crowd size (None to clear)
+/// * `rating` - Rating from 0.0 to 5.0 (None to clear)
+/// 
+/// # Returns
+/// * `Ok(Show)` - The updated show
+/// * `Err(AppError::Validation)` - If the attendance is negative or the rating is out of range
+/// * `Err(AppError::NotFound)` - If the show does not exist
+/// * `Err(AppError::Database)` - Database error if update fails
+pub fn internal_update_show_metrics(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+    attendance: Option<i32>,
+    rating: Option<f64>,
+) -> Result<Show, AppError> {
+    use crate::schema::shows;
+
+    if attendance.is_some_and(|count| count < 0) {
+        return Err(AppError::Validation("attendance: Attendance cannot be negative".to_string()));
+    }
+
+    if rating.is_some_and(|value| !(0.0..=MAX_SHOW_RATING).contains(&value)) {
+        return Err(AppError::Validation(format!(
+            "show_rating: Rating must be between 0 and {}",
+            MAX_SHOW_RATING
+        )));
+    }
+
+    diesel::update(shows::table.find(show_id))
+        .set((shows::attendance.eq(attendance), shows::show_rating.eq(rating)))
+        .returning(Show::as_returning())
+        .get_result(conn)
+        .map_err(AppError::from)
+}
+
+/// Averages the ratings of every episode of a recurring show
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of any episode of the show
+/// 
+/// # Returns
+/// * `Ok(Some(f64))` - Average rating across the series
+/// * `Ok(None)` - If no episode has been rated
+/// * `Err(DieselError)` - NotFound if the show does not exist, or a database error
+/// 
+/// # Note
+/// Episodes of the same program share a show name, so the series is every
+/// show with this show's name. Unrated episodes are ignored.
+pub fn internal_get_average_show_rating(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<Option<f64>, DieselError> {
+    use crate::schema::shows;
+    use diesel::dsl::avg;
+
+    let series_name = shows::table
+        .find(show_id)
+        .select(shows::name)
+        .first::<String>(conn)?;
+
+    shows::table
+        .filter(shows::name.eq(series_name))
+        .select(avg(shows::show_rating))
+        .first::<Option<f64>>(conn)
+}
+
 /// Tauri command to create a new wrestling show
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `show_data` - ShowData struct containing name and description
+/// * `show_data` - ShowData struct containing name, description, venue and show day
 /// 
 /// # Returns
 /// * `Ok(Show)` - The newly created show
@@ -114,7 +380,7 @@ pub fn internal_get_shows(conn: &mut SqliteConnection) -> Result<Vec<Show>, Dies
 pub fn create_show(state: State<'_, DbState>, show_data: ShowData) -> Result<Show, String> {
     let mut conn = get_connection(&state)?;
 
-    internal_create_show(&mut conn, &show_data.name, &show_data.description)
+    internal_create_show_with_details(&mut conn, &show_data)
         .inspect(|show| {
             info!("Show '{}' created successfully", show.name);
         })
@@ -142,1655 +408,5751 @@ pub fn get_shows(state: State<'_, DbState>) -> Result<Vec<Show>, String> {
     })
 }
 
-
-// ===== User Operations =====
-
-/// Creates a new user in the database (internal function for tests and commands)
+/// Tauri command to fetch a single show by ID
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `username` - The username for the new user
-/// * `password` - The password (will be hashed before storage)
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show to fetch
 /// 
 /// # Returns
-/// * `Ok(User)` - The newly created user
-/// * `Err(DieselError)` - Database error if creation fails
-/// 
-/// # Note
-/// Passwords should be hashed before calling this function
-pub fn internal_create_user(
-    conn: &mut SqliteConnection,
-    username: &str,
-    password: &str,
-) -> Result<User, DieselError> {
-    let new_user = NewUser {
-        username: username.to_string(),
-        password: password.to_string(),
-    };
+/// * `Ok(Some(Show))` - The show if found
+/// * `Ok(None)` - If no show with the given ID exists
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_show_by_id(state: State<'_, DbState>, show_id: i32) -> Result<Option<Show>, AppError> {
+    validate_id(show_id, "show_id")?;
 
-    diesel::insert_into(crate::schema::users::dsl::users)
-        .values(&new_user)
-        .returning(User::as_returning())
-        .get_result(conn)
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_show_by_id(&mut conn, show_id).map_err(|e| {
+        error!("Error loading show: {}", e);
+        AppError::from(e).with_context("Failed to load show")
+    })
 }
 
-/// Tauri command to create a new user account
+/// Tauri command to update a show's details
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `user_data` - UserData struct containing username and password
+/// * `show_id` - ID of the show to update
+/// * `show_data` - ShowData struct with name, description, venue and show day
 /// 
 /// # Returns
-/// * `Ok(User)` - The newly created user
-/// * `Err(String)` - Error message if creation fails
+/// * `Ok(Show)` - The updated show
+/// * `Err(AppError)` - Categorized error if update fails (NotFound for unknown shows)
 #[tauri::command]
-pub fn create_user(state: State<'_, DbState>, user_data: UserData) -> Result<User, String> {
-    let mut conn = get_connection(&state)?;
+pub fn update_show(state: State<'_, DbState>, show_id: i32, show_data: ShowData) -> Result<Show, AppError> {
+    validate_id(show_id, "show_id")?;
 
-    internal_create_user(&mut conn, &user_data.username, &user_data.password)
-        .inspect(|user| {
-            info!("User '{}' created successfully", user.username);
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_update_show(&mut conn, show_id, &show_data)
+        .inspect(|show| {
+            info!("Show '{}' updated successfully", show.name);
         })
         .map_err(|e| {
-            error!("Error creating user: {}", e);
-            format!("Failed to create user: {}", e)
+            error!("Error updating show: {}", e);
+            AppError::from(e).with_context("Failed to update show")
         })
 }
 
-// ===== Wrestler Operations =====
-
-/// Gets all wrestlers ordered by ID (internal function for tests and commands)
+/// Tauri command to allow or forbid multi-match bookings on a show
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// * `allow` - Whether multi-match bookings are allowed
 /// 
 /// # Returns
-/// * `Ok(Vec<Wrestler>)` - Vector of all wrestlers in the global pool
-/// * `Err(DieselError)` - Database error if query fails
+/// * `Ok(Show)` - The updated show
+/// * `Err(AppError)` - Categorized error if update fails
+#[tauri::command]
+pub fn set_show_allow_multi_match(
+    state: State<'_, DbState>,
+    show_id: i32,
+    allow: bool,
+) -> Result<Show, AppError> {
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_set_show_allow_multi_match(&mut conn, show_id, allow).map_err(|e| {
+        error!("Error updating multi-match setting for show {}: {}", show_id, e);
+        AppError::from(e).with_context("Failed to update show")
+    })
+}
+
+/// Tauri command to record a show's attendance and rating
 /// 
-/// # Note
-/// Wrestlers are global entities not tied to specific promotions
-pub fn internal_get_wrestlers(conn: &mut SqliteConnection) -> Result<Vec<Wrestler>, DieselError> {
-    use crate::schema::wrestlers::dsl::*;
-    wrestlers
-        .order(id.asc())
-        .load::<Wrestler>(conn)
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// * `attendance` - Crowd size (None to clear)
+/// * `rating` - Rating from 0.0 to 5.0 (None to clear)
+/// 
+/// # Returns
+/// * `Ok(Show)` - The updated show
+/// * `Err(AppError)` - Categorized error if validation or the update fails
+#[tauri::command]
+pub fn update_show_metrics(
+    state: State<'_, DbState>,
+    show_id: i32,
+    attendance: Option<i32>,
+    rating: Option<f64>,
+) -> Result<Show, AppError> {
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_update_show_metrics(&mut conn, show_id, attendance, rating)
+        .inspect(|show| {
+            info!("Metrics updated for show '{}'", show.name);
+        })
+        .map_err(|e| {
+            error!("Error updating metrics for show {}: {}", show_id, e);
+            e.with_context("Failed to update show metrics")
+        })
 }
 
-/// Gets a specific wrestler by ID (internal function for tests and commands)
+/// Tauri command to get the average rating of a recurring show
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `wrestler_id` - The ID of the wrestler to retrieve
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of any episode of the show
 /// 
 /// # Returns
-/// * `Ok(Some(Wrestler))` - The wrestler if found
-/// * `Ok(None)` - If no wrestler with the given ID exists
-/// * `Err(DieselError)` - Database error if query fails
-pub fn internal_get_wrestler_by_id(conn: &mut SqliteConnection, wrestler_id: i32) -> Result<Option<Wrestler>, DieselError> {
-    use crate::schema::wrestlers::dsl::*;
-    wrestlers.filter(id.eq(wrestler_id)).first::<Wrestler>(conn).optional()
+/// * `Ok(Option<f64>)` - Average rating across the series, None if unrated
+/// * `Err(AppError)` - Categorized error if the show is missing or the query fails
+#[tauri::command]
+pub fn get_average_show_rating(state: State<'_, DbState>, show_id: i32) -> Result<Option<f64>, AppError> {
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_average_show_rating(&mut conn, show_id).map_err(|e| {
+        error!("Error averaging rating for show {}: {}", show_id, e);
+        AppError::from(e).with_context("Failed to average show rating")
+    })
 }
 
-/// Creates a new wrestler with basic information (internal function)
+
+// ===== Promotion Operations =====
+
+/// Creates a new promotion
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// * `name` - The wrestler's ring name
-/// * `gender` - Gender ("Male", "Female", or other)
-/// * `wins` - Number of wins
-/// * `losses` - Number of losses
+/// * `name` - Unique name of the promotion
+/// * `description` - Optional description of the promotion
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The newly created wrestler
-/// * `Err(DieselError)` - Database error if creation fails
-pub fn internal_create_wrestler(
+/// * `Ok(Promotion)` - The newly created promotion
+/// * `Err(DieselError)` - Database error if creation fails (e.g., duplicate name)
+pub fn internal_create_promotion(
     conn: &mut SqliteConnection,
     name: &str,
-    gender: &str,
-    wins: i32,
-    losses: i32,
-) -> Result<Wrestler, DieselError> {
-    let new_wrestler = NewWrestler {
+    description: Option<&str>,
+) -> Result<Promotion, DieselError> {
+    use crate::schema::promotions;
+
+    let new_promotion = NewPromotion {
         name: name.to_string(),
-        gender: gender.to_string(),
-        wins,
-        losses,
-        is_user_created: Some(false), // Default to system wrestler
+        description: description.map(str::to_string),
     };
 
-    diesel::insert_into(crate::schema::wrestlers::dsl::wrestlers)
-        .values(&new_wrestler)
-        .returning(Wrestler::as_returning())
+    diesel::insert_into(promotions::table)
+        .values(&new_promotion)
+        .returning(Promotion::as_returning())
         .get_result(conn)
 }
 
-/// Creates a new wrestler with enhanced details (internal function for test data)
+/// Gets a single promotion by its ID
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// * `wrestler_name` - Ring name
-/// * `wrestler_real_name` - Real name
-/// * `wrestler_nickname` - Nickname or catchphrase
-/// * `wrestler_gender` - Gender ("Male", "Female", or other)
-/// * `wrestler_wins` - Initial win count
-/// * `wrestler_losses` - Initial loss count
-/// * `wrestler_height` - Height (e.g., "6'5\"")
-/// * `wrestler_weight` - Weight (e.g., "260 lbs")
-/// * `wrestler_debut_year` - Year of wrestling debut
-/// * `wrestler_strength` - Strength rating (1-10)
-/// * `wrestler_speed` - Speed rating (1-10)
-/// * `wrestler_agility` - Agility rating (1-10)
-/// * `wrestler_stamina` - Stamina rating (1-10)
-/// * `wrestler_charisma` - Charisma rating (1-10)
-/// * `wrestler_technique` - Technique rating (1-10)
-/// * `wrestler_biography` - Biography text
-/// * `is_user_created` - Whether this is a user-created wrestler
+/// * `promotion_id` - ID of the promotion
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The newly created wrestler with all details
-/// * `Err(DieselError)` - Database error if creation fails
-pub fn internal_create_enhanced_wrestler(
+/// * `Ok(Option<Promotion>)` - The promotion if found, None otherwise
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_promotion_by_id(
     conn: &mut SqliteConnection,
-    wrestler_name: &str,
-    wrestler_real_name: &str,
-    wrestler_nickname: &str,
-    wrestler_gender: &str,
-    wrestler_wins: i32,
-    wrestler_losses: i32,
-    wrestler_height: &str,
-    wrestler_weight: &str,
-    wrestler_debut_year: i32,
-    wrestler_strength: i32,
-    wrestler_speed: i32,
-    wrestler_agility: i32,
-    wrestler_stamina: i32,
-    wrestler_charisma: i32,
-    wrestler_technique: i32,
-    wrestler_biography: &str,
-    is_user_created: bool,
-) -> Result<Wrestler, DieselError> {
-    let new_wrestler = NewEnhancedWrestler {
-        name: wrestler_name.to_string(),
-        gender: wrestler_gender.to_string(),
-        wins: wrestler_wins,
-        losses: wrestler_losses,
-        real_name: Some(wrestler_real_name.to_string()),
-        nickname: Some(wrestler_nickname.to_string()),
-        height: Some(wrestler_height.to_string()),
-        weight: Some(wrestler_weight.to_string()),
-        debut_year: Some(wrestler_debut_year),
-        strength: Some(wrestler_strength),
-        speed: Some(wrestler_speed),
-        agility: Some(wrestler_agility),
-        stamina: Some(wrestler_stamina),
-        charisma: Some(wrestler_charisma),
-        technique: Some(wrestler_technique),
-        biography: Some(wrestler_biography.to_string()),
-        is_user_created: Some(is_user_created),
-    };
+    promotion_id: i32,
+) -> Result<Option<Promotion>, DieselError> {
+    use crate::schema::promotions;
 
-    diesel::insert_into(crate::schema::wrestlers::dsl::wrestlers)
-        .values(&new_wrestler)
-        .returning(Wrestler::as_returning())
-        .get_result(conn)
+    promotions::table
+        .find(promotion_id)
+        .first::<Promotion>(conn)
+        .optional()
 }
 
-/// Creates a new user-created wrestler with enhanced details
+/// Moves a show into a promotion, or removes it from its promotion
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// * `wrestler_data` - EnhancedWrestlerData struct with all wrestler details
+/// * `show_id` - ID of the show
+/// * `promotion_id` - Promotion to assign the show to (None to unassign)
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The newly created wrestler marked as user-created
-/// * `Err(DieselError)` - Database error if creation fails
-/// 
-/// # Note
-/// New wrestlers start with 0 wins and losses
-pub fn internal_create_user_wrestler(
+/// * `Ok(Show)` - The updated show
+/// * `Err(DieselError)` - Database error if update fails
+pub fn internal_assign_show_to_promotion(
     conn: &mut SqliteConnection,
-    wrestler_data: &EnhancedWrestlerData,
-) -> Result<Wrestler, DieselError> {
-    let gender_str: String = wrestler_data.gender.clone().into();
-    
-    let new_wrestler = NewEnhancedWrestler {
-        name: wrestler_data.name.clone(),
-        gender: gender_str,
-        wins: 0, // New wrestlers start with 0 wins/losses
-        losses: 0,
-        real_name: wrestler_data.real_name.clone(),
-        nickname: wrestler_data.nickname.clone(),
-        height: wrestler_data.height.clone(),
-        weight: wrestler_data.weight.clone(),
-        debut_year: wrestler_data.debut_year,
-        strength: wrestler_data.strength,
-        speed: wrestler_data.speed,
-        agility: wrestler_data.agility,
-        stamina: wrestler_data.stamina,
-        charisma: wrestler_data.charisma,
-        technique: wrestler_data.technique,
-        biography: wrestler_data.biography.clone(),
-        is_user_created: Some(true), // User-created wrestler
-        // Wrestlers are now global - no promotion_id needed
-    };
+    show_id: i32,
+    promotion_id: Option<i32>,
+) -> Result<Show, DieselError> {
+    use crate::schema::shows;
 
-    diesel::insert_into(crate::schema::wrestlers::dsl::wrestlers)
-        .values(&new_wrestler)
-        .returning(Wrestler::as_returning())
+    diesel::update(shows::table.find(show_id))
+        .set(shows::promotion_id.eq(promotion_id))
+        .returning(Show::as_returning())
         .get_result(conn)
 }
 
-
-/// Updates a wrestler's power ratings
+/// Gets a promotion together with its shows, title count and roster size
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// * `wrestler_id` - ID of the wrestler to update
-/// * `new_strength` - New strength rating (1-10, None to keep existing)
-/// * `new_speed` - New speed rating (1-10, None to keep existing)
-/// * `new_agility` - New agility rating (1-10, None to keep existing)
-/// * `new_stamina` - New stamina rating (1-10, None to keep existing)
-/// * `new_charisma` - New charisma rating (1-10, None to keep existing)
-/// * `new_technique` - New technique rating (1-10, None to keep existing)
+/// * `promotion_id` - ID of the promotion
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The updated wrestler
-/// * `Err(DieselError)` - Database error if update fails
-pub fn internal_update_wrestler_power_ratings(
+/// * `Ok(PromotionOverview)` - The promotion summary (zero counts if it has no shows)
+/// * `Err(DieselError)` - NotFound if the promotion does not exist, or a database error
+pub fn internal_get_promotion_overview(
     conn: &mut SqliteConnection,
-    wrestler_id: i32,
-    new_strength: Option<i32>,
-    new_speed: Option<i32>,
-    new_agility: Option<i32>,
-    new_stamina: Option<i32>,
-    new_charisma: Option<i32>,
-    new_technique: Option<i32>,
-) -> Result<Wrestler, DieselError> {
-    use crate::schema::wrestlers::dsl::*;
-    
-    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
-        .set((
-            strength.eq(new_strength),
-            speed.eq(new_speed),
-            agility.eq(new_agility),
-            stamina.eq(new_stamina),
-            charisma.eq(new_charisma),
-            technique.eq(new_technique),
-        ))
-        .returning(Wrestler::as_returning())
-        .get_result(conn)
+    promotion_id: i32,
+) -> Result<PromotionOverview, DieselError> {
+    use crate::schema::{promotions, show_rosters, shows, titles};
+    use diesel::dsl::count;
+
+    let promotion = promotions::table
+        .find(promotion_id)
+        .first::<Promotion>(conn)?;
+
+    let promotion_shows = shows::table
+        .filter(shows::promotion_id.eq(promotion_id))
+        .order(shows::id.asc())
+        .load::<Show>(conn)?;
+    let show_ids: Vec<i32> = promotion_shows.iter().map(|show| show.id).collect();
+
+    let title_count = titles::table
+        .filter(titles::is_active.eq(true))
+        .filter(titles::show_id.eq_any(&show_ids))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    let total_roster = show_rosters::table
+        .filter(show_rosters::is_active.eq(true))
+        .filter(show_rosters::show_id.eq_any(&show_ids))
+        .select(count(show_rosters::wrestler_id).aggregate_distinct())
+        .get_result::<i64>(conn)?;
+
+    Ok(PromotionOverview {
+        promotion,
+        shows: promotion_shows,
+        title_count,
+        total_roster,
+    })
 }
 
-/// Updates a wrestler's basic statistics and physical attributes
+/// Gets the current champions of every title on a promotion's shows
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// * `wrestler_id` - ID of the wrestler to update
-/// * `new_height` - New height (e.g., "6'2\"", None to keep existing)
-/// * `new_weight` - New weight (e.g., "220 lbs", None to keep existing)
-/// * `new_debut_year` - New debut year (None to keep existing)
-/// * `new_wins` - New win count
-/// * `new_losses` - New loss count
+/// * `promotion_id` - ID of the promotion
+/// * `include_cross_brand` - Whether to also include titles not assigned to any show
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The updated wrestler
-/// * `Err(DieselError)` - Database error if update fails
-pub fn internal_update_wrestler_basic_stats(
+/// * `Ok(Vec<(Title, Vec<TitleHolderInfo>)>)` - Held titles with their holders, ordered by prestige tier then display order
+/// * `Err(DieselError)` - NotFound if the promotion does not exist, or a database error
+/// 
+/// # Note
+/// Vacant and inactive titles are left out
+pub fn internal_get_champions_for_promotion(
     conn: &mut SqliteConnection,
-    wrestler_id: i32,
-    new_height: Option<String>,
-    new_weight: Option<String>,
-    new_debut_year: Option<i32>,
-    new_wins: i32,
-    new_losses: i32,
-) -> Result<Wrestler, DieselError> {
-    use crate::schema::wrestlers::dsl::*;
-    
-    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
-        .set((
-            height.eq(new_height),
-            weight.eq(new_weight),
-            debut_year.eq(new_debut_year),
-            wins.eq(new_wins),
-            losses.eq(new_losses),
-        ))
-        .returning(Wrestler::as_returning())
-        .get_result(conn)
+    promotion_id: i32,
+    include_cross_brand: bool,
+) -> Result<Vec<(Title, Vec<TitleHolderInfo>)>, DieselError> {
+    use crate::schema::{promotions, shows, titles};
+
+    promotions::table
+        .find(promotion_id)
+        .select(promotions::id)
+        .first::<i32>(conn)?;
+
+    let show_ids = shows::table
+        .filter(shows::promotion_id.eq(promotion_id))
+        .select(shows::id)
+        .load::<i32>(conn)?;
+
+    let mut query = titles::table
+        .filter(titles::is_active.eq(true))
+        .into_boxed();
+    query = if include_cross_brand {
+        query.filter(titles::show_id.eq_any(show_ids).or(titles::show_id.is_null()))
+    } else {
+        query.filter(titles::show_id.eq_any(show_ids))
+    };
+
+    let promotion_titles = query
+        .order(titles::prestige_tier.asc())
+        .then_order_by(titles::display_order.asc())
+        .then_order_by(titles::name.asc())
+        .load::<Title>(conn)?;
+
+    let mut champions = Vec::new();
+    for title in promotion_titles {
+        let holders = load_holders_for_title(conn, title.id, None)?;
+        if !holders.is_empty() {
+            champions.push((title, holders));
+        }
+    }
+
+    Ok(champions)
 }
 
-/// Updates a wrestler's ring name and nickname
+/// Tauri command to create a new promotion
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `wrestler_id` - ID of the wrestler to update
-/// * `new_name` - New ring name
-/// * `new_nickname` - New nickname or catchphrase (None to clear)
+/// * `state` - The Tauri state containing the database pool
+/// * `name` - Unique name of the promotion
+/// * `description` - Optional description of the promotion
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The updated wrestler
-/// * `Err(DieselError)` - Database error if update fails
-pub fn internal_update_wrestler_name(
-    conn: &mut SqliteConnection,
-    wrestler_id: i32,
-    new_name: &str,
-    new_nickname: Option<String>,
-) -> Result<Wrestler, DieselError> {
-    use crate::schema::wrestlers::dsl::*;
-    
-    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
-        .set((
-            name.eq(new_name),
-            nickname.eq(new_nickname),
-        ))
-        .returning(Wrestler::as_returning())
-        .get_result(conn)
+/// * `Ok(Promotion)` - The newly created promotion
+/// * `Err(AppError)` - Categorized error if creation fails
+#[tauri::command]
+pub fn create_promotion(
+    state: State<'_, DbState>,
+    name: String,
+    description: Option<String>,
+) -> Result<Promotion, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_create_promotion(&mut conn, &name, description.as_deref())
+        .inspect(|promotion| {
+            info!("Promotion '{}' created successfully", promotion.name);
+        })
+        .map_err(|e| {
+            error!("Error creating promotion: {}", e);
+            AppError::from(e).with_context("Failed to create promotion")
+        })
 }
 
-/// Updates a wrestler's real name
+/// Tauri command to fetch a single promotion
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `wrestler_id` - ID of the wrestler to update
-/// * `new_real_name` - New real name (None to clear)
+/// * `state` - The Tauri state containing the database pool
+/// * `promotion_id` - ID of the promotion
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The updated wrestler
-/// * `Err(DieselError)` - Database error if update fails
-pub fn internal_update_wrestler_real_name(
-    conn: &mut SqliteConnection,
-    wrestler_id: i32,
-    new_real_name: Option<String>,
-) -> Result<Wrestler, DieselError> {
-    use crate::schema::wrestlers::dsl::*;
-    
-    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
-        .set(real_name.eq(new_real_name))
-        .returning(Wrestler::as_returning())
-        .get_result(conn)
+/// * `Ok(Option<Promotion>)` - The promotion if found, None otherwise
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_promotion_by_id(
+    state: State<'_, DbState>,
+    promotion_id: i32,
+) -> Result<Option<Promotion>, AppError> {
+    validate_id(promotion_id, "promotion_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_promotion_by_id(&mut conn, promotion_id).map_err(|e| {
+        error!("Error fetching promotion {}: {}", promotion_id, e);
+        AppError::from(e).with_context("Failed to fetch promotion")
+    })
 }
 
-/// Updates a wrestler's biography text
+/// Tauri command to move a show into a promotion
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `wrestler_id` - ID of the wrestler to update
-/// * `new_biography` - New biography text (None to clear)
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// * `promotion_id` - Promotion to assign the show to (None to unassign)
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The updated wrestler
-/// * `Err(DieselError)` - Database error if update fails
-pub fn internal_update_wrestler_biography(
-    conn: &mut SqliteConnection,
-    wrestler_id: i32,
-    new_biography: Option<String>,
-) -> Result<Wrestler, DieselError> {
-    use crate::schema::wrestlers::dsl::*;
-    
-    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
-        .set(biography.eq(new_biography))
-        .returning(Wrestler::as_returning())
-        .get_result(conn)
-}
+/// * `Ok(Show)` - The updated show
+/// * `Err(AppError)` - Categorized error if update fails
+#[tauri::command]
+pub fn assign_show_to_promotion(
+    state: State<'_, DbState>,
+    show_id: i32,
+    promotion_id: Option<i32>,
+) -> Result<Show, AppError> {
+    validate_id(show_id, "show_id")?;
+    if let Some(id) = promotion_id {
+        validate_id(id, "promotion_id")?;
+    }
 
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
 
-/// Creates a new signature move for a wrestler
+    internal_assign_show_to_promotion(&mut conn, show_id, promotion_id).map_err(|e| {
+        error!("Error assigning show {} to promotion: {}", show_id, e);
+        AppError::from(e).with_context("Failed to assign show to promotion")
+    })
+}
+
+/// Tauri command to get the landing page overview of a promotion
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `wrestler_id` - ID of the wrestler who performs this move
-/// * `move_name` - Name of the move (e.g., "Stone Cold Stunner")
-/// * `move_type` - Type of move ("primary", "secondary", etc.)
+/// * `state` - The Tauri state containing the database pool
+/// * `promotion_id` - ID of the promotion
 /// 
 /// # Returns
-/// * `Ok(SignatureMove)` - The newly created signature move
-/// * `Err(DieselError)` - Database error if creation fails
-pub fn internal_create_signature_move(
-    conn: &mut SqliteConnection,
-    wrestler_id: i32,
-    move_name: &str,
-    move_type: &str,
-) -> Result<SignatureMove, DieselError> {
-    let new_move = NewSignatureMove {
-        wrestler_id,
-        move_name: move_name.to_string(),
-        move_type: move_type.to_string(),
-    };
+/// * `Ok(PromotionOverview)` - The promotion with its shows, title count and roster size
+/// * `Err(AppError)` - Categorized error if the promotion is missing or the query fails
+#[tauri::command]
+pub fn get_promotion_overview(
+    state: State<'_, DbState>,
+    promotion_id: i32,
+) -> Result<PromotionOverview, AppError> {
+    validate_id(promotion_id, "promotion_id")?;
 
-    diesel::insert_into(crate::schema::signature_moves::dsl::signature_moves)
-        .values(&new_move)
-        .returning(SignatureMove::as_returning())
-        .get_result(conn)
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_promotion_overview(&mut conn, promotion_id).map_err(|e| {
+        error!("Error loading overview for promotion {}: {}", promotion_id, e);
+        AppError::from(e).with_context("Failed to load promotion overview")
+    })
 }
 
-/// Deletes a wrestler (only if user-created)
+/// Tauri command to get the current champions of a promotion
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `promotion_id` - ID of the promotion
+/// * `include_cross_brand` - Whether to include titles not assigned to any show (defaults to false)
+/// 
+/// # Returns
+/// * `Ok(Vec<(Title, Vec<TitleHolderInfo>)>)` - Held titles with their holders
+/// * `Err(AppError)` - Categorized error if the promotion is missing or the query fails
+#[tauri::command]
+pub fn get_champions_for_promotion(
+    state: State<'_, DbState>,
+    promotion_id: i32,
+    include_cross_brand: Option<bool>,
+) -> Result<Vec<(Title, Vec<TitleHolderInfo>)>, AppError> {
+    validate_id(promotion_id, "promotion_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_champions_for_promotion(&mut conn, promotion_id, include_cross_brand.unwrap_or(false))
+        .map_err(|e| {
+            error!("Error loading champions for promotion {}: {}", promotion_id, e);
+            AppError::from(e).with_context("Failed to load promotion champions")
+        })
+}
+
+// ===== User Operations =====
+
+/// Creates a new user in the database (internal function for tests and commands)
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// * `wrestler_id` - ID of the wrestler to delete
+/// * `username` - The username for the new user
+/// * `password` - The password (will be hashed before storage)
 /// 
 /// # Returns
-/// * `Ok(())` - If deletion was successful
-/// * `Err(DieselError::RollbackTransaction)` - If wrestler is not user-created
-/// * `Err(DieselError)` - Other database errors
+/// * `Ok(User)` - The newly created user
+/// * `Err(DieselError)` - Database error if creation fails
 /// 
 /// # Note
-/// System wrestlers cannot be deleted. Deletion cascades through foreign keys.
-pub fn internal_delete_wrestler(
+/// Passwords should be hashed before calling this function
+pub fn internal_create_user(
     conn: &mut SqliteConnection,
-    wrestler_id: i32,
-) -> Result<(), DieselError> {
-    use crate::schema::wrestlers::dsl::*;
-    
-    // First check if the wrestler exists and is user-created
-    let wrestler = wrestlers
-        .filter(id.eq(wrestler_id))
-        .first::<Wrestler>(conn)?;
-    
-    // Only allow deletion of user-created wrestlers
-    if !wrestler.is_user_created.unwrap_or(false) {
-        return Err(DieselError::RollbackTransaction);
-    }
-    
-    // Delete the wrestler (database handles cascading deletes via foreign key constraints)
-    diesel::delete(wrestlers.filter(id.eq(wrestler_id)))
-        .execute(conn)?;
-        
-    Ok(())
+    username: &str,
+    password: &str,
+) -> Result<User, DieselError> {
+    let new_user = NewUser {
+        username: username.to_string(),
+        password: password.to_string(),
+    };
+
+    diesel::insert_into(crate::schema::users::dsl::users)
+        .values(&new_user)
+        .returning(User::as_returning())
+        .get_result(conn)
 }
 
-/// Tauri command to create a new wrestler with basic information
+/// Tauri command to create a new user account
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `wrestler_data` - WrestlerData struct containing name and gender
+/// * `user_data` - UserData struct containing username and password
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The newly created wrestler
+/// * `Ok(User)` - The newly created user
 /// * `Err(String)` - Error message if creation fails
 #[tauri::command]
-pub fn create_wrestler(
-    state: State<'_, DbState>,
-    wrestler_data: WrestlerData,
-) -> Result<Wrestler, String> {
+pub fn create_user(state: State<'_, DbState>, user_data: UserData) -> Result<User, String> {
     let mut conn = get_connection(&state)?;
-    let gender_str: String = wrestler_data.gender.into();
 
-    internal_create_wrestler(&mut conn, &wrestler_data.name, &gender_str, 0, 0)
-        .inspect(|wrestler| {
-            info!("Wrestler '{}' created successfully", wrestler.name);
+    internal_create_user(&mut conn, &user_data.username, &user_data.password)
+        .inspect(|user| {
+            info!("User '{}' created successfully", user.username);
         })
         .map_err(|e| {
-            error!("Error creating wrestler: {}", e);
-            format!("Failed to create wrestler: {}", e)
+            error!("Error creating user: {}", e);
+            format!("Failed to create user: {}", e)
         })
 }
 
-/// Tauri command to create a user-defined wrestler with full details
+// ===== Settings Operations =====
+
+/// Gets the stored value of a setting
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
-/// * `wrestler_data` - EnhancedWrestlerData with all wrestler attributes
+/// * `conn` - Mutable reference to the database connection
+/// * `setting_key` - Key of the setting
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The newly created wrestler
-/// * `Err(String)` - Error message if creation fails
-#[tauri::command]
-pub fn create_user_wrestler(
-    state: State<'_, DbState>,
-    wrestler_data: EnhancedWrestlerData,
-) -> Result<Wrestler, String> {
-    let mut conn = get_connection(&state)?;
+/// * `Ok(Some(String))` - The stored value
+/// * `Ok(None)` - If the setting has never been set
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_setting(
+    conn: &mut SqliteConnection,
+    setting_key: &str,
+) -> Result<Option<String>, DieselError> {
+    use crate::schema::settings;
+
+    settings::table
+        .find(setting_key)
+        .select(settings::value)
+        .first::<String>(conn)
+        .optional()
+}
 
-    internal_create_user_wrestler(&mut conn, &wrestler_data)
-        .inspect(|wrestler| {
-            info!("User wrestler '{}' created successfully", wrestler.name);
-        })
-        .map_err(|e| {
-            error!("Error creating user wrestler: {}", e);
-            format!("Failed to create user wrestler: {}", e)
+/// Stores a setting, replacing any previous value
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `setting_key` - Key of the setting (trimmed before saving)
+/// * `setting_value` - Value to store
+/// 
+/// # Returns
+/// * `Ok(Setting)` - The stored setting
+/// * `Err(AppError::Validation)` - If the key is empty
+/// * `Err(AppError::Database)` - Database error if the update fails
+pub fn internal_set_setting(
+    conn: &mut SqliteConnection,
+    setting_key: &str,
+    setting_value: &str,
+) -> Result<Setting, AppError> {
+    use crate::schema::settings;
+
+    let setting_key = setting_key.trim();
+    if setting_key.is_empty() {
+        return Err(AppError::Validation("key: Setting key cannot be empty".to_string()));
+    }
+
+    diesel::replace_into(settings::table)
+        .values(&Setting {
+            key: setting_key.to_string(),
+            value: setting_value.to_string(),
         })
+        .returning(Setting::as_returning())
+        .get_result(conn)
+        .map_err(AppError::from)
 }
 
-/// Tauri command to fetch all wrestlers from the global pool
+/// Gets a setting as a boolean
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
+/// * `conn` - Mutable reference to the database connection
+/// * `setting_key` - Key of the setting
+/// * `default` - Value returned when the setting has never been set
 /// 
 /// # Returns
-/// * `Ok(Vec<Wrestler>)` - Vector of all wrestlers
-/// * `Err(String)` - Error message if query fails
-#[tauri::command]
-pub fn get_wrestlers(state: State<'_, DbState>) -> Result<Vec<Wrestler>, String> {
-    let mut conn = get_connection(&state)?;
+/// * `Ok(bool)` - The stored value ("true" or "false"), or the default
+/// * `Err(AppError::Validation)` - If the stored value is not a boolean
+/// * `Err(AppError::Database)` - Database error if query fails
+pub fn internal_get_setting_bool(
+    conn: &mut SqliteConnection,
+    setting_key: &str,
+    default: bool,
+) -> Result<bool, AppError> {
+    match internal_get_setting(conn, setting_key)? {
+        Some(stored) => stored.trim().parse::<bool>().map_err(|_| {
+            AppError::Validation(format!("{}: '{}' is not a boolean", setting_key, stored))
+        }),
+        None => Ok(default),
+    }
+}
 
-    internal_get_wrestlers(&mut conn).map_err(|e| {
-        error!("Error loading wrestlers: {}", e);
-        format!("Failed to load wrestlers: {}", e)
-    })
+/// Gets a setting as an integer
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `setting_key` - Key of the setting
+/// * `default` - Value returned when the setting has never been set
+/// 
+/// # Returns
+/// * `Ok(i32)` - The stored value, or the default
+/// * `Err(AppError::Validation)` - If the stored value is not an integer
+/// * `Err(AppError::Database)` - Database error if query fails
+pub fn internal_get_setting_i32(
+    conn: &mut SqliteConnection,
+    setting_key: &str,
+    default: i32,
+) -> Result<i32, AppError> {
+    match internal_get_setting(conn, setting_key)? {
+        Some(stored) => stored.trim().parse::<i32>().map_err(|_| {
+            AppError::Validation(format!("{}: '{}' is not an integer", setting_key, stored))
+        }),
+        None => Ok(default),
+    }
 }
 
-/// Tauri command to fetch all unassigned wrestlers (not on any show roster)
+/// Tauri command to get the stored value of a setting
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
+/// * `key` - Key of the setting
 /// 
 /// # Returns
-/// * `Ok(Vec<Wrestler>)` - Vector of wrestlers not assigned to any show
-/// * `Err(String)` - Error message if query fails
+/// * `Ok(Option<String>)` - The stored value, None if never set
+/// * `Err(AppError)` - Categorized error if query fails
 #[tauri::command]
-pub fn get_unassigned_wrestlers(state: State<'_, DbState>) -> Result<Vec<Wrestler>, String> {
-    let mut conn = get_connection(&state)?;
+pub fn get_setting(state: State<'_, DbState>, key: String) -> Result<Option<String>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
 
-    internal_get_unassigned_wrestlers(&mut conn).map_err(|e| {
-        error!("Error loading unassigned wrestlers: {}", e);
-        format!("Failed to load unassigned wrestlers: {}", e)
+    internal_get_setting(&mut conn, &key).map_err(|e| {
+        error!("Error loading setting '{}': {}", key, e);
+        AppError::from(e).with_context("Failed to load setting")
     })
 }
 
-/// Tauri command to fetch a specific wrestler by ID
+/// Tauri command to store a setting
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `wrestler_id` - The ID of the wrestler to retrieve
+/// * `key` - Key of the setting
+/// * `value` - Value to store
 /// 
 /// # Returns
-/// * `Ok(Some(Wrestler))` - The wrestler if found
-/// * `Ok(None)` - If no wrestler with the given ID exists
-/// * `Err(String)` - Error message if query fails
+/// * `Ok(Setting)` - The stored setting
+/// * `Err(AppError)` - Categorized error if the key is empty or the update fails
+/// 
+/// # Note
+/// Values are stored as given; settings with a dedicated command (such as
+/// `set_roster_mode`) should be changed through it so they are validated
 #[tauri::command]
-pub fn get_wrestler_by_id(state: State<'_, DbState>, wrestler_id: i32) -> Result<Option<Wrestler>, String> {
-    let mut conn = get_connection(&state)?;
+pub fn set_setting(state: State<'_, DbState>, key: String, value: String) -> Result<Setting, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
 
-    internal_get_wrestler_by_id(&mut conn, wrestler_id).map_err(|e| {
-        error!("Error loading wrestler: {}", e);
-        format!("Failed to load wrestler: {}", e)
-    })
+    internal_set_setting(&mut conn, &key, &value)
+        .inspect(|setting| {
+            info!("Setting '{}' updated", setting.key);
+        })
+        .map_err(|e| {
+            error!("Error storing setting '{}': {}", key, e);
+            e.with_context("Failed to store setting")
+        })
 }
 
+// ===== Audit Log Operations =====
 
-/// Tauri command to update a wrestler's power ratings
+/// Records a mutation in the audit log
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `operation` - What happened, e.g. "title_change"
+/// * `entity` - Kind of record affected, e.g. "title"
+/// * `entity_id` - Optional ID of the affected record
+/// * `details` - Optional human-readable description
+/// 
+/// # Note
+/// Logging is best-effort: a failed insert is reported with `warn!` and never
+/// fails the operation being logged
+pub fn internal_log_operation(
+    conn: &mut SqliteConnection,
+    operation: &str,
+    entity: &str,
+    entity_id: Option<i32>,
+    details: Option<&str>,
+) {
+    use crate::schema::audit_log;
+
+    let entry = NewOperationLog {
+        timestamp: Utc::now().naive_utc(),
+        operation: operation.to_string(),
+        entity: entity.to_string(),
+        entity_id,
+        details: details.map(|s| s.to_string()),
+    };
+
+    if let Err(e) = diesel::insert_into(audit_log::table).values(&entry).execute(conn) {
+        warn!("Failed to record '{}' on {} {:?} in audit log: {}", operation, entity, entity_id, e);
+    }
+}
+
+/// Gets the most recent audit log entries
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `limit` - Maximum number of entries to return
+/// 
+/// # Returns
+/// * `Ok(Vec<OperationLog>)` - Entries newest first
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_audit_log(
+    conn: &mut SqliteConnection,
+    limit: i64,
+) -> Result<Vec<OperationLog>, DieselError> {
+    use crate::schema::audit_log;
+
+    audit_log::table
+        .order(audit_log::timestamp.desc())
+        .then_order_by(audit_log::id.desc())
+        .limit(limit)
+        .select(OperationLog::as_select())
+        .load::<OperationLog>(conn)
+}
+
+/// Tauri command to get the most recent audit log entries
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `wrestler_id` - ID of the wrestler to update
-/// * `strength` - New strength rating (1-10, None to keep)
-/// * `speed` - New speed rating (1-10, None to keep)
-/// * `agility` - New agility rating (1-10, None to keep)
-/// * `stamina` - New stamina rating (1-10, None to keep)
-/// * `charisma` - New charisma rating (1-10, None to keep)
-/// * `technique` - New technique rating (1-10, None to keep)
+/// * `limit` - Maximum number of entries to return
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The updated wrestler
-/// * `Err(String)` - Error message if update fails
+/// * `Ok(Vec<OperationLog>)` - Entries newest first
+/// * `Err(AppError)` - Categorized error if query fails
 #[tauri::command]
-pub fn update_wrestler_power_ratings(
-    state: State<'_, DbState>,
-    wrestler_id: i32,
-    strength: Option<i32>,
-    speed: Option<i32>,
-    agility: Option<i32>,
-    stamina: Option<i32>,
-    charisma: Option<i32>,
-    technique: Option<i32>,
-) -> Result<Wrestler, String> {
-    let mut conn = get_connection(&state)?;
+pub fn get_audit_log(state: State<'_, DbState>, limit: i64) -> Result<Vec<OperationLog>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
 
-    internal_update_wrestler_power_ratings(
-        &mut conn, 
-        wrestler_id, 
-        strength, 
-        speed, 
-        agility, 
-        stamina, 
-        charisma, 
-        technique
-    )
-    .inspect(|wrestler| {
-        info!("Wrestler '{}' power ratings updated", wrestler.name);
-    })
-    .map_err(|e| {
-        error!("Error updating wrestler power ratings: {}", e);
-        format!("Failed to update wrestler power ratings: {}", e)
+    internal_get_audit_log(&mut conn, limit).map_err(|e| {
+        error!("Error loading audit log: {}", e);
+        AppError::from(e).with_context("Failed to load audit log")
     })
 }
 
-/// Tauri command to update a wrestler's basic statistics
+// ===== Wrestler Operations =====
+
+/// Gets all wrestlers ordered by ID (internal function for tests and commands)
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
-/// * `wrestler_id` - ID of the wrestler to update
-/// * `height` - New height (e.g., "6'2\"")
-/// * `weight` - New weight (e.g., "220 lbs")
-/// * `debut_year` - New debut year
-/// * `wins` - New win count
-/// * `losses` - New loss count
+/// * `conn` - Mutable reference to the database connection
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The updated wrestler
-/// * `Err(String)` - Error message if update fails
-#[tauri::command]
-pub fn update_wrestler_basic_stats(
-    state: State<'_, DbState>,
-    wrestler_id: i32,
-    height: Option<String>,
-    weight: Option<String>,
-    debut_year: Option<i32>,
-    wins: i32,
-    losses: i32,
-) -> Result<Wrestler, String> {
-    let mut conn = get_connection(&state)?;
-
-    internal_update_wrestler_basic_stats(
-        &mut conn, 
-        wrestler_id, 
-        height, 
-        weight, 
-        debut_year, 
-        wins, 
-        losses
-    )
-    .inspect(|wrestler| {
-        info!("Wrestler '{}' basic stats updated", wrestler.name);
-    })
-    .map_err(|e| {
-        error!("Error updating wrestler basic stats: {}", e);
-        format!("Failed to update wrestler basic stats: {}", e)
-    })
+/// * `Ok(Vec<Wrestler>)` - Vector of all wrestlers in the global pool
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Wrestlers are global entities not tied to specific promotions
+pub fn internal_get_wrestlers(conn: &mut SqliteConnection) -> Result<Vec<Wrestler>, DieselError> {
+    use crate::schema::wrestlers::dsl::*;
+    wrestlers
+        .order(id.asc())
+        .load::<Wrestler>(conn)
 }
 
-/// Tauri command to update a wrestler's ring name and nickname
+/// Gets a specific wrestler by ID (internal function for tests and commands)
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
-/// * `wrestler_id` - ID of the wrestler to update
-/// * `name` - New ring name
-/// * `nickname` - New nickname or catchphrase
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - The ID of the wrestler to retrieve
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The updated wrestler
-/// * `Err(String)` - Error message if update fails
-#[tauri::command]
-pub fn update_wrestler_name(
-    state: State<'_, DbState>,
-    wrestler_id: i32,
-    name: String,
-    nickname: Option<String>,
-) -> Result<Wrestler, String> {
-    let mut conn = get_connection(&state)?;
-
-    internal_update_wrestler_name(&mut conn, wrestler_id, &name, nickname)
-        .inspect(|wrestler| {
-            info!("Wrestler '{}' name updated", wrestler.name);
-        })
-        .map_err(|e| {
-            error!("Error updating wrestler name: {}", e);
-            format!("Failed to update wrestler name: {}", e)
-        })
+/// * `Ok(Some(Wrestler))` - The wrestler if found
+/// * `Ok(None)` - If no wrestler with the given ID exists
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_wrestler_by_id(conn: &mut SqliteConnection, wrestler_id: i32) -> Result<Option<Wrestler>, DieselError> {
+    use crate::schema::wrestlers::dsl::*;
+    wrestlers.filter(id.eq(wrestler_id)).first::<Wrestler>(conn).optional()
 }
 
-/// Tauri command to update a wrestler's real name
+/// Counts wrestlers per gender value
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
-/// * `wrestler_id` - ID of the wrestler to update
-/// * `real_name` - New real name (None to clear)
+/// * `conn` - Mutable reference to the database connection
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The updated wrestler
-/// * `Err(String)` - Error message if update fails
-#[tauri::command]
-pub fn update_wrestler_real_name(
-    state: State<'_, DbState>,
-    wrestler_id: i32,
-    real_name: Option<String>,
-) -> Result<Wrestler, String> {
-    let mut conn = get_connection(&state)?;
+/// * `Ok(Vec<(String, i64)>)` - Each distinct gender with its wrestler count, ordered by gender
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Groups on the stored value, so custom genders are reported alongside "Male" and "Female"
+pub fn internal_count_wrestlers_by_gender(conn: &mut SqliteConnection) -> Result<Vec<(String, i64)>, DieselError> {
+    use crate::schema::wrestlers::dsl::*;
+    use diesel::dsl::count_star;
 
-    internal_update_wrestler_real_name(&mut conn, wrestler_id, real_name)
-        .inspect(|wrestler| {
-            info!("Wrestler '{}' real name updated", wrestler.name);
-        })
-        .map_err(|e| {
-            error!("Error updating wrestler real name: {}", e);
-            format!("Failed to update wrestler real name: {}", e)
-        })
+    wrestlers
+        .group_by(gender)
+        .select((gender, count_star()))
+        .order(gender.asc())
+        .load::<(String, i64)>(conn)
 }
 
-/// Tauri command to update a wrestler's biography
+/// Counts wrestlers per debut decade
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
-/// * `wrestler_id` - ID of the wrestler to update
-/// * `biography` - New biography text (None to clear)
+/// * `conn` - Mutable reference to the database connection
 /// 
 /// # Returns
-/// * `Ok(Wrestler)` - The updated wrestler
-/// * `Err(String)` - Error message if update fails
-#[tauri::command]
-pub fn update_wrestler_biography(
-    state: State<'_, DbState>,
-    wrestler_id: i32,
-    biography: Option<String>,
-) -> Result<Wrestler, String> {
-    let mut conn = get_connection(&state)?;
+/// * `Ok(Vec<(String, i64)>)` - Decade labels like "1990s" with counts, oldest first
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Wrestlers without a debut year are counted in a trailing "Unknown" bucket
+pub fn internal_count_wrestlers_by_decade(conn: &mut SqliteConnection) -> Result<Vec<(String, i64)>, DieselError> {
+    use crate::schema::wrestlers::dsl::*;
+    use diesel::dsl::count_star;
+    use std::collections::BTreeMap;
+
+    let per_year = wrestlers
+        .group_by(debut_year)
+        .select((debut_year, count_star()))
+        .load::<(Option<i32>, i64)>(conn)?;
+
+    let mut per_decade: BTreeMap<i32, i64> = BTreeMap::new();
+    let mut unknown = 0;
+    for (year, count) in per_year {
+        match year {
+            Some(year) => *per_decade.entry(year - year.rem_euclid(10)).or_insert(0) += count,
+            None => unknown += count,
+        }
+    }
 
-    internal_update_wrestler_biography(&mut conn, wrestler_id, biography)
-        .inspect(|wrestler| {
-            info!("Wrestler '{}' biography updated", wrestler.name);
-        })
-        .map_err(|e| {
-            error!("Error updating wrestler biography: {}", e);
-            format!("Failed to update wrestler biography: {}", e)
-        })
+    let mut decades: Vec<(String, i64)> = per_decade
+        .into_iter()
+        .map(|(decade, count)| (format!("{}s", decade), count))
+        .collect();
+    if unknown > 0 {
+        decades.push(("Unknown".to_string(), unknown));
+    }
+
+    Ok(decades)
 }
 
-/// Tauri command to delete a wrestler (only user-created wrestlers)
+/// Maximum length of a wrestler's ring name
+const MAX_WRESTLER_NAME_LENGTH: usize = 100;
+
+/// Validates wrestler creation input
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
-/// * `wrestler_id` - ID of the wrestler to delete
+/// * `name` - The wrestler's ring name
+/// * `wins` - Number of wins
+/// * `losses` - Number of losses
 /// 
 /// # Returns
-/// * `Ok(String)` - Success message
-/// * `Err(String)` - Error message if deletion fails or wrestler is system-created
-#[tauri::command]
-pub fn delete_wrestler(state: State<'_, DbState>, wrestler_id: i32) -> Result<String, String> {
-    let mut conn = get_connection(&state)?;
+/// * `Ok(String)` - The trimmed ring name
+/// * `Err(AppError::Validation)` - Field-specific message describing the invalid input
+fn validate_new_wrestler(name: &str, wins: i32, losses: i32) -> Result<String, AppError> {
+    let name = name.trim();
 
-    internal_delete_wrestler(&mut conn, wrestler_id)
-        .inspect(|_| {
-            info!("Wrestler with ID {} deleted successfully", wrestler_id);
-        })
-        .map_err(|e| {
-            error!("Error deleting wrestler: {}", e);
-            match e {
-                DieselError::RollbackTransaction => "Cannot delete system wrestler - only user-created wrestlers can be deleted".to_string(),
-                DieselError::NotFound => "Wrestler not found".to_string(),
-                _ => format!("Failed to delete wrestler: {}", e),
-            }
-        })
-        .map(|_| "Wrestler deleted successfully".to_string())
-}
+    if name.is_empty() {
+        return Err(AppError::Validation("name: Wrestler name cannot be empty".to_string()));
+    }
 
+    if name.chars().count() > MAX_WRESTLER_NAME_LENGTH {
+        return Err(AppError::Validation(format!(
+            "name: Wrestler name cannot exceed {} characters",
+            MAX_WRESTLER_NAME_LENGTH
+        )));
+    }
 
-// ===== Title Operations =====
+    if wins < 0 {
+        return Err(AppError::Validation("wins: Wins cannot be negative".to_string()));
+    }
 
-/// Creates a new championship title (internal function)
+    if losses < 0 {
+        return Err(AppError::Validation("losses: Losses cannot be negative".to_string()));
+    }
+
+    Ok(name.to_string())
+}
+
+/// Creates a new wrestler with basic information (internal function)
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// * `name` - Title name (e.g., "WWE Championship")
-/// * `title_type` - Type of title ("Singles", "Tag Team", etc.)
-/// * `division` - Division ("World", "Intercontinental", etc.)
-/// * `gender` - Gender restriction ("Male", "Female", "Mixed")
-/// * `show_id` - Optional show assignment (None for cross-brand)
-/// * `current_holder_id` - Optional initial champion ID
-/// * `is_user_created` - Whether this is a user-created title
+/// * `name` - The wrestler's ring name (trimmed before saving)
+/// * `gender` - Gender ("Male", "Female", or other), normalized before saving
+/// * `wins` - Number of wins
+/// * `losses` - Number of losses
 /// 
 /// # Returns
-/// * `Ok(Title)` - The newly created title
-/// * `Err(DieselError)` - Database error if creation fails
-/// 
-/// # Note
-/// Prestige tier is automatically calculated based on division
-pub fn internal_create_belt(
+/// * `Ok(Wrestler)` - The newly created wrestler
+/// * `Err(AppError::Validation)` - If the name is empty or too long, the gender is unrecognised, or the record is negative
+/// * `Err(AppError::Database)` - Database error if creation fails
+pub fn internal_create_wrestler(
     conn: &mut SqliteConnection,
     name: &str,
-    title_type: &str,
-    division: &str,
     gender: &str,
-    show_id: Option<i32>,
-    current_holder_id: Option<i32>,
-    is_user_created: bool,
-) -> Result<Title, DieselError> {
-    // Calculate prestige tier based on division
-    let prestige_tier = match division {
-        "World" | "WWE Championship" | "Women's World" | "WWE Women's Championship" => 1,
-        "Intercontinental" | "United States" | "Women's Intercontinental" | "Women's United States" => 2,
-        "World Tag Team" | "WWE Tag Team" | "Women's Tag Team" => 3,
-        _ => 4, // Specialty titles
-    };
+    wins: i32,
+    losses: i32,
+) -> Result<Wrestler, AppError> {
+    let name = validate_new_wrestler(name, wins, losses)?;
+    let gender = normalize_wrestler_gender(gender)?;
 
-    let new_title = NewTitle {
-        name: name.to_string(),
-        current_holder_id,
-        title_type: title_type.to_string(),
-        division: division.to_string(),
-        prestige_tier,
-        gender: gender.to_string(),
-        show_id,
-        is_active: true,
-        is_user_created: Some(is_user_created),
+    let new_wrestler = NewWrestler {
+        name,
+        gender,
+        wins,
+        losses,
+        is_user_created: Some(false), // Default to system wrestler
     };
 
-    diesel::insert_into(crate::schema::titles::dsl::titles)
-        .values(&new_title)
-        .returning(Title::as_returning())
+    diesel::insert_into(crate::schema::wrestlers::dsl::wrestlers)
+        .values(&new_wrestler)
+        .returning(Wrestler::as_returning())
         .get_result(conn)
+        .map_err(AppError::from)
 }
 
-/// Tauri command to create a new championship title
-/// 
-/// # Arguments
-/// * `state` - The Tauri state containing the database pool
-/// * `title_data` - TitleData struct with all title attributes
-/// 
-/// # Returns
-/// * `Ok(Title)` - The newly created title
-/// * `Err(String)` - Error message if creation fails
-#[tauri::command]
-pub fn create_belt(state: State<'_, DbState>, title_data: TitleData) -> Result<Title, String> {
-    let mut conn = get_connection(&state)?;
-
-    internal_create_belt(
-        &mut conn,
-        &title_data.name,
-        &title_data.title_type,
-        &title_data.division,
-        &title_data.gender,
-        title_data.show_id,
-        title_data.current_holder_id,
-        true, // User-created titles
-    )
-    .inspect(|title| {
-        info!("Title '{}' created successfully", title.name);
-    })
-    .map_err(|e| {
-        error!("Error creating title: {}", e);
-        format!("Failed to create title: {}", e)
-    })
-}
-
-/// Gets all titles with their current holders (internal function)
+/// Creates a new wrestler with enhanced details (internal function for test data)
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
+/// * `wrestler_name` - Ring name
+/// * `wrestler_real_name` - Real name
+/// * `wrestler_nickname` - Nickname or catchphrase
+/// * `wrestler_gender` - Gender ("Male", "Female", or other)
+/// * `wrestler_wins` - Initial win count
+/// * `wrestler_losses` - Initial loss count
+/// * `wrestler_height` - Height (e.g., "6'5\"")
+/// * `wrestler_weight` - Weight (e.g., "260 lbs")
+/// * `wrestler_debut_year` - Year of wrestling debut
+/// * `wrestler_strength` - Strength rating (1-10)
+/// * `wrestler_speed` - Speed rating (1-10)
+/// * `wrestler_agility` - Agility rating (1-10)
+/// * `wrestler_stamina` - Stamina rating (1-10)
+/// * `wrestler_charisma` - Charisma rating (1-10)
+/// * `wrestler_technique` - Technique rating (1-10)
+/// * `wrestler_biography` - Biography text
+/// * `is_user_created` - Whether this is a user-created wrestler
 /// 
 /// # Returns
-/// * `Ok(Vec<TitleWithHolders>)` - Vector of titles with holder information
-/// * `Err(DieselError)` - Database error if query fails
-/// 
-/// # Note
-/// Returns all active titles from the global pool, ordered by prestige tier
-pub fn internal_get_titles(conn: &mut SqliteConnection) -> Result<Vec<TitleWithHolders>, DieselError> {
-    use crate::schema::{titles, title_holders, wrestlers};
-    
-    // Get all active titles (global, not promotion-specific)
-    let all_titles = titles::table
-        .filter(titles::is_active.eq(true))
-        .order(titles::prestige_tier.asc())
-        .then_order_by(titles::name.asc())
-        .load::<Title>(conn)?;
-
-    let mut titles_with_holders = Vec::new();
-
-    for title in all_titles {
-        // Get current holders for this title
-        let current_holders_data = title_holders::table
-            .inner_join(wrestlers::table.on(title_holders::wrestler_id.eq(wrestlers::id)))
-            .filter(title_holders::title_id.eq(title.id))
-            .filter(title_holders::held_until.is_null())
-            .select((TitleHolder::as_select(), wrestlers::name, wrestlers::gender))
-            .load::<(TitleHolder, String, String)>(conn)?;
-
-        let current_holders: Vec<TitleHolderInfo> = current_holders_data
-            .into_iter()
-            .map(|(holder, wrestler_name, wrestler_gender)| TitleHolderInfo {
-                holder,
-                wrestler_name,
-                wrestler_gender,
-            })
-            .collect();
-
-        // Calculate days held for the first holder (for single titles)
-        let days_held = if let Some(first_holder) = current_holders.first() {
-            let now = Utc::now().naive_utc();
-            let duration = now - first_holder.holder.held_since;
-            Some((duration.num_days()) as i32)
-        } else {
-            None
-        };
-
-        titles_with_holders.push(TitleWithHolders {
-            title,
-            current_holders,
-            days_held,
-        });
-    }
+/// * `Ok(Wrestler)` - The newly created wrestler with all details
+/// * `Err(AppError::Validation)` - If the gender is unrecognised
+/// * `Err(AppError::Database)` - Database error if creation fails
+pub fn internal_create_enhanced_wrestler(
+    conn: &mut SqliteConnection,
+    wrestler_name: &str,
+    wrestler_real_name: &str,
+    wrestler_nickname: &str,
+    wrestler_gender: &str,
+    wrestler_wins: i32,
+    wrestler_losses: i32,
+    wrestler_height: &str,
+    wrestler_weight: &str,
+    wrestler_debut_year: i32,
+    wrestler_strength: i32,
+    wrestler_speed: i32,
+    wrestler_agility: i32,
+    wrestler_stamina: i32,
+    wrestler_charisma: i32,
+    wrestler_technique: i32,
+    wrestler_biography: &str,
+    is_user_created: bool,
+) -> Result<Wrestler, AppError> {
+    let new_wrestler = NewEnhancedWrestler {
+        name: wrestler_name.to_string(),
+        gender: normalize_wrestler_gender(wrestler_gender)?,
+        wins: wrestler_wins,
+        losses: wrestler_losses,
+        real_name: Some(wrestler_real_name.to_string()),
+        nickname: Some(wrestler_nickname.to_string()),
+        height: Some(wrestler_height.to_string()),
+        weight: Some(wrestler_weight.to_string()),
+        debut_year: Some(wrestler_debut_year),
+        strength: Some(wrestler_strength),
+        speed: Some(wrestler_speed),
+        agility: Some(wrestler_agility),
+        stamina: Some(wrestler_stamina),
+        charisma: Some(wrestler_charisma),
+        technique: Some(wrestler_technique),
+        biography: Some(wrestler_biography.to_string()),
+        is_user_created: Some(is_user_created),
+    };
 
-    Ok(titles_with_holders)
+    diesel::insert_into(crate::schema::wrestlers::dsl::wrestlers)
+        .values(&new_wrestler)
+        .returning(Wrestler::as_returning())
+        .get_result(conn)
+        .map_err(AppError::from)
 }
 
-/// Tauri command to fetch all championship titles with holders
+/// Creates a new user-created wrestler with enhanced details
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_data` - EnhancedWrestlerData struct with all wrestler details
 /// 
 /// # Returns
-/// * `Ok(Vec<TitleWithHolders>)` - Vector of all titles with current holders
-/// * `Err(String)` - Error message if query fails
-#[tauri::command]
-pub fn get_titles(state: State<'_, DbState>) -> Result<Vec<TitleWithHolders>, String> {
-    let mut conn = get_connection(&state)?;
+/// * `Ok(Wrestler)` - The newly created wrestler marked as user-created
+/// * `Err(AppError::Validation)` - If the name is empty or too long
+/// * `Err(AppError::Database)` - Database error if creation fails
+/// 
+/// # Note
+/// New wrestlers start with 0 wins and losses
+pub fn internal_create_user_wrestler(
+    conn: &mut SqliteConnection,
+    wrestler_data: &EnhancedWrestlerData,
+) -> Result<Wrestler, AppError> {
+    // New wrestlers start with 0 wins/losses, so only the name needs checking
+    let name = validate_new_wrestler(&wrestler_data.name, 0, 0)?;
+    let gender_str: String = wrestler_data.gender.clone().into();
     
-    internal_get_titles(&mut conn)
-        .map_err(|e| {
-            error!("Error fetching titles: {}", e);
-            format!("Failed to fetch titles: {}", e)
-        })
+    let new_wrestler = NewEnhancedWrestler {
+        name,
+        gender: gender_str,
+        wins: 0, // New wrestlers start with 0 wins/losses
+        losses: 0,
+        real_name: wrestler_data.real_name.clone(),
+        nickname: wrestler_data.nickname.clone(),
+        height: wrestler_data.height.clone(),
+        weight: wrestler_data.weight.clone(),
+        debut_year: wrestler_data.debut_year,
+        strength: wrestler_data.strength,
+        speed: wrestler_data.speed,
+        agility: wrestler_data.agility,
+        stamina: wrestler_data.stamina,
+        charisma: wrestler_data.charisma,
+        technique: wrestler_data.technique,
+        biography: wrestler_data.biography.clone(),
+        is_user_created: Some(true), // User-created wrestler
+        // Wrestlers are now global - no promotion_id needed
+    };
+
+    diesel::insert_into(crate::schema::wrestlers::dsl::wrestlers)
+        .values(&new_wrestler)
+        .returning(Wrestler::as_returning())
+        .get_result(conn)
+        .map_err(AppError::from)
 }
 
-/// Deletes a championship title (only if user-created)
+/// Creates a user-created wrestler together with their signature moves
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// * `title_id` - ID of the title to delete
+/// * `wrestler_data` - EnhancedWrestlerData struct with all wrestler details
+/// * `moves` - Signature moves as (move name, move type) pairs
 /// 
 /// # Returns
-/// * `Ok(())` - If deletion was successful
-/// * `Err(DieselError::RollbackTransaction)` - If title is not user-created
-/// * `Err(DieselError)` - Other database errors
+/// * `Ok((Wrestler, Vec<SignatureMove>))` - The new wrestler and their moves
+/// * `Err(AppError::Validation)` - If the wrestler or any move is invalid
+/// * `Err(AppError::Database)` - Database error if any insert fails
 /// 
 /// # Note
-/// Ends any current title reigns before deletion
-pub fn internal_delete_title(
+/// Runs in a single transaction, so a failed move insert also removes the wrestler
+pub fn internal_create_wrestler_with_moves(
     conn: &mut SqliteConnection,
-    title_id: i32,
-) -> Result<(), DieselError> {
-    use crate::schema::{titles, title_holders};
-    
-    // First check if the title exists and is user-created
-    let title = titles::table
-        .filter(titles::id.eq(title_id))
-        .first::<Title>(conn)?;
-    
-    // Only allow deletion of user-created titles
-    if !title.is_user_created.unwrap_or(false) {
-        return Err(DieselError::RollbackTransaction);
+    wrestler_data: &EnhancedWrestlerData,
+    moves: &[(String, String)],
+) -> Result<(Wrestler, Vec<SignatureMove>), AppError> {
+    // Validate every move before touching the database
+    let mut parsed_moves = Vec::with_capacity(moves.len());
+    for (move_name, move_type) in moves {
+        if move_name.trim().is_empty() {
+            return Err(AppError::Validation("move_name: Move name cannot be empty".to_string()));
+        }
+        let parsed_move_type = move_type.parse::<MoveType>().map_err(AppError::Validation)?;
+        parsed_moves.push((move_name.trim(), String::from(parsed_move_type)));
     }
-    
-    // End any current title reigns for this title before deletion
-    let now = Utc::now().naive_utc();
-    diesel::update(title_holders::table)
-        .filter(title_holders::title_id.eq(title_id))
-        .filter(title_holders::held_until.is_null())
-        .set(title_holders::held_until.eq(now))
-        .execute(conn)?;
-    
-    // Delete the title (database handles cascading deletes via foreign key constraints)
-    diesel::delete(titles::table.filter(titles::id.eq(title_id)))
-        .execute(conn)?;
-        
-    Ok(())
+
+    conn.transaction(|conn| {
+        let wrestler = internal_create_user_wrestler(conn, wrestler_data)?;
+
+        let signature_moves = parsed_moves
+            .iter()
+            .map(|(move_name, move_type)| {
+                internal_create_signature_move(conn, wrestler.id, move_name, move_type)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((wrestler, signature_moves))
+    })
 }
 
-/// Gets titles that can be assigned to a wrestler based on gender compatibility
+/// Creates a new wrestler using an existing wrestler as a template
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// * `wrestler_gender` - Gender of the wrestler ("Male", "Female", etc.)
+/// * `source_id` - ID of the wrestler to copy
+/// * `new_name` - Ring name for the new wrestler
+/// * `copy_moves` - Whether to copy the source's active signature moves
 /// 
 /// # Returns
-/// * `Ok(Vec<TitleWithHolders>)` - Vector of compatible titles
-/// * `Err(DieselError)` - Database error if query fails
+/// * `Ok(Wrestler)` - The new user-created wrestler
+/// * `Err(AppError::Validation)` - If the new name is empty or too long
+/// * `Err(AppError::NotFound)` - If the source wrestler does not exist
+/// * `Err(AppError::Database)` - Database error if creation fails
 /// 
 /// # Note
-/// - Male wrestlers can hold Male and Mixed titles
-/// - Female wrestlers can hold Female and Mixed titles
-/// - Other gender wrestlers can hold any title
-pub fn internal_get_titles_for_wrestler_gender(
+/// Profile details and power ratings are copied; the record starts at 0-0 and
+/// roster, title and match history are not carried over
+pub fn internal_clone_wrestler(
     conn: &mut SqliteConnection,
-    wrestler_gender: &str,
-) -> Result<Vec<TitleWithHolders>, DieselError> {
-    use crate::schema::{titles, title_holders, wrestlers};
-    
-    // Filter titles based on gender compatibility:
-    // - Male wrestlers can hold Male and Mixed titles
-    // - Female wrestlers can hold Female and Mixed titles  
-    // - Other gender wrestlers can hold any title
-    let gender_filter = match wrestler_gender {
-        "Male" => vec!["Male", "Mixed"],
-        "Female" => vec!["Female", "Mixed"], 
-        _ => vec!["Male", "Female", "Mixed"], // "Other" or any other gender
-    };
-    
-    // Get active titles that match gender criteria
-    let filtered_titles = titles::table
-        .filter(titles::is_active.eq(true))
-        .filter(titles::gender.eq_any(gender_filter))
-        .order(titles::prestige_tier.asc())
-        .then_order_by(titles::name.asc())
-        .load::<Title>(conn)?;
-
-    let mut titles_with_holders = Vec::new();
-
-    for title in filtered_titles {
-        // Get current holders for this title
-        let current_holders_data = title_holders::table
-            .inner_join(wrestlers::table.on(title_holders::wrestler_id.eq(wrestlers::id)))
-            .filter(title_holders::title_id.eq(title.id))
-            .filter(title_holders::held_until.is_null())
-            .select((TitleHolder::as_select(), wrestlers::name, wrestlers::gender))
-            .load::<(TitleHolder, String, String)>(conn)?;
-
-        let current_holders: Vec<TitleHolderInfo> = current_holders_data
-            .into_iter()
-            .map(|(holder, wrestler_name, wrestler_gender)| TitleHolderInfo {
-                holder,
-                wrestler_name,
-                wrestler_gender,
-            })
-            .collect();
-
-        // Calculate days held for the first holder (for single titles)
-        let days_held = if let Some(first_holder) = current_holders.first() {
-            let now = Utc::now().naive_utc();
-            let duration = now - first_holder.holder.held_since;
-            Some((duration.num_days()) as i32)
-        } else {
-            None
+    source_id: i32,
+    new_name: &str,
+    copy_moves: bool,
+) -> Result<Wrestler, AppError> {
+    use crate::schema::wrestlers;
+
+    let name = validate_new_wrestler(new_name, 0, 0)?;
+
+    conn.transaction(|conn| {
+        let source = wrestlers::table
+            .find(source_id)
+            .first::<Wrestler>(conn)?;
+
+        let new_wrestler = NewEnhancedWrestler {
+            name,
+            gender: source.gender,
+            wins: 0,
+            losses: 0,
+            real_name: source.real_name,
+            nickname: source.nickname,
+            height: source.height,
+            weight: source.weight,
+            debut_year: source.debut_year,
+            strength: source.strength,
+            speed: source.speed,
+            agility: source.agility,
+            stamina: source.stamina,
+            charisma: source.charisma,
+            technique: source.technique,
+            biography: source.biography,
+            is_user_created: Some(true),
         };
 
-        titles_with_holders.push(TitleWithHolders {
-            title,
-            current_holders,
-            days_held,
-        });
-    }
+        let clone = diesel::insert_into(wrestlers::table)
+            .values(&new_wrestler)
+            .returning(Wrestler::as_returning())
+            .get_result(conn)?;
 
-    Ok(titles_with_holders)
+        if copy_moves {
+            for signature_move in internal_get_signature_moves_for_wrestler(conn, source_id, false)? {
+                internal_create_signature_move(conn, clone.id, &signature_move.move_name, &signature_move.move_type)?;
+            }
+        }
+
+        Ok(clone)
+    })
 }
 
-/// Updates title holder (ends current reign and starts new one)
+
+/// Updates a wrestler's power ratings
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// * `title_id` - ID of the title to update
-/// * `new_wrestler_id` - ID of the new champion
-/// * `event_name` - Optional event name where title changed hands
-/// * `event_location` - Optional event location
-/// * `change_method` - Optional method of title change (e.g., "Pinfall", "Submission")
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `new_strength` - New strength rating (1-10, None to keep existing)
+/// * `new_speed` - New speed rating (1-10, None to keep existing)
+/// * `new_agility` - New agility rating (1-10, None to keep existing)
+/// * `new_stamina` - New stamina rating (1-10, None to keep existing)
+/// * `new_charisma` - New charisma rating (1-10, None to keep existing)
+/// * `new_technique` - New technique rating (1-10, None to keep existing)
 /// 
 /// # Returns
-/// * `Ok(())` - If title holder was successfully updated
+/// * `Ok(Wrestler)` - The updated wrestler
 /// * `Err(DieselError)` - Database error if update fails
-/// 
-/// # Note
-/// Validates string lengths to prevent database abuse (max 255 chars)
-pub fn internal_update_title_holder(
+pub fn internal_update_wrestler_power_ratings(
     conn: &mut SqliteConnection,
-    title_id: i32,
-    new_wrestler_id: i32,
-    event_name: Option<&str>,
-    event_location: Option<&str>,
-    change_method: Option<&str>,
-) -> Result<(), DieselError> {
-    use crate::schema::title_holders;
-    use diesel::result::{DatabaseErrorKind, Error as DieselError};
-
-    // Input validation to prevent abuse
-    const MAX_STRING_LENGTH: usize = 255;
-    
-    if let Some(name) = event_name {
-        if name.len() > MAX_STRING_LENGTH {
-            return Err(DieselError::DatabaseError(
-                DatabaseErrorKind::Unknown,
-                Box::new("Event name too long".to_string())
-            ));
-        }
-    }
+    wrestler_id: i32,
+    new_strength: Option<i32>,
+    new_speed: Option<i32>,
+    new_agility: Option<i32>,
+    new_stamina: Option<i32>,
+    new_charisma: Option<i32>,
+    new_technique: Option<i32>,
+) -> Result<Wrestler, DieselError> {
+    use crate::schema::wrestlers::dsl::*;
     
-    if let Some(location) = event_location {
-        if location.len() > MAX_STRING_LENGTH {
-            return Err(DieselError::DatabaseError(
-                DatabaseErrorKind::Unknown,
-                Box::new("Event location too long".to_string())
-            ));
-        }
-    }
-    
-    if let Some(method) = change_method {
-        if method.len() > MAX_STRING_LENGTH {
-            return Err(DieselError::DatabaseError(
-                DatabaseErrorKind::Unknown,
-                Box::new("Change method too long".to_string())
-            ));
-        }
-    }
-
-    let now = Utc::now().naive_utc();
-
-    // End current title reigns for this title
-    diesel::update(title_holders::table)
-        .filter(title_holders::title_id.eq(title_id))
-        .filter(title_holders::held_until.is_null())
-        .set(title_holders::held_until.eq(now))
-        .execute(conn)?;
-
-    // Create new title holder record
-    let new_holder = NewTitleHolder {
-        title_id,
-        wrestler_id: new_wrestler_id,
-        held_since: now,
-        event_name: event_name.map(|s| s.to_string()),
-        event_location: event_location.map(|s| s.to_string()),
-        change_method: change_method.map(|s| s.to_string()),
-    };
-
-    diesel::insert_into(title_holders::table)
-        .values(&new_holder)
-        .execute(conn)?;
-
-    Ok(())
+    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
+        .set((
+            strength.eq(new_strength),
+            speed.eq(new_speed),
+            agility.eq(new_agility),
+            stamina.eq(new_stamina),
+            charisma.eq(new_charisma),
+            technique.eq(new_technique),
+        ))
+        .returning(Wrestler::as_returning())
+        .get_result(conn)
 }
 
-/// Tauri command to change a championship title holder
+/// Updates a wrestler's basic statistics and physical attributes
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
-/// * `title_id` - ID of the title to update
-/// * `new_wrestler_id` - ID of the new champion
-/// * `event_name` - Optional event name
-/// * `event_location` - Optional event location
-/// * `change_method` - Optional method of victory
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `new_height` - New height (e.g., "6'2\"", None to keep existing)
+/// * `new_weight` - New weight (e.g., "220 lbs", None to keep existing)
+/// * `new_debut_year` - New debut year (None to keep existing)
+/// * `new_wins` - New win count
+/// * `new_losses` - New loss count
 /// 
 /// # Returns
-/// * `Ok(String)` - Success message
-/// * `Err(String)` - Error message if update fails
-#[tauri::command]
-pub fn update_title_holder(
-    state: State<'_, DbState>,
-    title_id: i32,
-    new_wrestler_id: i32,
-    event_name: Option<String>,
-    event_location: Option<String>,
-    change_method: Option<String>,
-) -> Result<String, String> {
-    let mut conn = get_connection(&state)?;
-
-    internal_update_title_holder(
-        &mut conn,
-        title_id,
-        new_wrestler_id,
-        event_name.as_deref(),
-        event_location.as_deref(),
-        change_method.as_deref(),
-    )
-    .map_err(|e| {
-        error!("Error updating title holder: {}", e);
-        format!("Failed to update title holder: {}", e)
-    })?;
-
-    Ok("Title holder updated successfully".to_string())
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(DieselError)` - Database error if update fails
+pub fn internal_update_wrestler_basic_stats(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+    new_height: Option<String>,
+    new_weight: Option<String>,
+    new_debut_year: Option<i32>,
+    new_wins: i32,
+    new_losses: i32,
+) -> Result<Wrestler, DieselError> {
+    use crate::schema::wrestlers::dsl::*;
+    
+    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
+        .set((
+            height.eq(new_height),
+            weight.eq(new_weight),
+            debut_year.eq(new_debut_year),
+            wins.eq(new_wins),
+            losses.eq(new_losses),
+        ))
+        .returning(Wrestler::as_returning())
+        .get_result(conn)
 }
 
-/// Tauri command to delete a championship title
+/// Updates a wrestler's ring name and nickname
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
-/// * `title_id` - ID of the title to delete
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `new_name` - New ring name
+/// * `new_nickname` - New nickname or catchphrase (None to clear)
 /// 
 /// # Returns
-/// * `Ok(String)` - Success message
-/// * `Err(String)` - Error message if deletion fails or title is system-created
-#[tauri::command]
-pub fn delete_title(state: State<'_, DbState>, title_id: i32) -> Result<String, String> {
-    let mut conn = get_connection(&state)?;
-
-    internal_delete_title(&mut conn, title_id)
-        .inspect(|_| {
-            info!("Title with ID {} deleted successfully", title_id);
-        })
-        .map_err(|e| {
-            error!("Error deleting title: {}", e);
-            match e {
-                DieselError::RollbackTransaction => "Cannot delete system title - only user-created titles can be deleted".to_string(),
-                DieselError::NotFound => "Title not found".to_string(),
-                _ => format!("Failed to delete title: {}", e),
-            }
-        })
-        .map(|_| "Title deleted successfully".to_string())
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(DieselError)` - Database error if update fails
+pub fn internal_update_wrestler_name(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+    new_name: &str,
+    new_nickname: Option<String>,
+) -> Result<Wrestler, DieselError> {
+    use crate::schema::wrestlers::dsl::*;
+    
+    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
+        .set((
+            name.eq(new_name),
+            nickname.eq(new_nickname),
+        ))
+        .returning(Wrestler::as_returning())
+        .get_result(conn)
 }
 
-/// Tauri command to get titles compatible with a wrestler's gender
+/// Updates a wrestler's real name
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
-/// * `wrestler_gender` - Gender of the wrestler
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `new_real_name` - New real name (None to clear)
 /// 
 /// # Returns
-/// * `Ok(Vec<TitleWithHolders>)` - Vector of compatible titles
-/// * `Err(String)` - Error message if query fails
-#[tauri::command]
-pub fn get_titles_for_wrestler(
-    state: State<'_, DbState>,
-    wrestler_gender: String,
-) -> Result<Vec<TitleWithHolders>, String> {
-    let mut conn = get_connection(&state)?;
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(DieselError)` - Database error if update fails
+pub fn internal_update_wrestler_real_name(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+    new_real_name: Option<String>,
+) -> Result<Wrestler, DieselError> {
+    use crate::schema::wrestlers::dsl::*;
     
-    internal_get_titles_for_wrestler_gender(&mut conn, &wrestler_gender)
-        .map_err(|e| {
-            error!("Error fetching titles for wrestler gender: {}", e);
-            format!("Failed to fetch titles for wrestler: {}", e)
-        })
+    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
+        .set(real_name.eq(new_real_name))
+        .returning(Wrestler::as_returning())
+        .get_result(conn)
 }
 
-/// Gets all titles assigned to a specific show
+/// Updates a wrestler's biography text
 /// 
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// * `show_id` - ID of the show to filter by
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `new_biography` - New biography text (None to clear)
 /// 
 /// # Returns
-/// * `Ok(Vec<TitleWithHolders>)` - Vector of titles assigned to the show
-/// * `Err(DieselError)` - Database error if query fails
-pub fn internal_get_titles_for_show(
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(DieselError)` - Database error if update fails
+pub fn internal_update_wrestler_biography(
     conn: &mut SqliteConnection,
-    show_id: i32,
-) -> Result<Vec<TitleWithHolders>, DieselError> {
-    use crate::schema::{titles, title_holders, wrestlers};
+    wrestler_id: i32,
+    new_biography: Option<String>,
+) -> Result<Wrestler, DieselError> {
+    use crate::schema::wrestlers::dsl::*;
     
-    // Get titles assigned to this specific show
-    let all_titles = titles::table
-        .filter(titles::is_active.eq(true))
-        .filter(titles::show_id.eq(show_id))
-        .order(titles::prestige_tier.asc())
-        .then_order_by(titles::name.asc())
-        .load::<Title>(conn)?;
-
-    let mut titles_with_holders = Vec::new();
-
-    for title in all_titles {
-        // Get current holders for this title
-        let current_holders_data = title_holders::table
-            .inner_join(wrestlers::table.on(title_holders::wrestler_id.eq(wrestlers::id)))
-            .filter(title_holders::title_id.eq(title.id))
-            .filter(title_holders::held_until.is_null())
-            .select((TitleHolder::as_select(), wrestlers::name, wrestlers::gender))
-            .load::<(TitleHolder, String, String)>(conn)?;
-
-        let current_holders: Vec<TitleHolderInfo> = current_holders_data
-            .into_iter()
-            .map(|(holder, wrestler_name, wrestler_gender)| TitleHolderInfo {
-                holder,
-                wrestler_name,
-                wrestler_gender,
-            })
-            .collect();
-
-        // Calculate days held for the first holder (for single titles)
-        let days_held = if let Some(first_holder) = current_holders.first() {
-            let now = Utc::now().naive_utc();
-            let duration = now - first_holder.holder.held_since;
-            Some((duration.num_days()) as i32)
-        } else {
-            None
-        };
-
-        titles_with_holders.push(TitleWithHolders {
-            title,
-            current_holders,
-            days_held,
-        });
-    }
-
-    Ok(titles_with_holders)
+    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
+        .set(biography.eq(new_biography))
+        .returning(Wrestler::as_returning())
+        .get_result(conn)
 }
 
-/// Gets all unassigned titles (not assigned to any show)
-/// 
+/// Updates all editable fields of a wrestler in a single statement
+///
 /// # Arguments
 /// * `conn` - Mutable reference to the database connection
-/// 
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `wrestler_data` - Complete wrestler profile to persist
+///
 /// # Returns
-/// * `Ok(Vec<TitleWithHolders>)` - Vector of cross-brand titles
-/// * `Err(DieselError)` - Database error if query fails
-pub fn internal_get_unassigned_titles(
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(DieselError)` - Database error if update fails
+///
+/// # Note
+/// Wins and losses are not part of the profile and are left untouched.
+pub fn internal_update_wrestler_full(
     conn: &mut SqliteConnection,
-) -> Result<Vec<TitleWithHolders>, DieselError> {
-    use crate::schema::{titles, title_holders, wrestlers};
-    
-    // Get titles not assigned to any show
-    let all_titles = titles::table
-        .filter(titles::is_active.eq(true))
-        .filter(titles::show_id.is_null())
-        .order(titles::prestige_tier.asc())
-        .then_order_by(titles::name.asc())
-        .load::<Title>(conn)?;
+    wrestler_id: i32,
+    wrestler_data: &EnhancedWrestlerData,
+) -> Result<Wrestler, DieselError> {
+    use crate::schema::wrestlers::dsl::*;
 
-    let mut titles_with_holders = Vec::new();
+    let gender_str: String = wrestler_data.gender.clone().into();
 
-    for title in all_titles {
-        // Get current holders for this title
-        let current_holders_data = title_holders::table
-            .inner_join(wrestlers::table.on(title_holders::wrestler_id.eq(wrestlers::id)))
-            .filter(title_holders::title_id.eq(title.id))
-            .filter(title_holders::held_until.is_null())
-            .select((TitleHolder::as_select(), wrestlers::name, wrestlers::gender))
-            .load::<(TitleHolder, String, String)>(conn)?;
+    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
+        .set((
+            name.eq(&wrestler_data.name),
+            gender.eq(gender_str),
+            real_name.eq(&wrestler_data.real_name),
+            nickname.eq(&wrestler_data.nickname),
+            height.eq(&wrestler_data.height),
+            weight.eq(&wrestler_data.weight),
+            debut_year.eq(wrestler_data.debut_year),
+            strength.eq(wrestler_data.strength),
+            speed.eq(wrestler_data.speed),
+            agility.eq(wrestler_data.agility),
+            stamina.eq(wrestler_data.stamina),
+            charisma.eq(wrestler_data.charisma),
+            technique.eq(wrestler_data.technique),
+            biography.eq(&wrestler_data.biography),
+        ))
+        .returning(Wrestler::as_returning())
+        .get_result(conn)
+}
 
-        let current_holders: Vec<TitleHolderInfo> = current_holders_data
-            .into_iter()
-            .map(|(holder, wrestler_name, wrestler_gender)| TitleHolderInfo {
-                holder,
-                wrestler_name,
-                wrestler_gender,
-            })
-            .collect();
 
-        // Calculate days held for the first holder (for single titles)
-        let days_held = if let Some(first_holder) = current_holders.first() {
-            let now = Utc::now().naive_utc();
-            let duration = now - first_holder.holder.held_since;
-            Some((duration.num_days()) as i32)
-        } else {
-            None
-        };
+/// Creates a new signature move for a wrestler
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler who performs this move
+/// * `move_name` - Name of the move (e.g., "Stone Cold Stunner")
+/// * `move_type` - Type of move ("primary", "secondary", etc.)
+/// 
+/// # Returns
+/// * `Ok(SignatureMove)` - The newly created signature move
+/// * `Err(DieselError)` - Database error if creation fails
+pub fn internal_create_signature_move(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+    move_name: &str,
+    move_type: &str,
+) -> Result<SignatureMove, DieselError> {
+    let new_move = NewSignatureMove {
+        wrestler_id,
+        move_name: move_name.to_string(),
+        move_type: move_type.to_string(),
+    };
 
-        titles_with_holders.push(TitleWithHolders {
-            title,
-            current_holders,
-            days_held,
-        });
+    diesel::insert_into(crate::schema::signature_moves::dsl::signature_moves)
+        .values(&new_move)
+        .returning(SignatureMove::as_returning())
+        .get_result(conn)
+}
+
+/// Gets a wrestler's signature moves
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+/// * `include_inactive` - Whether to include retired moves
+/// 
+/// # Returns
+/// * `Ok(Vec<SignatureMove>)` - Moves ordered with primary finishers first, then by name
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_signature_moves_for_wrestler(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+    include_inactive: bool,
+) -> Result<Vec<SignatureMove>, DieselError> {
+    use crate::schema::signature_moves;
+
+    let mut query = signature_moves::table
+        .filter(signature_moves::wrestler_id.eq(wrestler_id))
+        .into_boxed();
+
+    if !include_inactive {
+        query = query.filter(signature_moves::is_active.eq(true));
     }
 
-    Ok(titles_with_holders)
+    query
+        .order(signature_moves::move_type.asc())
+        .then_order_by(signature_moves::move_name.asc())
+        .load::<SignatureMove>(conn)
 }
 
-/// Tauri command to get titles assigned to a specific show
+/// Retires or reactivates a signature move without deleting it
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
-/// * `show_id` - ID of the show
+/// * `conn` - Mutable reference to the database connection
+/// * `move_id` - ID of the signature move
+/// * `active` - New activity state
 /// 
 /// # Returns
-/// * `Ok(Vec<TitleWithHolders>)` - Vector of titles for the show
-/// * `Err(String)` - Error message if query fails
-#[tauri::command]
-pub fn get_titles_for_show(
-    state: State<'_, DbState>,
-    show_id: i32,
-) -> Result<Vec<TitleWithHolders>, String> {
-    let mut conn = get_connection(&state)?;
-    
-    internal_get_titles_for_show(&mut conn, show_id)
-        .map_err(|e| {
-            error!("Error fetching titles for show: {}", e);
-            format!("Failed to fetch titles for show: {}", e)
-        })
+/// * `Ok(SignatureMove)` - The updated signature move
+/// * `Err(DieselError)` - Database error if update fails or the move does not exist
+pub fn internal_set_signature_move_active(
+    conn: &mut SqliteConnection,
+    move_id: i32,
+    active: bool,
+) -> Result<SignatureMove, DieselError> {
+    use crate::schema::signature_moves;
+
+    diesel::update(signature_moves::table.filter(signature_moves::id.eq(move_id)))
+        .set(signature_moves::is_active.eq(active))
+        .returning(SignatureMove::as_returning())
+        .get_result(conn)
 }
 
-/// Tauri command to get cross-brand titles not assigned to any show
+/// Gets every signature move of a given type across the roster
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
+/// * `conn` - Mutable reference to the database connection
+/// * `move_type` - Type of move ("primary" or "secondary", case-insensitive)
 /// 
 /// # Returns
-/// * `Ok(Vec<TitleWithHolders>)` - Vector of unassigned titles
-/// * `Err(String)` - Error message if query fails
-#[tauri::command]
-pub fn get_unassigned_titles(
-    state: State<'_, DbState>,
-) -> Result<Vec<TitleWithHolders>, String> {
-    let mut conn = get_connection(&state)?;
-    
-    internal_get_unassigned_titles(&mut conn)
-        .map_err(|e| {
-            error!("Error fetching unassigned titles: {}", e);
-            format!("Failed to fetch unassigned titles: {}", e)
-        })
+/// * `Ok(Vec<(SignatureMove, String)>)` - Moves with the performing wrestler's name, ordered by wrestler then move
+/// * `Err(AppError::Validation)` - If the move type is not a valid `MoveType`
+/// * `Err(AppError::Database)` - Database error if query fails
+pub fn internal_get_signature_moves_by_type(
+    conn: &mut SqliteConnection,
+    move_type: &str,
+) -> Result<Vec<(SignatureMove, String)>, AppError> {
+    use crate::schema::{signature_moves, wrestlers};
+
+    let move_type: String = move_type
+        .parse::<MoveType>()
+        .map_err(AppError::Validation)?
+        .into();
+
+    signature_moves::table
+        .inner_join(wrestlers::table.on(signature_moves::wrestler_id.eq(wrestlers::id)))
+        .filter(signature_moves::move_type.eq(move_type))
+        .order(wrestlers::name.asc())
+        .then_order_by(signature_moves::move_name.asc())
+        .select((SignatureMove::as_select(), wrestlers::name))
+        .load::<(SignatureMove, String)>(conn)
+        .map_err(AppError::from)
 }
 
-/// Tauri command to create comprehensive test data for development
-/// 
-/// Creates the following test data:
-/// - 2 shows (Monday Night RAW, Friday Night SmackDown)
-/// - 5 wrestlers with detailed profiles and signature moves
-/// - 15 championship titles across different tiers
-/// - Show roster assignments
-/// - Title holders
-/// - Sample matches with participants
+/// Deletes a wrestler (only if user-created)
 /// 
 /// # Arguments
-/// * `state` - The Tauri state containing the database pool
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler to delete
 /// 
 /// # Returns
-/// * `Ok(String)` - Summary of created test data
-/// * `Err(String)` - Error message if creation fails or data already exists
-#[tauri::command]
-pub fn create_test_data(state: State<'_, DbState>) -> Result<String, String> {
-    let mut conn = get_connection(&state)?;
-    
-    // Check if specific test data already exists
-    let existing_shows = internal_get_shows(&mut conn).map_err(|e| format!("Error checking shows: {}", e))?;
-    
-    // Check for specific test shows instead of any shows
-    let test_show_names = ["Monday Night RAW", "Friday Night SmackDown"];
-    let existing_test_shows: Vec<_> = existing_shows.iter()
-        .filter(|show| test_show_names.contains(&show.name.as_str()))
-        .collect();
-    
-    if !existing_test_shows.is_empty() {
-        return Ok(format!("Test data already exists: found {} test shows", existing_test_shows.len()));
-    }
+/// * `Ok(())` - If deletion was successful
+/// * `Err(DieselError::RollbackTransaction)` - If wrestler is not user-created
+/// * `Err(DieselError)` - Other database errors
+/// 
+/// # Note
+/// System wrestlers cannot be deleted. Deletion cascades through foreign keys.
+pub fn internal_delete_wrestler(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+) -> Result<(), DieselError> {
+    use crate::schema::wrestlers::dsl::*;
     
-    // Create test shows
-    let test_shows = vec![
-        ("Monday Night RAW", "WWE's flagship weekly show featuring the biggest superstars"),
-        ("Friday Night SmackDown", "The longest-running weekly episodic TV show in history"),
-    ];
+    // First check if the wrestler exists and is user-created
+    let wrestler = wrestlers
+        .filter(id.eq(wrestler_id))
+        .first::<Wrestler>(conn)?;
     
-    for (name, description) in test_shows {
-        internal_create_show(&mut conn, name, description)
-            .map_err(|e| format!("Failed to create show '{}': {}", name, e))?;
+    // Only allow deletion of user-created wrestlers
+    if !wrestler.is_user_created.unwrap_or(false) {
+        return Err(DieselError::RollbackTransaction);
     }
     
-    // Create test wrestlers with detailed information
-    let test_wrestlers = vec![
-        (
-            "The Rock", "Dwayne Johnson", "The People's Champion", "Male", 245, 67,
-            "6'5\"", "260 lbs", 1996, "WWE", 9, 6, 7, 9, 10, 8,
-            "The Rock is one of the most electrifying superstars in sports entertainment history. Known for his incredible charisma, devastating finishing moves, and ability to captivate audiences worldwide. From his days as 'Rocky Maivia' to becoming 'The People's Champion,' The Rock has dominated both the wrestling ring and Hollywood.",
-            true // This wrestler is user-created for testing modifications
-        ),
-        (
-            "Stone Cold Steve Austin", "Steven James Anderson", "The Texas Rattlesnake", "Male", 312, 89,
-            "6'2\"", "252 lbs", 1989, "WWE", 8, 7, 6, 8, 9, 7,
-            "Stone Cold Steve Austin is the beer-drinking, hell-raising anti-hero who defined the Attitude Era. With his rebellious nature and iconic catchphrases, Austin became the face of WWE during its most successful period.",
-            false
-        ),
-        (
-            "Becky Lynch", "Rebecca Quin", "The Man", "Female", 156, 43,
-            "5'6\"", "135 lbs", 2013, "WWE", 7, 8, 9, 8, 9, 8,
-            "Becky Lynch transformed from 'The Irish Lass Kicker' to 'The Man' - the top superstar in all of WWE. Her journey from underdog to champion inspired millions and redefined what it means to be a top star in sports entertainment.",
-            false
-        ),
-        (
-            "Charlotte Flair", "Ashley Elizabeth Fliehr", "The Queen", "Female", 198, 52,
-            "5'10\"", "143 lbs", 2012, "WWE", 7, 8, 8, 8, 9, 9,
-            "Charlotte Flair is a second-generation superstar who has established herself as one of the most dominant competitors in WWE history. The daughter of 'Nature Boy' Ric Flair, she has carved out her own legendary legacy.",
-            false
-        ),
-        (
-            "John Cena", "John Felix Anthony Cena Jr.", "The Cenation Leader", "Male", 289, 78,
-            "6'1\"", "251 lbs", 2002, "WWE", 8, 7, 7, 9, 10, 8,
-            "John Cena is a 16-time World Champion who became the face of WWE for over a decade. Known for his 'Never Give Up' attitude and incredible work ethic, Cena has inspired millions of fans worldwide while also pursuing a successful acting career.",
-            false
-        ),
-    ];
-    
-    for (name, real_name, nickname, gender, wins, losses, height, weight, debut_year, _unused_promotion, strength, speed, agility, stamina, charisma, technique, biography, is_user_created) in test_wrestlers {
-        let wrestler = internal_create_enhanced_wrestler(
-            &mut conn, name, real_name, nickname, gender, wins, losses, 
-            height, weight, debut_year, strength, speed, agility, 
-            stamina, charisma, technique, biography, is_user_created
-        ).map_err(|e| format!("Failed to create wrestler '{}': {}", name, e))?;
-        
-        // Add signature moves for each wrestler
-        let moves = match name {
-            "The Rock" => vec![
-                ("Rock Bottom", "primary"),
-                ("People's Elbow", "primary"), 
-                ("Samoan Drop", "secondary"),
-                ("Spinebuster", "secondary"),
-            ],
-            "Stone Cold Steve Austin" => vec![
-                ("Stone Cold Stunner", "primary"),
-                ("Lou Thesz Press", "secondary"),
-                ("Slingshot Suplex", "secondary"),
-            ],
-            "Becky Lynch" => vec![
-                ("Dis-arm-her", "primary"),
-                ("Manhandle Slam", "primary"),
-                ("Bex-Ploder", "secondary"),
-                ("Leg Drop", "secondary"),
-            ],
-            "Charlotte Flair" => vec![
-                ("Figure Eight", "primary"),
-                ("Natural Selection", "primary"),
-                ("Big Boot", "secondary"),
-                ("Spear", "secondary"),
-            ],
-            "John Cena" => vec![
-                ("Attitude Adjustment", "primary"),
-                ("STFU/STF", "primary"),
-                ("Five Knuckle Shuffle", "secondary"),
-                ("Flying Shoulder Block", "secondary"),
-            ],
-            _ => vec![],
-        };
+    // Delete the wrestler (database handles cascading deletes via foreign key constraints)
+    diesel::delete(wrestlers.filter(id.eq(wrestler_id)))
+        .execute(conn)?;
+
+    internal_log_operation(
+        conn,
+        "wrestler_deleted",
+        "wrestler",
+        Some(wrestler_id),
+        Some(&format!("Deleted {}", wrestler.name)),
+    );
         
-        for (move_name, move_type) in moves {
-            internal_create_signature_move(&mut conn, wrestler.id, move_name, move_type)
-                .map_err(|e| format!("Failed to create move '{}' for '{}': {}", move_name, name, e))?;
-        }
+    Ok(())
+}
+
+/// Counts the records that deleting a wrestler would remove
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+/// 
+/// # Returns
+/// * `Ok(DeletionImpact)` - Match, reign, roster and signature move counts
+/// * `Err(DieselError)` - NotFound if the wrestler does not exist, or a database error
+pub fn internal_get_deletion_impact(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+) -> Result<DeletionImpact, DieselError> {
+    use crate::schema::{match_participants, show_rosters, signature_moves, title_holders, wrestlers};
+
+    wrestlers::table
+        .find(wrestler_id)
+        .select(wrestlers::id)
+        .first::<i32>(conn)?;
+
+    let match_count = match_participants::table
+        .filter(match_participants::wrestler_id.eq(wrestler_id))
+        .select(diesel::dsl::count(match_participants::match_id).aggregate_distinct())
+        .get_result::<i64>(conn)?;
+
+    let active_reigns = title_holders::table
+        .filter(title_holders::wrestler_id.eq(wrestler_id))
+        .filter(title_holders::held_until.is_null())
+        .count()
+        .get_result::<i64>(conn)?;
+
+    let roster_assignments = show_rosters::table
+        .filter(show_rosters::wrestler_id.eq(wrestler_id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    let signature_moves = signature_moves::table
+        .filter(signature_moves::wrestler_id.eq(wrestler_id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    Ok(DeletionImpact {
+        match_count,
+        active_reigns,
+        roster_assignments,
+        signature_moves,
+    })
+}
+
+/// Changes a wrestler's availability status
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+/// * `new_status` - New status ("Active", "Injured" or "Retired")
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(AppError::Validation)` - If the status is not recognized
+/// * `Err(AppError::NotFound)` - If the wrestler does not exist
+/// * `Err(AppError::Database)` - Database error if update fails
+pub fn internal_set_wrestler_status(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+    new_status: &str,
+) -> Result<Wrestler, AppError> {
+    use crate::schema::wrestlers::dsl::*;
+
+    if !WRESTLER_STATUSES.contains(&new_status) {
+        return Err(AppError::Validation(format!(
+            "status: '{}' is not one of {}",
+            new_status,
+            WRESTLER_STATUSES.join(", ")
+        )));
     }
-    
-    // Create test titles
-    let all_shows = internal_get_shows(&mut conn).map_err(|e| format!("Error getting shows: {}", e))?;
-    let raw_show = all_shows.iter()
-        .find(|show| show.name == "Monday Night RAW");
-    let smackdown_show = all_shows.iter()
-        .find(|show| show.name == "Friday Night SmackDown");
 
-    let test_titles = vec![
-        // Tier 1 - World Championships
-        ("World Heavyweight Championship", "Singles", "World", "Male", raw_show.map(|s| s.id)),
-        ("WWE Championship", "Singles", "WWE Championship", "Male", smackdown_show.map(|s| s.id)),
-        ("Women's World Championship", "Singles", "Women's World", "Female", raw_show.map(|s| s.id)),
-        ("WWE Women's Championship", "Singles", "WWE Women's Championship", "Female", smackdown_show.map(|s| s.id)),
-        
-        // Tier 2 - Secondary Championships
-        ("Intercontinental Championship", "Singles", "Intercontinental", "Male", raw_show.map(|s| s.id)),
-        ("United States Championship", "Singles", "United States", "Male", smackdown_show.map(|s| s.id)),
-        ("Women's Intercontinental Championship", "Singles", "Women's Intercontinental", "Female", raw_show.map(|s| s.id)),
-        ("Women's United States Championship", "Singles", "Women's United States", "Female", smackdown_show.map(|s| s.id)),
-        
-        // Tier 3 - Tag Team Championships
-        ("World Tag Team Championship", "Tag Team", "World Tag Team", "Male", raw_show.map(|s| s.id)),
-        ("WWE Tag Team Championship", "Tag Team", "WWE Tag Team", "Male", smackdown_show.map(|s| s.id)),
-        ("Women's Tag Team Championship", "Tag Team", "Women's Tag Team", "Female", None), // Cross-brand
-        
-        // Tier 4 - Specialty Championships
-        ("Money in the Bank", "Singles", "Money in the Bank", "Mixed", None),
-        ("Hardcore Championship", "Singles", "Hardcore", "Mixed", None),
-        ("Speed Championship", "Singles", "Speed", "Mixed", None),
-        ("24/7 Championship", "Singles", "24/7", "Mixed", None),
-    ];
+    diesel::update(wrestlers.filter(id.eq(wrestler_id)))
+        .set(status.eq(new_status))
+        .returning(Wrestler::as_returning())
+        .get_result(conn)
+        .map_err(AppError::from)
+}
 
-    let mut title_count = 0;
-    for (name, title_type, division, gender, show_id) in test_titles {
-        internal_create_belt(&mut conn, name, title_type, division, gender, show_id, None, false)
-            .map_err(|e| format!("Failed to create title '{}': {}", name, e))?;
-        title_count += 1;
+/// Sets or clears a wrestler's home promotion
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+/// * `promotion_id` - Home promotion to set (None to clear)
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(AppError::NotFound)` - If the wrestler or promotion does not exist
+/// * `Err(AppError::Database)` - Database error if update fails
+/// 
+/// # Note
+/// The home promotion is a display/filter attribute only; show rosters and the
+/// global wrestler pool are unaffected.
+pub fn internal_set_wrestler_home_promotion(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+    promotion_id: Option<i32>,
+) -> Result<Wrestler, AppError> {
+    use crate::schema::{promotions, wrestlers};
+
+    if let Some(promotion_id) = promotion_id {
+        promotions::table
+            .find(promotion_id)
+            .select(promotions::id)
+            .first::<i32>(conn)
+            .optional()?
+            .ok_or_else(|| AppError::NotFound(format!("Promotion {} not found", promotion_id)))?;
     }
-    
-    // Assign wrestlers to show rosters
-    let all_wrestlers = internal_get_wrestlers(&mut conn).map_err(|e| format!("Error getting wrestlers: {}", e))?;
-    let raw_show_id = raw_show.map(|s| s.id).ok_or("RAW show not found")?;
-    let smackdown_show_id = smackdown_show.map(|s| s.id).ok_or("SmackDown show not found")?;
-    
-    // Assign all 5 wrestlers to RAW
-    for wrestler in &all_wrestlers {
-        internal_assign_wrestler_to_show(&mut conn, raw_show_id, wrestler.id)
-            .map_err(|e| format!("Failed to assign wrestler {} to RAW: {}", wrestler.name, e))?;
+
+    diesel::update(wrestlers::table.find(wrestler_id))
+        .set(wrestlers::home_promotion_id.eq(promotion_id))
+        .returning(Wrestler::as_returning())
+        .get_result(conn)
+        .map_err(AppError::from)
+}
+
+/// Gets all wrestlers whose home promotion is the given promotion
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `promotion_id` - ID of the promotion
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Wrestlers ordered by name
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_wrestlers_by_home_promotion(
+    conn: &mut SqliteConnection,
+    promotion_id: i32,
+) -> Result<Vec<Wrestler>, DieselError> {
+    use crate::schema::wrestlers;
+
+    wrestlers::table
+        .filter(wrestlers::home_promotion_id.eq(promotion_id))
+        .order(wrestlers::name.asc())
+        .load::<Wrestler>(conn)
+}
+
+/// Gets active wrestlers who have not been booked recently
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `days` - Number of days without a match before a wrestler counts as inactive
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Inactive wrestlers ordered by name
+/// * `Err(AppError::Validation)` - If `days` is negative
+/// * `Err(AppError::Database)` - Database error if query fails
+/// 
+/// # Note
+/// Only competitor appearances count. A match's date is its scheduled date,
+/// falling back to when it was created. Wrestlers who have never wrestled are
+/// included; injured and retired wrestlers are not. A window reaching past the
+/// earliest representable date only flags wrestlers who have never wrestled.
+pub fn internal_get_inactive_wrestlers(
+    conn: &mut SqliteConnection,
+    days: i64,
+) -> Result<Vec<Wrestler>, AppError> {
+    use crate::schema::{match_participants, matches, wrestlers};
+    use chrono::{NaiveDate, TimeDelta};
+    use diesel::dsl::max;
+    use std::collections::HashMap;
+
+    if days < 0 {
+        return Err(AppError::Validation(format!("days: {} cannot be negative", days)));
     }
-    
-    // Assign 3 wrestlers to SmackDown (Charlotte, Becky, and Stone Cold)
-    let smackdown_wrestlers = ["Charlotte Flair", "Becky Lynch", "Stone Cold Steve Austin"];
-    for wrestler in &all_wrestlers {
-        if smackdown_wrestlers.contains(&wrestler.name.as_str()) {
-            internal_assign_wrestler_to_show(&mut conn, smackdown_show_id, wrestler.id)
-                .map_err(|e| format!("Failed to assign wrestler {} to SmackDown: {}", wrestler.name, e))?;
+
+    let active_wrestlers = wrestlers::table
+        .filter(wrestlers::status.eq(ACTIVE_STATUS))
+        .order(wrestlers::name.asc())
+        .load::<Wrestler>(conn)?;
+
+    let last_scheduled = match_participants::table
+        .inner_join(matches::table.on(match_participants::match_id.eq(matches::id)))
+        .filter(match_participants::role.eq(COMPETITOR_ROLE))
+        .filter(matches::scheduled_date.is_not_null())
+        .group_by(match_participants::wrestler_id)
+        .select((match_participants::wrestler_id, max(matches::scheduled_date)))
+        .load::<(i32, Option<NaiveDate>)>(conn)?;
+
+    let last_unscheduled = match_participants::table
+        .inner_join(matches::table.on(match_participants::match_id.eq(matches::id)))
+        .filter(match_participants::role.eq(COMPETITOR_ROLE))
+        .filter(matches::scheduled_date.is_null())
+        .group_by(match_participants::wrestler_id)
+        .select((match_participants::wrestler_id, max(matches::created_at)))
+        .load::<(i32, Option<chrono::NaiveDateTime>)>(conn)?;
+
+    let mut last_match_dates: HashMap<i32, NaiveDate> = HashMap::new();
+    let latest = last_scheduled.into_iter().chain(
+        last_unscheduled
+            .into_iter()
+            .map(|(wrestler_id, created_at)| (wrestler_id, created_at.map(|created| created.date()))),
+    );
+    for (wrestler_id, match_date) in latest {
+        if let Some(match_date) = match_date {
+            let last = last_match_dates.entry(wrestler_id).or_insert(match_date);
+            *last = (*last).max(match_date);
         }
     }
-    
-    // Assign title holders
-    let all_titles = internal_get_titles(&mut conn).map_err(|e| format!("Error getting titles: {}", e))?;
-    
-    // Make The Rock the World Heavyweight Champion
-    if let Some(rock) = all_wrestlers.iter().find(|w| w.name == "The Rock") {
-        if let Some(whc) = all_titles.iter().find(|t| t.title.name == "World Heavyweight Championship") {
-            internal_update_title_holder(
-                &mut conn,
-                whc.title.id,
-                rock.id,
-                Some("Monday Night RAW"),
-                Some("Won in tournament final"),
-                None
-            ).map_err(|e| format!("Failed to assign World Heavyweight Championship: {}", e))?;
-        }
+
+    let cutoff = TimeDelta::try_days(days)
+        .and_then(|window| Utc::now().date_naive().checked_sub_signed(window))
+        .unwrap_or(NaiveDate::MIN);
+
+    Ok(active_wrestlers
+        .into_iter()
+        .filter(|wrestler| {
+            last_match_dates
+                .get(&wrestler.id)
+                .is_none_or(|last_match| *last_match < cutoff)
+        })
+        .collect())
+}
+
+/// Gets wrestlers who have never held a title
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `gender` - Optional gender to limit the results to
+/// * `exclude_retired` - Whether to leave out retired wrestlers
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Wrestlers without any reign, past or present, ordered by name
+/// * `Err(AppError::Validation)` - If the gender is unrecognised
+/// * `Err(AppError::Database)` - Database error if query fails
+pub fn internal_get_never_champions(
+    conn: &mut SqliteConnection,
+    gender: Option<&str>,
+    exclude_retired: bool,
+) -> Result<Vec<Wrestler>, AppError> {
+    use crate::schema::{title_holders, wrestlers};
+    use diesel::dsl::{exists, not};
+
+    let mut query = wrestlers::table
+        .filter(not(exists(
+            title_holders::table.filter(title_holders::wrestler_id.eq(wrestlers::id)),
+        )))
+        .into_boxed();
+
+    if let Some(gender) = gender {
+        query = query.filter(wrestlers::gender.eq(normalize_wrestler_gender(gender)?));
     }
-    
-    // Make Charlotte Flair the WWE Women's Champion
-    if let Some(charlotte) = all_wrestlers.iter().find(|w| w.name == "Charlotte Flair") {
-        if let Some(wwe_womens) = all_titles.iter().find(|t| t.title.name == "WWE Women's Championship") {
-            internal_update_title_holder(
-                &mut conn,
-                wwe_womens.title.id,
-                charlotte.id,
-                Some("Friday Night SmackDown"),
-                Some("Defeated previous champion"),
-                None
-            ).map_err(|e| format!("Failed to assign WWE Women's Championship: {}", e))?;
+
+    if exclude_retired {
+        query = query.filter(wrestlers::status.ne(RETIRED_STATUS));
+    }
+
+    query
+        .order(wrestlers::name.asc())
+        .load::<Wrestler>(conn)
+        .map_err(AppError::from)
+}
+
+/// Computes the roster-wide average of each power rating
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(RatingAverages)` - Average per rating, ignoring wrestlers without that rating
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_rating_averages(
+    conn: &mut SqliteConnection,
+) -> Result<RatingAverages, DieselError> {
+    let roster = crate::schema::wrestlers::table.load::<Wrestler>(conn)?;
+
+    let average = |rating: fn(&Wrestler) -> Option<i32>| {
+        let values: Vec<i32> = roster.iter().filter_map(rating).collect();
+        (!values.is_empty()).then(|| values.iter().sum::<i32>() as f64 / values.len() as f64)
+    };
+
+    Ok(RatingAverages {
+        strength: average(|w| w.strength),
+        speed: average(|w| w.speed),
+        agility: average(|w| w.agility),
+        stamina: average(|w| w.stamina),
+        charisma: average(|w| w.charisma),
+        technique: average(|w| w.technique),
+    })
+}
+
+/// Finds the most and least experienced active wrestlers by debut year
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(ExperienceExtremes)` - Earliest and latest debuting active wrestlers
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Wrestlers without a debut year are ignored. Ties go to the name that sorts first.
+pub fn internal_get_experience_extremes(
+    conn: &mut SqliteConnection,
+) -> Result<ExperienceExtremes, DieselError> {
+    use crate::schema::wrestlers;
+
+    let most_experienced = wrestlers::table
+        .filter(wrestlers::status.eq(ACTIVE_STATUS))
+        .filter(wrestlers::debut_year.is_not_null())
+        .order(wrestlers::debut_year.asc())
+        .then_order_by(wrestlers::name.asc())
+        .first::<Wrestler>(conn)
+        .optional()?;
+
+    let least_experienced = wrestlers::table
+        .filter(wrestlers::status.eq(ACTIVE_STATUS))
+        .filter(wrestlers::debut_year.is_not_null())
+        .order(wrestlers::debut_year.desc())
+        .then_order_by(wrestlers::name.asc())
+        .first::<Wrestler>(conn)
+        .optional()?;
+
+    Ok(ExperienceExtremes {
+        most_experienced,
+        least_experienced,
+    })
+}
+
+/// Gets wrestlers rated at or above a threshold in one power rating
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `field` - Rating to filter on, one of `RATING_FIELDS`
+/// * `min` - Lowest rating to include
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Matching wrestlers, highest rated first, then by name
+/// * `Err(AppError::Validation)` - If the field is not a known rating
+/// * `Err(AppError::Database)` - Database error if query fails
+/// 
+/// # Note
+/// Wrestlers with no value for the rating are never included.
+pub fn internal_get_wrestlers_above_rating(
+    conn: &mut SqliteConnection,
+    field: &str,
+    min: i32,
+) -> Result<Vec<Wrestler>, AppError> {
+    use crate::schema::wrestlers;
+
+    let query = wrestlers::table.into_boxed();
+    let query = match field {
+        "strength" => query.filter(wrestlers::strength.ge(min)).order(wrestlers::strength.desc()),
+        "speed" => query.filter(wrestlers::speed.ge(min)).order(wrestlers::speed.desc()),
+        "agility" => query.filter(wrestlers::agility.ge(min)).order(wrestlers::agility.desc()),
+        "stamina" => query.filter(wrestlers::stamina.ge(min)).order(wrestlers::stamina.desc()),
+        "charisma" => query.filter(wrestlers::charisma.ge(min)).order(wrestlers::charisma.desc()),
+        "technique" => query.filter(wrestlers::technique.ge(min)).order(wrestlers::technique.desc()),
+        _ => {
+            return Err(AppError::Validation(format!(
+                "field: '{}' is not one of {}",
+                field,
+                RATING_FIELDS.join(", ")
+            )))
         }
+    };
+
+    query
+        .then_order_by(wrestlers::name.asc())
+        .load::<Wrestler>(conn)
+        .map_err(AppError::from)
+}
+
+/// Gets wrestlers whose height falls within a range
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `min_inches` - Shortest height to include, in inches
+/// * `max_inches` - Tallest height to include, in inches
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Matching wrestlers, shortest first, then by name
+/// * `Err(AppError::Validation)` - If the minimum is above the maximum
+/// * `Err(AppError::Database)` - Database error if query fails
+/// 
+/// # Note
+/// Heights are stored as free text, so they are parsed with `parse_height_inches`
+/// and filtered in Rust. Wrestlers with a missing or unparseable height are left out.
+pub fn internal_get_wrestlers_by_height_range(
+    conn: &mut SqliteConnection,
+    min_inches: i32,
+    max_inches: i32,
+) -> Result<Vec<Wrestler>, AppError> {
+    use crate::schema::wrestlers;
+
+    if min_inches > max_inches {
+        return Err(AppError::Validation(format!(
+            "min_inches: {} is greater than max_inches {}",
+            min_inches, max_inches
+        )));
     }
-    
-    // Create sample matches
-    let match_data_list = vec![
-        // RAW matches
-        MatchData {
-            show_id: raw_show_id,
-            match_name: Some("World Heavyweight Championship Match".to_string()),
-            match_type: "Singles".to_string(),
-            match_stipulation: Some("Standard".to_string()),
-            scheduled_date: None,
-            match_order: Some(5),
-            is_title_match: true,
-            title_id: all_titles.iter().find(|t| t.title.name == "World Heavyweight Championship").map(|t| t.title.id),
-        },
-        MatchData {
-            show_id: raw_show_id,
-            match_name: Some("Grudge Match".to_string()),
-            match_type: "Singles".to_string(),
-            match_stipulation: Some("No Disqualification".to_string()),
-            scheduled_date: None,
-            match_order: Some(3),
-            is_title_match: false,
-            title_id: None,
-        },
-        MatchData {
-            show_id: raw_show_id,
-            match_name: Some("Opening Contest".to_string()),
-            match_type: "Singles".to_string(),
-            match_stipulation: Some("Standard".to_string()),
-            scheduled_date: None,
-            match_order: Some(1),
-            is_title_match: false,
-            title_id: None,
-        },
+
+    let candidates = wrestlers::table
+        .filter(wrestlers::height.is_not_null())
+        .order(wrestlers::name.asc())
+        .load::<Wrestler>(conn)?;
+
+    let mut in_range: Vec<(i32, Wrestler)> = candidates
+        .into_iter()
+        .filter_map(|wrestler| {
+            let inches = parse_height_inches(wrestler.height.as_deref()?)?;
+            (min_inches..=max_inches).contains(&inches).then_some((inches, wrestler))
+        })
+        .collect();
+    // Stable sort keeps the name ordering for equal heights
+    in_range.sort_by_key(|(inches, _)| *inches);
+
+    Ok(in_range.into_iter().map(|(_, wrestler)| wrestler).collect())
+}
+
+/// Compares two wrestlers' power ratings and records
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `a_id` - ID of the first wrestler
+/// * `b_id` - ID of the second wrestler
+/// 
+/// # Returns
+/// * `Ok(Comparison)` - Both wrestlers with rating and record deltas (first minus second)
+/// * `Err(DieselError)` - NotFound if either wrestler does not exist, or a database error
+/// 
+/// # Note
+/// A rating delta is skipped (None) rather than treating a missing rating as 0,
+/// so unrated wrestlers don't show misleading gaps
+pub fn internal_compare_wrestlers(
+    conn: &mut SqliteConnection,
+    a_id: i32,
+    b_id: i32,
+) -> Result<Comparison, DieselError> {
+    use crate::schema::wrestlers;
+
+    let wrestler_a = wrestlers::table.find(a_id).first::<Wrestler>(conn)?;
+    let wrestler_b = wrestlers::table.find(b_id).first::<Wrestler>(conn)?;
+
+    let delta = |rating: fn(&Wrestler) -> Option<i32>| {
+        rating(&wrestler_a).zip(rating(&wrestler_b)).map(|(a, b)| a - b)
+    };
+    let rating_deltas = RatingDeltas {
+        strength: delta(|w| w.strength),
+        speed: delta(|w| w.speed),
+        agility: delta(|w| w.agility),
+        stamina: delta(|w| w.stamina),
+        charisma: delta(|w| w.charisma),
+        technique: delta(|w| w.technique),
+    };
+    let win_rate_delta = wrestler_a
+        .win_rate()
+        .zip(wrestler_b.win_rate())
+        .map(|(a, b)| a - b);
+
+    Ok(Comparison {
+        rating_deltas,
+        wins_delta: wrestler_a.wins - wrestler_b.wins,
+        losses_delta: wrestler_a.losses - wrestler_b.losses,
+        win_rate_delta,
+        wrestler_a,
+        wrestler_b,
+    })
+}
+
+/// Ranks wrestlers by how many matches they have been booked in
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `limit` - Maximum number of wrestlers to return
+/// 
+/// # Returns
+/// * `Ok(Vec<(Wrestler, i64)>)` - Wrestlers with their match count, busiest first, then by name
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Counts match participant rows in any role, so it reflects bookings rather
+/// than the win/loss record. Wrestlers who were never booked are left out.
+pub fn internal_get_busiest_wrestlers(
+    conn: &mut SqliteConnection,
+    limit: i64,
+) -> Result<Vec<(Wrestler, i64)>, DieselError> {
+    use crate::schema::{match_participants, wrestlers};
+    use diesel::dsl::count_star;
+
+    wrestlers::table
+        .inner_join(match_participants::table)
+        .group_by(wrestlers::id)
+        .select((Wrestler::as_select(), count_star()))
+        .order((count_star().desc(), wrestlers::name.asc()))
+        .limit(limit)
+        .load::<(Wrestler, i64)>(conn)
+}
+
+/// Finds the wrestlers someone has faced most often
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+/// * `limit` - Maximum number of opponents to return
+/// 
+/// # Returns
+/// * `Ok(Vec<(Wrestler, i64)>)` - Opponents with the number of matches shared, most frequent first, then by name
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Only competitors count on both sides. Wrestlers on the same team in a match
+/// were partners rather than opponents, so that match is not counted for them.
+pub fn internal_get_frequent_opponents(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+    limit: i64,
+) -> Result<Vec<(Wrestler, i64)>, DieselError> {
+    use crate::schema::{match_participants, wrestlers};
+    use std::collections::HashMap;
+
+    let own_entries = match_participants::table
+        .filter(match_participants::wrestler_id.eq(wrestler_id))
+        .filter(match_participants::role.eq(COMPETITOR_ROLE))
+        .select((match_participants::match_id, match_participants::team_number))
+        .load::<(i32, Option<i32>)>(conn)?;
+    let own_teams: HashMap<i32, Option<i32>> = own_entries.into_iter().collect();
+
+    let others = match_participants::table
+        .filter(match_participants::match_id.eq_any(own_teams.keys().copied().collect::<Vec<_>>()))
+        .filter(match_participants::wrestler_id.ne(wrestler_id))
+        .filter(match_participants::role.eq(COMPETITOR_ROLE))
+        .select((
+            match_participants::match_id,
+            match_participants::wrestler_id,
+            match_participants::team_number,
+        ))
+        .load::<(i32, i32, Option<i32>)>(conn)?;
+
+    let mut encounters: HashMap<i32, i64> = HashMap::new();
+    for (match_id, opponent_id, team_number) in others {
+        let partners = team_number.is_some() && own_teams.get(&match_id) == Some(&team_number);
+        if !partners {
+            *encounters.entry(opponent_id).or_default() += 1;
+        }
+    }
+
+    let opponents = wrestlers::table
+        .filter(wrestlers::id.eq_any(encounters.keys().copied().collect::<Vec<_>>()))
+        .load::<Wrestler>(conn)?;
+
+    let mut ranked: Vec<(Wrestler, i64)> = opponents
+        .into_iter()
+        .map(|opponent| {
+            let count = encounters[&opponent.id];
+            (opponent, count)
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.name.cmp(&b.0.name)));
+    ranked.truncate(limit.max(0) as usize);
+
+    Ok(ranked)
+}
+
+/// Tauri command to create a new wrestler with basic information
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_data` - WrestlerData struct containing name and gender
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The newly created wrestler
+/// * `Err(AppError)` - Categorized error if creation fails
+#[tauri::command]
+pub fn create_wrestler(
+    state: State<'_, DbState>,
+    wrestler_data: WrestlerData,
+) -> Result<Wrestler, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+    let gender_str: String = wrestler_data.gender.into();
+
+    internal_create_wrestler(&mut conn, &wrestler_data.name, &gender_str, 0, 0)
+        .inspect(|wrestler| {
+            info!("Wrestler '{}' created successfully", wrestler.name);
+        })
+        .map_err(|e| {
+            error!("Error creating wrestler: {}", e);
+            e.with_context("Failed to create wrestler")
+        })
+}
+
+/// Tauri command to create a user-defined wrestler with full details
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_data` - EnhancedWrestlerData with all wrestler attributes
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The newly created wrestler
+/// * `Err(AppError)` - Categorized error if creation fails
+#[tauri::command]
+pub fn create_user_wrestler(
+    state: State<'_, DbState>,
+    wrestler_data: EnhancedWrestlerData,
+) -> Result<Wrestler, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_create_user_wrestler(&mut conn, &wrestler_data)
+        .inspect(|wrestler| {
+            info!("User wrestler '{}' created successfully", wrestler.name);
+        })
+        .map_err(|e| {
+            error!("Error creating user wrestler: {}", e);
+            e.with_context("Failed to create user wrestler")
+        })
+}
+
+/// Tauri command to create a user-defined wrestler and their signature moves at once
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_data` - EnhancedWrestlerData with all wrestler attributes
+/// * `moves` - Signature moves as (move name, move type) pairs
+/// 
+/// # Returns
+/// * `Ok((Wrestler, Vec<SignatureMove>))` - The new wrestler and their moves
+/// * `Err(AppError)` - Categorized error if validation fails or nothing was created
+#[tauri::command]
+pub fn create_wrestler_with_moves(
+    state: State<'_, DbState>,
+    wrestler_data: EnhancedWrestlerData,
+    moves: Vec<(String, String)>,
+) -> Result<(Wrestler, Vec<SignatureMove>), AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_create_wrestler_with_moves(&mut conn, &wrestler_data, &moves)
+        .inspect(|(wrestler, signature_moves)| {
+            info!(
+                "Wrestler '{}' created with {} signature move(s)",
+                wrestler.name,
+                signature_moves.len()
+            );
+        })
+        .map_err(|e| {
+            error!("Error creating wrestler with moves: {}", e);
+            e.with_context("Failed to create wrestler")
+        })
+}
+
+/// Tauri command to create a new wrestler from an existing one
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `source_id` - ID of the wrestler to copy
+/// * `new_name` - Ring name for the new wrestler
+/// * `copy_moves` - Whether to copy signature moves (defaults to false)
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The new wrestler
+/// * `Err(AppError)` - Categorized error if validation or creation fails
+#[tauri::command]
+pub fn clone_wrestler(
+    state: State<'_, DbState>,
+    source_id: i32,
+    new_name: String,
+    copy_moves: Option<bool>,
+) -> Result<Wrestler, AppError> {
+    validate_id(source_id, "source_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_clone_wrestler(&mut conn, source_id, &new_name, copy_moves.unwrap_or(false))
+        .inspect(|wrestler| {
+            info!("Wrestler {} cloned as '{}'", source_id, wrestler.name);
+        })
+        .map_err(|e| {
+            error!("Error cloning wrestler: {}", e);
+            e.with_context("Failed to clone wrestler")
+        })
+}
+
+/// Tauri command to fetch all wrestlers from the global pool
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Vector of all wrestlers
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_wrestlers(state: State<'_, DbState>) -> Result<Vec<Wrestler>, String> {
+    let mut conn = get_connection(&state)?;
+
+    internal_get_wrestlers(&mut conn).map_err(|e| {
+        error!("Error loading wrestlers: {}", e);
+        format!("Failed to load wrestlers: {}", e)
+    })
+}
+
+/// Tauri command to fetch all unassigned wrestlers (not on any show roster)
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Vector of wrestlers not assigned to any show
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_unassigned_wrestlers(state: State<'_, DbState>) -> Result<Vec<Wrestler>, String> {
+    let mut conn = get_connection(&state)?;
+
+    internal_get_unassigned_wrestlers(&mut conn).map_err(|e| {
+        error!("Error loading unassigned wrestlers: {}", e);
+        format!("Failed to load unassigned wrestlers: {}", e)
+    })
+}
+
+/// Tauri command to fetch free agents with their draft stats
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<FreeAgent>)` - Unassigned wrestlers, best rated first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_free_agents(state: State<'_, DbState>) -> Result<Vec<FreeAgent>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_free_agents(&mut conn).map_err(|e| {
+        error!("Error loading free agents: {}", e);
+        AppError::from(e).with_context("Failed to load free agents")
+    })
+}
+
+/// Tauri command to get rostered wrestlers who have never been booked
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Benchwarmers ordered by name
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_benchwarmers(state: State<'_, DbState>) -> Result<Vec<Wrestler>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_benchwarmers(&mut conn).map_err(|e| {
+        error!("Error loading benchwarmers: {}", e);
+        AppError::from(e).with_context("Failed to load benchwarmers")
+    })
+}
+
+/// Tauri command to fetch a specific wrestler by ID
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - The ID of the wrestler to retrieve
+/// 
+/// # Returns
+/// * `Ok(Some(Wrestler))` - The wrestler if found
+/// * `Ok(None)` - If no wrestler with the given ID exists
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_wrestler_by_id(state: State<'_, DbState>, wrestler_id: i32) -> Result<Option<Wrestler>, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_wrestler_by_id(&mut conn, wrestler_id).map_err(|e| {
+        error!("Error loading wrestler: {}", e);
+        AppError::from(e).with_context("Failed to load wrestler")
+    })
+}
+
+/// Tauri command to count wrestlers per gender
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<(String, i64)>)` - Each distinct gender with its wrestler count
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn count_wrestlers_by_gender(state: State<'_, DbState>) -> Result<Vec<(String, i64)>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_count_wrestlers_by_gender(&mut conn).map_err(|e| {
+        error!("Error counting wrestlers by gender: {}", e);
+        AppError::from(e).with_context("Failed to count wrestlers by gender")
+    })
+}
+
+/// Tauri command to count wrestlers per debut decade
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<(String, i64)>)` - Decade labels with counts, "Unknown" last
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn count_wrestlers_by_decade(state: State<'_, DbState>) -> Result<Vec<(String, i64)>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_count_wrestlers_by_decade(&mut conn).map_err(|e| {
+        error!("Error counting wrestlers by decade: {}", e);
+        AppError::from(e).with_context("Failed to count wrestlers by decade")
+    })
+}
+
+
+/// Tauri command to update a wrestler's power ratings
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `strength` - New strength rating (1-10, None to keep)
+/// * `speed` - New speed rating (1-10, None to keep)
+/// * `agility` - New agility rating (1-10, None to keep)
+/// * `stamina` - New stamina rating (1-10, None to keep)
+/// * `charisma` - New charisma rating (1-10, None to keep)
+/// * `technique` - New technique rating (1-10, None to keep)
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(String)` - Error message if update fails
+#[tauri::command]
+pub fn update_wrestler_power_ratings(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+    strength: Option<i32>,
+    speed: Option<i32>,
+    agility: Option<i32>,
+    stamina: Option<i32>,
+    charisma: Option<i32>,
+    technique: Option<i32>,
+) -> Result<Wrestler, String> {
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+
+    internal_update_wrestler_power_ratings(
+        &mut conn, 
+        wrestler_id, 
+        strength, 
+        speed, 
+        agility, 
+        stamina, 
+        charisma, 
+        technique
+    )
+    .inspect(|wrestler| {
+        info!("Wrestler '{}' power ratings updated", wrestler.name);
+    })
+    .map_err(|e| {
+        error!("Error updating wrestler power ratings: {}", e);
+        format!("Failed to update wrestler power ratings: {}", e)
+    })
+}
+
+/// Tauri command to update a wrestler's basic statistics
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `height` - New height (e.g., "6'2\"")
+/// * `weight` - New weight (e.g., "220 lbs")
+/// * `debut_year` - New debut year
+/// * `wins` - New win count
+/// * `losses` - New loss count
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(String)` - Error message if update fails
+#[tauri::command]
+pub fn update_wrestler_basic_stats(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+    height: Option<String>,
+    weight: Option<String>,
+    debut_year: Option<i32>,
+    wins: i32,
+    losses: i32,
+) -> Result<Wrestler, String> {
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+
+    internal_update_wrestler_basic_stats(
+        &mut conn, 
+        wrestler_id, 
+        height, 
+        weight, 
+        debut_year, 
+        wins, 
+        losses
+    )
+    .inspect(|wrestler| {
+        info!("Wrestler '{}' basic stats updated", wrestler.name);
+    })
+    .map_err(|e| {
+        error!("Error updating wrestler basic stats: {}", e);
+        format!("Failed to update wrestler basic stats: {}", e)
+    })
+}
+
+/// Tauri command to update a wrestler's ring name and nickname
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `name` - New ring name
+/// * `nickname` - New nickname or catchphrase
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(String)` - Error message if update fails
+#[tauri::command]
+pub fn update_wrestler_name(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+    name: String,
+    nickname: Option<String>,
+) -> Result<Wrestler, String> {
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+
+    internal_update_wrestler_name(&mut conn, wrestler_id, &name, nickname)
+        .inspect(|wrestler| {
+            info!("Wrestler '{}' name updated", wrestler.name);
+        })
+        .map_err(|e| {
+            error!("Error updating wrestler name: {}", e);
+            format!("Failed to update wrestler name: {}", e)
+        })
+}
+
+/// Tauri command to update a wrestler's real name
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `real_name` - New real name (None to clear)
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(String)` - Error message if update fails
+#[tauri::command]
+pub fn update_wrestler_real_name(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+    real_name: Option<String>,
+) -> Result<Wrestler, String> {
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+
+    internal_update_wrestler_real_name(&mut conn, wrestler_id, real_name)
+        .inspect(|wrestler| {
+            info!("Wrestler '{}' real name updated", wrestler.name);
+        })
+        .map_err(|e| {
+            error!("Error updating wrestler real name: {}", e);
+            format!("Failed to update wrestler real name: {}", e)
+        })
+}
+
+/// Tauri command to update a wrestler's biography
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `biography` - New biography text (None to clear)
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(String)` - Error message if update fails
+#[tauri::command]
+pub fn update_wrestler_biography(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+    biography: Option<String>,
+) -> Result<Wrestler, String> {
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+
+    internal_update_wrestler_biography(&mut conn, wrestler_id, biography)
+        .inspect(|wrestler| {
+            info!("Wrestler '{}' biography updated", wrestler.name);
+        })
+        .map_err(|e| {
+            error!("Error updating wrestler biography: {}", e);
+            format!("Failed to update wrestler biography: {}", e)
+        })
+}
+
+/// Tauri command to update all editable fields of a wrestler at once
+///
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler to update
+/// * `wrestler_data` - Complete wrestler profile to persist
+///
+/// # Returns
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(String)` - Error message if update fails
+#[tauri::command]
+pub fn update_wrestler(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+    wrestler_data: EnhancedWrestlerData,
+) -> Result<Wrestler, String> {
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+
+    internal_update_wrestler_full(&mut conn, wrestler_id, &wrestler_data)
+        .inspect(|wrestler| {
+            info!("Wrestler '{}' updated", wrestler.name);
+        })
+        .map_err(|e| {
+            error!("Error updating wrestler: {}", e);
+            format!("Failed to update wrestler: {}", e)
+        })
+}
+
+/// Tauri command to delete a wrestler (only user-created wrestlers)
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler to delete
+/// 
+/// # Returns
+/// * `Ok(String)` - Success message
+/// * `Err(String)` - Error message if deletion fails or wrestler is system-created
+#[tauri::command]
+pub fn delete_wrestler(state: State<'_, DbState>, wrestler_id: i32) -> Result<String, String> {
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+
+    internal_delete_wrestler(&mut conn, wrestler_id)
+        .inspect(|_| {
+            info!("Wrestler with ID {} deleted successfully", wrestler_id);
+        })
+        .map_err(|e| {
+            error!("Error deleting wrestler: {}", e);
+            match e {
+                DieselError::RollbackTransaction => "Cannot delete system wrestler - only user-created wrestlers can be deleted".to_string(),
+                DieselError::NotFound => "Wrestler not found".to_string(),
+                _ => format!("Failed to delete wrestler: {}", e),
+            }
+        })
+        .map(|_| "Wrestler deleted successfully".to_string())
+}
+
+/// Tauri command to preview what deleting a wrestler would remove
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
+/// 
+/// # Returns
+/// * `Ok(DeletionImpact)` - Counts of the associated records
+/// * `Err(AppError)` - Categorized error if the wrestler is missing or the query fails
+#[tauri::command]
+pub fn get_wrestler_deletion_impact(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+) -> Result<DeletionImpact, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_deletion_impact(&mut conn, wrestler_id).map_err(|e| {
+        error!("Error fetching deletion impact for wrestler {}: {}", wrestler_id, e);
+        AppError::from(e).with_context("Failed to fetch deletion impact")
+    })
+}
+
+/// Tauri command to change a wrestler's availability status
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
+/// * `status` - New status ("Active", "Injured" or "Retired")
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(AppError)` - Categorized error if validation or the update fails
+#[tauri::command]
+pub fn set_wrestler_status(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+    status: String,
+) -> Result<Wrestler, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_set_wrestler_status(&mut conn, wrestler_id, &status)
+        .inspect(|wrestler| {
+            info!("Wrestler '{}' status set to {}", wrestler.name, wrestler.status);
+        })
+        .map_err(|e| {
+            error!("Error updating wrestler status: {}", e);
+            e.with_context("Failed to update wrestler status")
+        })
+}
+
+/// Tauri command to set or clear a wrestler's home promotion
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
+/// * `promotion_id` - Home promotion to set (None to clear)
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The updated wrestler
+/// * `Err(AppError)` - Categorized error if the wrestler or promotion is missing or the update fails
+#[tauri::command]
+pub fn set_wrestler_home_promotion(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+    promotion_id: Option<i32>,
+) -> Result<Wrestler, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+    if let Some(id) = promotion_id {
+        validate_id(id, "promotion_id")?;
+    }
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_set_wrestler_home_promotion(&mut conn, wrestler_id, promotion_id)
+        .inspect(|wrestler| {
+            info!("Wrestler '{}' home promotion set to {:?}", wrestler.name, wrestler.home_promotion_id);
+        })
+        .map_err(|e| {
+            error!("Error updating wrestler home promotion: {}", e);
+            e.with_context("Failed to update wrestler home promotion")
+        })
+}
+
+/// Tauri command to list the wrestlers whose home promotion is the given promotion
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `promotion_id` - ID of the promotion
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Wrestlers ordered by name
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_wrestlers_by_home_promotion(
+    state: State<'_, DbState>,
+    promotion_id: i32,
+) -> Result<Vec<Wrestler>, AppError> {
+    validate_id(promotion_id, "promotion_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_wrestlers_by_home_promotion(&mut conn, promotion_id).map_err(|e| {
+        error!("Error loading wrestlers for home promotion {}: {}", promotion_id, e);
+        AppError::from(e).with_context("Failed to load wrestlers by home promotion")
+    })
+}
+
+/// Tauri command to list active wrestlers who have not been booked recently
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `days` - Number of days without a match before a wrestler counts as inactive
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Inactive wrestlers ordered by name
+/// * `Err(AppError)` - Validation error for a negative window, or a database error
+#[tauri::command]
+pub fn get_inactive_wrestlers(
+    state: State<'_, DbState>,
+    days: i64,
+) -> Result<Vec<Wrestler>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_inactive_wrestlers(&mut conn, days).map_err(|e| {
+        error!("Error fetching inactive wrestlers: {}", e);
+        e.with_context("Failed to fetch inactive wrestlers")
+    })
+}
+
+/// Tauri command to get wrestlers who have never held a title
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `gender` - Optional gender to limit the results to
+/// * `exclude_retired` - Whether to leave out retired wrestlers (defaults to false)
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Wrestlers without any reign, ordered by name
+/// * `Err(AppError)` - Categorized error if the gender is invalid or the query fails
+#[tauri::command]
+pub fn get_never_champions(
+    state: State<'_, DbState>,
+    gender: Option<String>,
+    exclude_retired: Option<bool>,
+) -> Result<Vec<Wrestler>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_never_champions(&mut conn, gender.as_deref(), exclude_retired.unwrap_or(false))
+        .map_err(|e| {
+            error!("Error fetching never champions: {}", e);
+            e.with_context("Failed to fetch wrestlers who have never held a title")
+        })
+}
+
+/// Tauri command to get the roster-wide average of each power rating
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(RatingAverages)` - Average per rating for radar chart comparisons
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_rating_averages(state: State<'_, DbState>) -> Result<RatingAverages, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_rating_averages(&mut conn).map_err(|e| {
+        error!("Error computing rating averages: {}", e);
+        AppError::from(e).with_context("Failed to compute rating averages")
+    })
+}
+
+/// Tauri command to get the most and least experienced active wrestlers
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(ExperienceExtremes)` - Earliest and latest debuting active wrestlers
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_experience_extremes(state: State<'_, DbState>) -> Result<ExperienceExtremes, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_experience_extremes(&mut conn).map_err(|e| {
+        error!("Error finding experience extremes: {}", e);
+        AppError::from(e).with_context("Failed to find experience extremes")
+    })
+}
+
+/// Tauri command to get wrestlers rated at or above a threshold
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `field` - Rating to filter on (strength, speed, agility, stamina, charisma or technique)
+/// * `min` - Lowest rating to include
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Matching wrestlers, highest rated first
+/// * `Err(AppError)` - Validation error for an unknown field, or a database error
+#[tauri::command]
+pub fn get_wrestlers_above_rating(
+    state: State<'_, DbState>,
+    field: String,
+    min: i32,
+) -> Result<Vec<Wrestler>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_wrestlers_above_rating(&mut conn, &field, min).map_err(|e| {
+        error!("Error filtering wrestlers by {} rating: {}", field, e);
+        e.with_context("Failed to filter wrestlers by rating")
+    })
+}
+
+/// Tauri command to get wrestlers whose height falls within a range
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `min_inches` - Shortest height to include, in inches
+/// * `max_inches` - Tallest height to include, in inches
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Matching wrestlers, shortest first
+/// * `Err(AppError)` - Categorized error if the range is invalid or the query fails
+#[tauri::command]
+pub fn get_wrestlers_by_height_range(
+    state: State<'_, DbState>,
+    min_inches: i32,
+    max_inches: i32,
+) -> Result<Vec<Wrestler>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_wrestlers_by_height_range(&mut conn, min_inches, max_inches).map_err(|e| {
+        error!("Error filtering wrestlers by height {}-{}: {}", min_inches, max_inches, e);
+        e.with_context("Failed to filter wrestlers by height")
+    })
+}
+
+/// Tauri command to compare two wrestlers side by side
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `a_id` - ID of the first wrestler
+/// * `b_id` - ID of the second wrestler
+/// 
+/// # Returns
+/// * `Ok(Comparison)` - Both wrestlers with rating and record deltas
+/// * `Err(AppError)` - Categorized error if either wrestler is missing or the query fails
+#[tauri::command]
+pub fn compare_wrestlers(state: State<'_, DbState>, a_id: i32, b_id: i32) -> Result<Comparison, AppError> {
+    validate_id(a_id, "a_id")?;
+    validate_id(b_id, "b_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_compare_wrestlers(&mut conn, a_id, b_id).map_err(|e| {
+        error!("Error comparing wrestlers {} and {}: {}", a_id, b_id, e);
+        AppError::from(e).with_context("Failed to compare wrestlers")
+    })
+}
+
+/// Tauri command to rank wrestlers by how many matches they have been booked in
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `limit` - Maximum number of wrestlers to return
+/// 
+/// # Returns
+/// * `Ok(Vec<(Wrestler, i64)>)` - Wrestlers with their match count, busiest first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_busiest_wrestlers(state: State<'_, DbState>, limit: i64) -> Result<Vec<(Wrestler, i64)>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_busiest_wrestlers(&mut conn, limit).map_err(|e| {
+        error!("Error ranking busiest wrestlers: {}", e);
+        AppError::from(e).with_context("Failed to rank busiest wrestlers")
+    })
+}
+
+/// Tauri command to find the wrestlers someone has faced most often
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
+/// * `limit` - Maximum number of opponents to return
+/// 
+/// # Returns
+/// * `Ok(Vec<(Wrestler, i64)>)` - Opponents with the number of matches shared, most frequent first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_frequent_opponents(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+    limit: i64,
+) -> Result<Vec<(Wrestler, i64)>, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_frequent_opponents(&mut conn, wrestler_id, limit).map_err(|e| {
+        error!("Error finding frequent opponents of wrestler {}: {}", wrestler_id, e);
+        AppError::from(e).with_context("Failed to find frequent opponents")
+    })
+}
+
+/// Tauri command to get a wrestler's signature moves
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
+/// * `include_inactive` - Whether to include retired moves (defaults to false)
+/// 
+/// # Returns
+/// * `Ok(Vec<SignatureMove>)` - The wrestler's moves
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_signature_moves_for_wrestler(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+    include_inactive: Option<bool>,
+) -> Result<Vec<SignatureMove>, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_signature_moves_for_wrestler(&mut conn, wrestler_id, include_inactive.unwrap_or(false))
+        .map_err(|e| {
+            error!("Error loading signature moves for wrestler {}: {}", wrestler_id, e);
+            AppError::from(e).with_context("Failed to load signature moves")
+        })
+}
+
+/// Tauri command to retire or reactivate a signature move
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `move_id` - ID of the signature move
+/// * `active` - New activity state
+/// 
+/// # Returns
+/// * `Ok(SignatureMove)` - The updated signature move
+/// * `Err(AppError)` - Categorized error if update fails
+#[tauri::command]
+pub fn set_signature_move_active(
+    state: State<'_, DbState>,
+    move_id: i32,
+    active: bool,
+) -> Result<SignatureMove, AppError> {
+    validate_id(move_id, "move_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_set_signature_move_active(&mut conn, move_id, active)
+        .inspect(|signature_move| {
+            info!("Signature move '{}' active set to {}", signature_move.move_name, active);
+        })
+        .map_err(|e| {
+            error!("Error updating signature move: {}", e);
+            AppError::from(e).with_context("Failed to update signature move")
+        })
+}
+
+/// Tauri command to list all signature moves of one type across the roster
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `move_type` - Type of move ("primary" or "secondary")
+/// 
+/// # Returns
+/// * `Ok(Vec<(SignatureMove, String)>)` - Moves with the performing wrestler's name
+/// * `Err(AppError)` - Categorized error if the type is invalid or the query fails
+#[tauri::command]
+pub fn get_signature_moves_by_type(
+    state: State<'_, DbState>,
+    move_type: String,
+) -> Result<Vec<(SignatureMove, String)>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_signature_moves_by_type(&mut conn, &move_type)
+        .map_err(|e| {
+            error!("Error loading signature moves by type: {}", e);
+            e.with_context("Failed to load signature moves")
+        })
+}
+
+// ===== Title Operations =====
+
+/// Prestige tier for world championships (mirrors the frontend constants)
+pub const WORLD_CHAMPIONSHIP_TIER: i32 = 1;
+/// Prestige tier for secondary singles championships
+pub const SECONDARY_CHAMPIONSHIP_TIER: i32 = 2;
+/// Prestige tier for tag team championships
+pub const TAG_TEAM_CHAMPIONSHIP_TIER: i32 = 3;
+/// Prestige tier for specialty titles
+pub const SPECIALTY_CHAMPIONSHIP_TIER: i32 = 4;
+
+/// Maximum length of the event fields recorded with a title change
+pub const MAX_EVENT_FIELD_LENGTH: usize = 255;
+
+/// Validates the optional event fields recorded when a title changes hands
+/// 
+/// # Arguments
+/// * `event_name` - Optional event name
+/// * `event_location` - Optional event location
+/// * `change_method` - Optional method of title change or reason for vacancy
+/// 
+/// # Returns
+/// * `Ok(())` - If every provided field is within `MAX_EVENT_FIELD_LENGTH`
+/// * `Err(AppError::Validation)` - Message naming the field that is too long
+pub fn validate_event_fields(
+    event_name: Option<&str>,
+    event_location: Option<&str>,
+    change_method: Option<&str>,
+) -> Result<(), AppError> {
+    let fields = [
+        (event_name, "Event name too long"),
+        (event_location, "Event location too long"),
+        (change_method, "Change method too long"),
+    ];
+
+    for (value, message) in fields {
+        if value.is_some_and(|v| v.len() > MAX_EVENT_FIELD_LENGTH) {
+            return Err(AppError::Validation(message.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates a new championship title (internal function)
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `name` - Title name (e.g., "WWE Championship")
+/// * `title_type` - Type of title ("Singles", "Tag Team", etc.)
+/// * `division` - Division ("World", "Intercontinental", etc.)
+/// * `gender` - Gender restriction ("Male", "Female", "Mixed"), normalized before saving
+/// * `show_id` - Optional show assignment (None for cross-brand)
+/// * `current_holder_id` - Optional initial champion ID
+/// * `is_user_created` - Whether this is a user-created title
+/// 
+/// # Returns
+/// * `Ok(Title)` - The newly created title
+/// * `Err(AppError::Validation)` - If the gender is unrecognised
+/// * `Err(AppError::Database)` - Database error if creation fails
+/// 
+/// # Note
+/// Prestige tier is automatically calculated based on division
+pub fn internal_create_belt(
+    conn: &mut SqliteConnection,
+    name: &str,
+    title_type: &str,
+    division: &str,
+    gender: &str,
+    show_id: Option<i32>,
+    current_holder_id: Option<i32>,
+    is_user_created: bool,
+) -> Result<Title, AppError> {
+    let gender = normalize_title_gender(gender)?;
+
+    // Calculate prestige tier based on division
+    let prestige_tier = match division {
+        "World" | "WWE Championship" | "Women's World" | "WWE Women's Championship" => WORLD_CHAMPIONSHIP_TIER,
+        "Intercontinental" | "United States" | "Women's Intercontinental" | "Women's United States" => SECONDARY_CHAMPIONSHIP_TIER,
+        "World Tag Team" | "WWE Tag Team" | "Women's Tag Team" => TAG_TEAM_CHAMPIONSHIP_TIER,
+        _ => SPECIALTY_CHAMPIONSHIP_TIER, // Specialty titles
+    };
+
+    // New titles are listed after every existing title until reordered
+    let display_order = crate::schema::titles::table
+        .select(diesel::dsl::max(crate::schema::titles::display_order))
+        .first::<Option<i32>>(conn)?
+        .map_or(0, |max_order| max_order + 1);
+
+    let new_title = NewTitle {
+        name: name.to_string(),
+        current_holder_id,
+        title_type: title_type.to_string(),
+        division: division.to_string(),
+        prestige_tier,
+        gender,
+        show_id,
+        is_active: true,
+        is_user_created: Some(is_user_created),
+        display_order,
+    };
+
+    diesel::insert_into(crate::schema::titles::dsl::titles)
+        .values(&new_title)
+        .returning(Title::as_returning())
+        .get_result(conn)
+        .map_err(AppError::from)
+}
+
+/// Tauri command to create a new championship title
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `title_data` - TitleData struct with all title attributes
+/// 
+/// # Returns
+/// * `Ok(Title)` - The newly created title
+/// * `Err(AppError)` - Categorized error if creation fails
+#[tauri::command]
+pub fn create_belt(state: State<'_, DbState>, title_data: TitleData) -> Result<Title, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_create_belt(
+        &mut conn,
+        &title_data.name,
+        &title_data.title_type,
+        &title_data.division,
+        &title_data.gender,
+        title_data.show_id,
+        title_data.current_holder_id,
+        true, // User-created titles
+    )
+    .inspect(|title| {
+        info!("Title '{}' created successfully", title.name);
+    })
+    .map_err(|e| {
+        error!("Error creating title: {}", e);
+        e.with_context("Failed to create title")
+    })
+}
+
+/// A title seeded by a title set template: (name, title type, division, gender)
+type TitleTemplate = (&'static str, &'static str, &'static str, &'static str);
+
+/// Title set templates available to `create_title_set`, by template name
+/// 
+/// - "standard": a men's world, two secondary and a tag team championship
+/// - "womens": the women's equivalents of the standard set
+const TITLE_SET_TEMPLATES: &[(&str, &[TitleTemplate])] = &[
+    (
+        "standard",
+        &[
+            ("World Heavyweight Championship", "Singles", "World", "Male"),
+            ("Intercontinental Championship", "Singles", "Intercontinental", "Male"),
+            ("United States Championship", "Singles", "United States", "Male"),
+            ("World Tag Team Championship", "Tag Team", "World Tag Team", "Male"),
+        ],
+    ),
+    (
+        "womens",
+        &[
+            ("Women's World Championship", "Singles", "Women's World", "Female"),
+            ("Women's Intercontinental Championship", "Singles", "Women's Intercontinental", "Female"),
+            ("Women's United States Championship", "Singles", "Women's United States", "Female"),
+            ("Women's Tag Team Championship", "Tag Team", "Women's Tag Team", "Female"),
+        ],
+    ),
+];
+
+/// Creates every title from a title set template
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - Optional show to assign the new titles to (None for cross-brand)
+/// * `template` - Template name ("standard" or "womens")
+/// 
+/// # Returns
+/// * `Ok(Vec<Title>)` - The titles that were created
+/// * `Err(AppError::Validation)` - If the template is not recognized
+/// * `Err(AppError::Database)` - Database error if creation fails (nothing is created)
+/// 
+/// # Note
+/// Titles whose name already exists are skipped, so applying a template twice
+/// creates nothing the second time. Runs in a single transaction.
+pub fn internal_create_title_set(
+    conn: &mut SqliteConnection,
+    show_id: Option<i32>,
+    template: &str,
+) -> Result<Vec<Title>, AppError> {
+    use crate::schema::titles;
+
+    let (_, template_titles) = TITLE_SET_TEMPLATES
+        .iter()
+        .find(|(name, _)| *name == template)
+        .ok_or_else(|| {
+            let names: Vec<&str> = TITLE_SET_TEMPLATES.iter().map(|(name, _)| *name).collect();
+            AppError::Validation(format!(
+                "template: '{}' is not one of {}",
+                template,
+                names.join(", ")
+            ))
+        })?;
+
+    conn.transaction::<Vec<Title>, AppError, _>(|conn| {
+        let mut created = Vec::new();
+
+        for &(name, title_type, division, gender) in template_titles.iter() {
+            let existing = titles::table
+                .filter(titles::name.eq(name))
+                .count()
+                .get_result::<i64>(conn)?;
+            if existing > 0 {
+                continue;
+            }
+
+            created.push(internal_create_belt(
+                conn, name, title_type, division, gender, show_id, None, true,
+            )?);
+        }
+
+        Ok(created)
+    })
+}
+
+/// Tauri command to create every title from a title set template
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - Optional show to assign the new titles to (None for cross-brand)
+/// * `template` - Template name ("standard" or "womens")
+/// 
+/// # Returns
+/// * `Ok(Vec<Title>)` - The titles that were created
+/// * `Err(AppError)` - Categorized error if the template is unknown or creation fails
+#[tauri::command]
+pub fn create_title_set(
+    state: State<'_, DbState>,
+    show_id: Option<i32>,
+    template: String,
+) -> Result<Vec<Title>, AppError> {
+    if let Some(id) = show_id {
+        validate_id(id, "show_id")?;
+    }
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_create_title_set(&mut conn, show_id, &template)
+        .inspect(|created| info!("Created {} titles from the '{}' template", created.len(), template))
+        .map_err(|e| {
+            error!("Error creating title set: {}", e);
+            e.with_context("Failed to create title set")
+        })
+}
+
+/// Loads the holders of a title with their names and genders
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title
+/// * `as_of` - Moment to look up the holders at (None for the current holders)
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleHolderInfo>)` - Holders at that moment (empty if vacant, two for tag titles)
+/// * `Err(DieselError)` - Database error if query fails
+fn load_holders_for_title(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+    as_of: Option<chrono::NaiveDateTime>,
+) -> Result<Vec<TitleHolderInfo>, DieselError> {
+    use crate::schema::{title_holders, wrestlers};
+
+    let mut query = title_holders::table
+        .inner_join(wrestlers::table.on(title_holders::wrestler_id.eq(wrestlers::id)))
+        .filter(title_holders::title_id.eq(title_id))
+        .select((TitleHolder::as_select(), wrestlers::name, wrestlers::gender))
+        .into_boxed();
+
+    query = match as_of {
+        Some(moment) => query
+            .filter(title_holders::held_since.le(moment))
+            .filter(
+                title_holders::held_until
+                    .is_null()
+                    .or(title_holders::held_until.gt(moment)),
+            ),
+        None => query.filter(title_holders::held_until.is_null()),
+    };
+
+    let holders_data = query.load::<(TitleHolder, String, String)>(conn)?;
+
+    Ok(holders_data
+        .into_iter()
+        .map(|(holder, wrestler_name, wrestler_gender)| TitleHolderInfo {
+            holder,
+            wrestler_name,
+            wrestler_gender,
+        })
+        .collect())
+}
+
+/// Pairs each title with its current holders and the first holder's days held
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `titles` - Titles to attach holders to, in display order
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - The titles in the same order with holder information
+/// * `Err(DieselError)` - Database error if query fails
+fn attach_current_holders(
+    conn: &mut SqliteConnection,
+    titles: Vec<Title>,
+) -> Result<Vec<TitleWithHolders>, DieselError> {
+    let mut titles_with_holders = Vec::new();
+
+    for title in titles {
+        let current_holders = load_holders_for_title(conn, title.id, None)?;
+
+        // Calculate days held for the first holder (for single titles)
+        let days_held = if let Some(first_holder) = current_holders.first() {
+            let now = Utc::now().naive_utc();
+            let duration = now - first_holder.holder.held_since;
+            Some((duration.num_days()) as i32)
+        } else {
+            None
+        };
+
+        titles_with_holders.push(TitleWithHolders {
+            title,
+            current_holders,
+            days_held,
+        });
+    }
+
+    Ok(titles_with_holders)
+}
+
+/// Gets all titles with their current holders (internal function)
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Vector of titles with holder information
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Returns all active titles from the global pool, ordered by prestige tier then display order
+pub fn internal_get_titles(conn: &mut SqliteConnection) -> Result<Vec<TitleWithHolders>, DieselError> {
+    use crate::schema::titles;
+    
+    // Get all active titles (global, not promotion-specific)
+    let all_titles = titles::table
+        .filter(titles::is_active.eq(true))
+        .order(titles::prestige_tier.asc())
+        .then_order_by(titles::display_order.asc())
+        .then_order_by(titles::name.asc())
+        .load::<Title>(conn)?;
+
+    attach_current_holders(conn, all_titles)
+}
+
+/// Gets every current champion as a flat list for ticker display
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<ChampionEntry>)` - One entry per holder, ordered by prestige tier then display order
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Vacant titles are skipped. Each holder of a tag team title gets its own entry.
+pub fn internal_get_champions_flat(conn: &mut SqliteConnection) -> Result<Vec<ChampionEntry>, DieselError> {
+    let now = Utc::now().naive_utc();
+
+    Ok(internal_get_titles(conn)?
+        .into_iter()
+        .flat_map(|title| {
+            let title_name = title.title.name;
+            title.current_holders.into_iter().map(move |holder| ChampionEntry {
+                title_name: title_name.clone(),
+                wrestler_name: holder.wrestler_name,
+                days_held: (now - holder.holder.held_since).num_days() as i32,
+            })
+        })
+        .collect())
+}
+
+/// Tauri command to fetch all championship titles with holders
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Vector of all titles with current holders
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_titles(state: State<'_, DbState>) -> Result<Vec<TitleWithHolders>, String> {
+    let mut conn = get_connection(&state)?;
+    
+    internal_get_titles(&mut conn)
+        .map_err(|e| {
+            error!("Error fetching titles: {}", e);
+            format!("Failed to fetch titles: {}", e)
+        })
+}
+
+/// Tauri command to get every current champion as a flat list
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<ChampionEntry>)` - One entry per holder, ordered by prestige tier
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_champions_flat(state: State<'_, DbState>) -> Result<Vec<ChampionEntry>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_champions_flat(&mut conn).map_err(|e| {
+        error!("Error listing champions: {}", e);
+        AppError::from(e).with_context("Failed to list champions")
+    })
+}
+
+/// Builds a `LIKE` pattern matching `query` anywhere, escaping wildcards with `\`
+fn contains_pattern(query: &str) -> String {
+    let escaped = query
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+/// Searches active titles by name or division
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `query` - Text to look for; matched case-insensitively anywhere in the field
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Matching titles with holders, in display order
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// `%` and `_` in the query are matched literally rather than as wildcards
+pub fn internal_search_titles(
+    conn: &mut SqliteConnection,
+    query: &str,
+) -> Result<Vec<TitleWithHolders>, DieselError> {
+    use crate::schema::titles;
+
+    let pattern = contains_pattern(query.trim());
+
+    let matching_titles = titles::table
+        .filter(titles::is_active.eq(true))
+        .filter(
+            titles::name
+                .like(&pattern)
+                .escape('\\')
+                .or(titles::division.like(&pattern).escape('\\')),
+        )
+        .order(titles::prestige_tier.asc())
+        .then_order_by(titles::display_order.asc())
+        .then_order_by(titles::name.asc())
+        .load::<Title>(conn)?;
+
+    attach_current_holders(conn, matching_titles)
+}
+
+/// Tauri command to search active titles by name or division
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `query` - Text to look for in title names and divisions
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Matching titles with holders
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn search_titles(
+    state: State<'_, DbState>,
+    query: String,
+) -> Result<Vec<TitleWithHolders>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_search_titles(&mut conn, &query).map_err(|e| {
+        error!("Error searching titles for '{}': {}", query, e);
+        AppError::from(e).with_context("Failed to search titles")
+    })
+}
+
+/// Sets a custom display order for titles within their prestige tier
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `orders` - (title ID, display order) pairs; lower orders are listed first
+/// 
+/// # Returns
+/// * `Ok(())` - If every title was reordered
+/// * `Err(AppError::NotFound)` - If any title does not exist (nothing is changed)
+/// * `Err(AppError::Database)` - Database error if update fails
+/// 
+/// # Note
+/// Titles are still grouped by prestige tier first, so the order only decides
+/// placement among titles of the same tier.
+pub fn internal_reorder_titles(
+    conn: &mut SqliteConnection,
+    orders: &[(i32, i32)],
+) -> Result<(), AppError> {
+    use crate::schema::titles;
+
+    conn.transaction::<(), AppError, _>(|conn| {
+        for &(title_id, display_order) in orders {
+            let updated = diesel::update(titles::table.find(title_id))
+                .set(titles::display_order.eq(display_order))
+                .execute(conn)?;
+            if updated == 0 {
+                return Err(AppError::NotFound(format!("Title {} not found", title_id)));
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// Tauri command to set a custom display order for titles
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `orders` - (title ID, display order) pairs; lower orders are listed first
+/// 
+/// # Returns
+/// * `Ok(())` - If every title was reordered
+/// * `Err(AppError)` - Categorized error if a title is missing or the update fails
+#[tauri::command]
+pub fn reorder_titles(
+    state: State<'_, DbState>,
+    orders: Vec<(i32, i32)>,
+) -> Result<(), AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_reorder_titles(&mut conn, &orders)
+        .inspect(|_| info!("Reordered {} titles", orders.len()))
+        .map_err(|e| {
+            error!("Error reordering titles: {}", e);
+            e.with_context("Failed to reorder titles")
+        })
+}
+
+/// Moves every title from one show to another, e.g. when merging brands
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `from_show_id` - ID of the show giving up its titles
+/// * `to_show_id` - ID of the show receiving them
+/// 
+/// # Returns
+/// * `Ok(usize)` - Number of titles moved
+/// * `Err(AppError::NotFound)` - If either show does not exist
+/// * `Err(AppError::Validation)` - If both IDs are the same show
+/// * `Err(AppError::Database)` - Database error if update fails
+/// 
+/// # Note
+/// Inactive titles move too, so retired belts stay with their new brand's history
+pub fn internal_move_all_titles(
+    conn: &mut SqliteConnection,
+    from_show_id: i32,
+    to_show_id: i32,
+) -> Result<usize, AppError> {
+    use crate::schema::titles;
+
+    if from_show_id == to_show_id {
+        return Err(AppError::Validation(
+            "to_show_id: Titles must move to a different show".to_string(),
+        ));
+    }
+
+    conn.transaction::<usize, AppError, _>(|conn| {
+        for show_id in [from_show_id, to_show_id] {
+            if internal_get_show_by_id(conn, show_id)?.is_none() {
+                return Err(AppError::NotFound(format!("Show {} not found", show_id)));
+            }
+        }
+
+        diesel::update(titles::table)
+            .filter(titles::show_id.eq(from_show_id))
+            .set(titles::show_id.eq(to_show_id))
+            .execute(conn)
+            .map_err(AppError::from)
+    })
+}
+
+/// Tauri command to move every title from one show to another
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `from_show_id` - ID of the show giving up its titles
+/// * `to_show_id` - ID of the show receiving them
+/// 
+/// # Returns
+/// * `Ok(usize)` - Number of titles moved
+/// * `Err(AppError)` - Categorized error if a show is missing or the update fails
+#[tauri::command]
+pub fn move_all_titles(
+    state: State<'_, DbState>,
+    from_show_id: i32,
+    to_show_id: i32,
+) -> Result<usize, AppError> {
+    validate_id(from_show_id, "from_show_id")?;
+    validate_id(to_show_id, "to_show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_move_all_titles(&mut conn, from_show_id, to_show_id)
+        .inspect(|moved| {
+            info!("Moved {} titles from show {} to show {}", moved, from_show_id, to_show_id);
+        })
+        .map_err(|e| {
+            error!("Error moving titles from show {} to show {}: {}", from_show_id, to_show_id, e);
+            e.with_context("Failed to move titles")
+        })
+}
+
+/// Gets the current champion(s) of a single title
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleHolderInfo>)` - Active holders, empty for a vacant title
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_champion_for_title(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+) -> Result<Vec<TitleHolderInfo>, DieselError> {
+    load_holders_for_title(conn, title_id, None)
+}
+
+/// Tauri command to get the current champion(s) of a single title
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `title_id` - ID of the title
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleHolderInfo>)` - Active holders, empty for a vacant title
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_champion_for_title(
+    state: State<'_, DbState>,
+    title_id: i32,
+) -> Result<Vec<TitleHolderInfo>, AppError> {
+    validate_id(title_id, "title_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_champion_for_title(&mut conn, title_id).map_err(|e| {
+        error!("Error fetching champion for title {}: {}", title_id, e);
+        AppError::from(e).with_context("Failed to fetch champion")
+    })
+}
+
+/// Gets who held the title at stake in a match on the day it was scheduled
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// 
+/// # Returns
+/// * `Ok(Some(Vec<TitleHolderInfo>))` - Holders going into the match, empty if the title was vacant
+/// * `Ok(None)` - If it is not a title match or has no scheduled date
+/// * `Err(DieselError)` - Database error if the match doesn't exist or a query fails
+/// 
+/// # Note
+/// Reigns are checked at the start of the scheduled day, so on a day the title
+/// changed hands the outgoing champion is reported. Later title changes don't
+/// affect the result.
+pub fn internal_get_holder_at_match(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+) -> Result<Option<Vec<TitleHolderInfo>>, DieselError> {
+    use crate::schema::matches;
+
+    let (is_title_match, title_id, scheduled_date) = matches::table
+        .find(match_id)
+        .select((matches::is_title_match, matches::title_id, matches::scheduled_date))
+        .first::<(bool, Option<i32>, Option<chrono::NaiveDate>)>(conn)?;
+
+    let (Some(title_id), Some(scheduled_date)) = (title_id.filter(|_| is_title_match), scheduled_date) else {
+        return Ok(None);
+    };
+    let day_start = scheduled_date.and_time(chrono::NaiveTime::MIN);
+
+    load_holders_for_title(conn, title_id, Some(day_start)).map(Some)
+}
+
+/// Tauri command to get who held the title at stake in a match when it was scheduled
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `match_id` - ID of the match
+/// 
+/// # Returns
+/// * `Ok(Option<Vec<TitleHolderInfo>>)` - Holders going into the match, None for non-title or undated matches
+/// * `Err(AppError)` - Categorized error if the match is missing or the query fails
+#[tauri::command]
+pub fn get_holder_at_match(
+    state: State<'_, DbState>,
+    match_id: i32,
+) -> Result<Option<Vec<TitleHolderInfo>>, AppError> {
+    validate_id(match_id, "match_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_holder_at_match(&mut conn, match_id).map_err(|e| {
+        error!("Error fetching title holder at match {}: {}", match_id, e);
+        AppError::from(e).with_context("Failed to fetch title holder at match")
+    })
+}
+
+/// Gets all active titles bucketed by prestige tier
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<(i32, Vec<TitleWithHolders>)>)` - One group per tier present, from
+///   `WORLD_CHAMPIONSHIP_TIER` down to `SPECIALTY_CHAMPIONSHIP_TIER`
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Titles keep their name ordering within each tier; empty tiers are omitted
+pub fn internal_get_titles_grouped(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<(i32, Vec<TitleWithHolders>)>, DieselError> {
+    let mut groups: Vec<(i32, Vec<TitleWithHolders>)> = Vec::new();
+
+    // internal_get_titles already orders by tier, then name
+    for title in internal_get_titles(conn)? {
+        match groups.last_mut() {
+            Some((tier, bucket)) if *tier == title.title.prestige_tier => bucket.push(title),
+            _ => groups.push((title.title.prestige_tier, vec![title])),
+        }
+    }
+
+    Ok(groups)
+}
+
+/// Tauri command to fetch titles grouped by prestige tier
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<(i32, Vec<TitleWithHolders>)>)` - Titles bucketed by tier
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_titles_grouped(
+    state: State<'_, DbState>,
+) -> Result<Vec<(i32, Vec<TitleWithHolders>)>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_titles_grouped(&mut conn)
+        .map_err(|e| {
+            error!("Error fetching grouped titles: {}", e);
+            AppError::from(e).with_context("Failed to fetch grouped titles")
+        })
+}
+
+/// Deletes a championship title (only if user-created)
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title to delete
+/// 
+/// # Returns
+/// * `Ok(())` - If deletion was successful
+/// * `Err(DieselError::RollbackTransaction)` - If title is not user-created
+/// * `Err(DieselError)` - Other database errors
+/// 
+/// # Note
+/// Ends any current title reigns before deletion
+pub fn internal_delete_title(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+) -> Result<(), DieselError> {
+    use crate::schema::{titles, title_holders};
+    
+    // First check if the title exists and is user-created
+    let title = titles::table
+        .filter(titles::id.eq(title_id))
+        .first::<Title>(conn)?;
+    
+    // Only allow deletion of user-created titles
+    if !title.is_user_created.unwrap_or(false) {
+        return Err(DieselError::RollbackTransaction);
+    }
+    
+    // End any current title reigns for this title before deletion
+    let now = Utc::now().naive_utc();
+    diesel::update(title_holders::table)
+        .filter(title_holders::title_id.eq(title_id))
+        .filter(title_holders::held_until.is_null())
+        .set(title_holders::held_until.eq(now))
+        .execute(conn)?;
+    
+    // Delete the title (database handles cascading deletes via foreign key constraints)
+    diesel::delete(titles::table.filter(titles::id.eq(title_id)))
+        .execute(conn)?;
+        
+    Ok(())
+}
+
+/// Returns the title genders a wrestler of the given gender may compete for
+/// 
+/// # Arguments
+/// * `wrestler_gender` - The wrestler's stored gender
+/// 
+/// # Note
+/// - Male wrestlers can hold Male and Mixed titles
+/// - Female wrestlers can hold Female and Mixed titles
+/// - Other gender wrestlers can hold any title
+pub fn compatible_title_genders(wrestler_gender: &str) -> Vec<&'static str> {
+    match wrestler_gender {
+        "Male" => vec!["Male", "Mixed"],
+        "Female" => vec!["Female", "Mixed"],
+        _ => vec!["Male", "Female", "Mixed"], // "Other" or any other gender
+    }
+}
+
+/// Gets titles that can be assigned to a wrestler based on gender compatibility
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_gender` - Gender of the wrestler ("Male", "Female", etc.), normalized before filtering
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Vector of compatible titles
+/// * `Err(AppError::Validation)` - If the gender is unrecognised
+/// * `Err(AppError::Database)` - Database error if query fails
+/// 
+/// # Note
+/// - Male wrestlers can hold Male and Mixed titles
+/// - Female wrestlers can hold Female and Mixed titles
+/// - Other gender wrestlers can hold any title
+pub fn internal_get_titles_for_wrestler_gender(
+    conn: &mut SqliteConnection,
+    wrestler_gender: &str,
+) -> Result<Vec<TitleWithHolders>, AppError> {
+    use crate::schema::titles;
+    
+    let wrestler_gender = normalize_wrestler_gender(wrestler_gender)?;
+    let gender_filter = compatible_title_genders(&wrestler_gender);
+    
+    // Get active titles that match gender criteria
+    let filtered_titles = titles::table
+        .filter(titles::is_active.eq(true))
+        .filter(titles::gender.eq_any(gender_filter))
+        .order(titles::prestige_tier.asc())
+        .then_order_by(titles::display_order.asc())
+        .then_order_by(titles::name.asc())
+        .load::<Title>(conn)?;
+
+    attach_current_holders(conn, filtered_titles).map_err(AppError::from)
+}
+
+/// Updates title holder (ends current reign and starts new one)
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title to update
+/// * `new_wrestler_id` - ID of the new champion
+/// * `event_name` - Optional event name where title changed hands
+/// * `event_location` - Optional event location
+/// * `change_method` - Optional method of title change (e.g., "Pinfall", "Submission")
+/// 
+/// # Returns
+/// * `Ok(())` - If title holder was successfully updated
+/// * `Err(AppError::Validation)` - If an event field is too long
+/// * `Err(AppError::Database)` - Database error if update fails
+/// 
+/// # Note
+/// Validates string lengths with `validate_event_fields` to prevent database abuse
+pub fn internal_update_title_holder(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+    new_wrestler_id: i32,
+    event_name: Option<&str>,
+    event_location: Option<&str>,
+    change_method: Option<&str>,
+) -> Result<(), AppError> {
+    use crate::schema::title_holders;
+
+    // Input validation to prevent abuse
+    validate_event_fields(event_name, event_location, change_method)?;
+
+    let now = Utc::now().naive_utc();
+
+    // End current title reigns for this title
+    diesel::update(title_holders::table)
+        .filter(title_holders::title_id.eq(title_id))
+        .filter(title_holders::held_until.is_null())
+        .set(title_holders::held_until.eq(now))
+        .execute(conn)?;
+
+    // Create new title holder record
+    let new_holder = NewTitleHolder {
+        title_id,
+        wrestler_id: new_wrestler_id,
+        held_since: now,
+        event_name: event_name.map(|s| s.to_string()),
+        event_location: event_location.map(|s| s.to_string()),
+        change_method: change_method.map(|s| s.to_string()),
+    };
+
+    diesel::insert_into(title_holders::table)
+        .values(&new_holder)
+        .execute(conn)?;
+
+    internal_log_operation(
+        conn,
+        "title_change",
+        "title",
+        Some(title_id),
+        Some(&format!("Wrestler {} became champion", new_wrestler_id)),
+    );
+
+    Ok(())
+}
+
+/// Tauri command to change a championship title holder
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `title_id` - ID of the title to update
+/// * `new_wrestler_id` - ID of the new champion
+/// * `event_name` - Optional event name
+/// * `event_location` - Optional event location
+/// * `change_method` - Optional method of victory
+/// 
+/// # Returns
+/// * `Ok(String)` - Success message
+/// * `Err(AppError)` - Categorized error if update fails
+#[tauri::command]
+pub fn update_title_holder(
+    state: State<'_, DbState>,
+    title_id: i32,
+    new_wrestler_id: i32,
+    event_name: Option<String>,
+    event_location: Option<String>,
+    change_method: Option<String>,
+) -> Result<String, AppError> {
+    validate_id(title_id, "title_id")?;
+    validate_id(new_wrestler_id, "new_wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_update_title_holder(
+        &mut conn,
+        title_id,
+        new_wrestler_id,
+        event_name.as_deref(),
+        event_location.as_deref(),
+        change_method.as_deref(),
+    )
+    .map_err(|e| {
+        error!("Error updating title holder: {}", e);
+        e.with_context("Failed to update title holder")
+    })?;
+
+    Ok("Title holder updated successfully".to_string())
+}
+
+/// Applies every title change from one event as a single unit
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `event_name` - Optional event name shared by every change
+/// * `event_location` - Optional event location shared by every change
+/// * `results` - (title ID, new holder ID, change method) for each title that changed hands
+/// 
+/// # Returns
+/// * `Ok(usize)` - Number of title changes applied
+/// * `Err(AppError::NotFound)` - If a title or wrestler does not exist
+/// * `Err(AppError::Validation)` - If a title is listed more than once, or a new holder is not gender-compatible with their title
+/// * `Err(AppError::Database)` - Database error if an update fails
+/// 
+/// # Note
+/// Runs in a single transaction: if any change fails, none are applied.
+/// Each title may change hands once per event, since every change ends all of
+/// the title's active reigns.
+pub fn internal_process_ppv_results(
+    conn: &mut SqliteConnection,
+    event_name: Option<&str>,
+    event_location: Option<&str>,
+    results: &[(i32, i32, Option<String>)],
+) -> Result<usize, AppError> {
+    use crate::schema::{titles, wrestlers};
+    use std::collections::HashSet;
+
+    let mut seen_titles = HashSet::new();
+    if let Some((title_id, _, _)) = results.iter().find(|(title_id, _, _)| !seen_titles.insert(*title_id)) {
+        return Err(AppError::Validation(format!(
+            "title_id: Title {} is listed more than once in the event results",
+            title_id
+        )));
+    }
+
+    conn.transaction::<usize, AppError, _>(|conn| {
+        for (title_id, new_holder_id, change_method) in results {
+            let title = titles::table
+                .find(title_id)
+                .first::<Title>(conn)
+                .optional()?
+                .ok_or_else(|| AppError::NotFound(format!("Title {} not found", title_id)))?;
+            let wrestler = wrestlers::table
+                .find(new_holder_id)
+                .first::<Wrestler>(conn)
+                .optional()?
+                .ok_or_else(|| AppError::NotFound(format!("Wrestler {} not found", new_holder_id)))?;
+
+            if !compatible_title_genders(&wrestler.gender).contains(&title.gender.as_str()) {
+                return Err(AppError::Validation(format!(
+                    "{} ({}) cannot hold the {} title '{}'",
+                    wrestler.name, wrestler.gender, title.gender, title.name
+                )));
+            }
+
+            internal_update_title_holder(
+                conn,
+                title.id,
+                wrestler.id,
+                event_name,
+                event_location,
+                change_method.as_deref(),
+            )?;
+        }
+
+        Ok(results.len())
+    })
+}
+
+/// Tauri command to apply every title change from one event at once
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `event_name` - Optional event name shared by every change
+/// * `event_location` - Optional event location shared by every change
+/// * `results` - (title ID, new holder ID, change method) for each title that changed hands
+/// 
+/// # Returns
+/// * `Ok(usize)` - Number of title changes applied
+/// * `Err(AppError)` - Categorized error if any change is invalid (nothing is applied)
+#[tauri::command]
+pub fn process_ppv_results(
+    state: State<'_, DbState>,
+    event_name: Option<String>,
+    event_location: Option<String>,
+    results: Vec<(i32, i32, Option<String>)>,
+) -> Result<usize, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_process_ppv_results(&mut conn, event_name.as_deref(), event_location.as_deref(), &results)
+        .inspect(|applied| info!("Processed {} title changes from event results", applied))
+        .map_err(|e| {
+            error!("Error processing event results: {}", e);
+            e.with_context("Failed to process event results")
+        })
+}
+
+/// Reverts the most recent title change, restoring the previous champions
+///
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title to revert
+///
+/// # Returns
+/// * `Ok(Vec<TitleHolder>)` - The restored (now active) reigns
+/// * `Err(AppError::NotFound)` - If the title has no reigns at all
+/// * `Err(AppError::Validation)` - If the title is vacant or there is no earlier reign to restore
+/// * `Err(AppError::Database)` - Database error if the update fails
+///
+/// # Note
+/// A title change is every active reign that started at the same moment, so
+/// both members of a tag team are removed together and every reign that ended
+/// when they won is reopened. Deleting and reopening happen in a single transaction.
+pub fn internal_undo_last_title_change(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+) -> Result<Vec<TitleHolder>, AppError> {
+    use crate::schema::title_holders;
+    use diesel::dsl::{count_star, max};
+
+    conn.transaction::<Vec<TitleHolder>, AppError, _>(|conn| {
+        let reign_count = title_holders::table
+            .filter(title_holders::title_id.eq(title_id))
+            .select(count_star())
+            .first::<i64>(conn)?;
+        if reign_count == 0 {
+            return Err(AppError::NotFound(format!("Title {} has no title changes to undo", title_id)));
+        }
+
+        let latest_since = title_holders::table
+            .filter(title_holders::title_id.eq(title_id))
+            .filter(title_holders::held_until.is_null())
+            .select(max(title_holders::held_since))
+            .first::<Option<chrono::NaiveDateTime>>(conn)?
+            .ok_or_else(|| {
+                AppError::Validation(format!("Title {} is vacant, so there is no title change to undo", title_id))
+            })?;
+
+        diesel::delete(
+            title_holders::table
+                .filter(title_holders::title_id.eq(title_id))
+                .filter(title_holders::held_until.is_null())
+                .filter(title_holders::held_since.eq(latest_since)),
+        )
+        .execute(conn)?;
+
+        let restored = diesel::update(
+            title_holders::table
+                .filter(title_holders::title_id.eq(title_id))
+                .filter(title_holders::held_until.eq(latest_since)),
+        )
+        .set(title_holders::held_until.eq(None::<chrono::NaiveDateTime>))
+        .returning(TitleHolder::as_returning())
+        .get_results(conn)?;
+
+        if restored.is_empty() {
+            return Err(AppError::Validation("There is no previous reign to restore".to_string()));
+        }
+
+        Ok(restored)
+    })
+}
+
+/// Tauri command to undo the most recent change of a title's holder
+///
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `title_id` - ID of the title to revert
+///
+/// # Returns
+/// * `Ok(String)` - Success message
+/// * `Err(AppError)` - Categorized error if there is nothing to undo or the update fails
+#[tauri::command]
+pub fn undo_last_title_change(
+    state: State<'_, DbState>,
+    title_id: i32,
+) -> Result<String, AppError> {
+    validate_id(title_id, "title_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_undo_last_title_change(&mut conn, title_id)
+        .map_err(|e| {
+            error!("Error undoing title change: {}", e);
+            e.with_context("Failed to undo title change")
+        })?;
+
+    info!("Last title change undone for title {}", title_id);
+    Ok("Title change undone successfully".to_string())
+}
+
+/// Finds singles titles that have more than one active reign
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<i32>)` - IDs of the affected titles, ascending
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Tag team titles legitimately have two active reigns and are never reported
+pub fn internal_get_titles_with_duplicate_reigns(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<i32>, DieselError> {
+    use crate::schema::{title_holders, titles};
+    use diesel::dsl::count_star;
+
+    let active_counts = title_holders::table
+        .inner_join(titles::table)
+        .filter(titles::title_type.eq("Singles"))
+        .filter(title_holders::held_until.is_null())
+        .group_by(title_holders::title_id)
+        .select((title_holders::title_id, count_star()))
+        .order(title_holders::title_id.asc())
+        .load::<(i32, i64)>(conn)?;
+
+    Ok(active_counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(title_id, _)| title_id)
+        .collect())
+}
+
+/// Ends all but the most recent active reign of a singles title
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title to repair
+/// 
+/// # Returns
+/// * `Ok(usize)` - Number of reigns that were ended (0 if the title was consistent)
+/// * `Err(AppError::NotFound)` - If the title does not exist
+/// * `Err(AppError::Database)` - Database error if the update fails
+/// 
+/// # Note
+/// Each stale reign is closed at the moment the kept reign began. Titles other
+/// than singles titles are left untouched.
+pub fn internal_repair_duplicate_reigns(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+) -> Result<usize, AppError> {
+    use crate::schema::{title_holders, titles};
+
+    conn.transaction::<usize, AppError, _>(|conn| {
+        let title_type = titles::table
+            .find(title_id)
+            .select(titles::title_type)
+            .first::<String>(conn)
+            .optional()?
+            .ok_or_else(|| AppError::NotFound(format!("Title {} not found", title_id)))?;
+        if title_type != "Singles" {
+            return Ok(0);
+        }
+
+        let active_reigns = title_holders::table
+            .filter(title_holders::title_id.eq(title_id))
+            .filter(title_holders::held_until.is_null())
+            .order(title_holders::held_since.desc())
+            .then_order_by(title_holders::id.desc())
+            .load::<TitleHolder>(conn)?;
+
+        let Some((kept, stale)) = active_reigns.split_first() else {
+            return Ok(0);
+        };
+
+        for reign in stale {
+            diesel::update(title_holders::table.find(reign.id))
+                .set(title_holders::held_until.eq(kept.held_since))
+                .execute(conn)?;
+        }
+
+        Ok(stale.len())
+    })
+}
+
+/// Tauri command to end all but the most recent active reign of a singles title
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `title_id` - ID of the title to repair
+/// 
+/// # Returns
+/// * `Ok(usize)` - Number of reigns that were ended
+/// * `Err(AppError)` - Categorized error if the title is missing or the update fails
+#[tauri::command]
+pub fn repair_duplicate_reigns(
+    state: State<'_, DbState>,
+    title_id: i32,
+) -> Result<usize, AppError> {
+    validate_id(title_id, "title_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_repair_duplicate_reigns(&mut conn, title_id)
+        .inspect(|fixed| info!("Ended {} duplicate reigns for title {}", fixed, title_id))
+        .map_err(|e| {
+            error!("Error repairing duplicate reigns: {}", e);
+            e.with_context("Failed to repair duplicate reigns")
+        })
+}
+
+/// Tauri command to delete a championship title
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `title_id` - ID of the title to delete
+/// 
+/// # Returns
+/// * `Ok(String)` - Success message
+/// * `Err(String)` - Error message if deletion fails or title is system-created
+#[tauri::command]
+pub fn delete_title(state: State<'_, DbState>, title_id: i32) -> Result<String, String> {
+    validate_id(title_id, "title_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+
+    internal_delete_title(&mut conn, title_id)
+        .inspect(|_| {
+            info!("Title with ID {} deleted successfully", title_id);
+        })
+        .map_err(|e| {
+            error!("Error deleting title: {}", e);
+            match e {
+                DieselError::RollbackTransaction => "Cannot delete system title - only user-created titles can be deleted".to_string(),
+                DieselError::NotFound => "Title not found".to_string(),
+                _ => format!("Failed to delete title: {}", e),
+            }
+        })
+        .map(|_| "Title deleted successfully".to_string())
+}
+
+/// Tauri command to get titles compatible with a wrestler's gender
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_gender` - Gender of the wrestler
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Vector of compatible titles
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_titles_for_wrestler(
+    state: State<'_, DbState>,
+    wrestler_gender: String,
+) -> Result<Vec<TitleWithHolders>, String> {
+    let mut conn = get_connection(&state)?;
+    
+    internal_get_titles_for_wrestler_gender(&mut conn, &wrestler_gender)
+        .map_err(|e| {
+            error!("Error fetching titles for wrestler gender: {}", e);
+            format!("Failed to fetch titles for wrestler: {}", e)
+        })
+}
+
+/// Gets all titles assigned to a specific show
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show to filter by
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Vector of titles assigned to the show
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_titles_for_show(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<Vec<TitleWithHolders>, DieselError> {
+    use crate::schema::titles;
+    
+    // Get titles assigned to this specific show
+    let all_titles = titles::table
+        .filter(titles::is_active.eq(true))
+        .filter(titles::show_id.eq(show_id))
+        .order(titles::prestige_tier.asc())
+        .then_order_by(titles::display_order.asc())
+        .then_order_by(titles::name.asc())
+        .load::<Title>(conn)?;
+
+    attach_current_holders(conn, all_titles)
+}
+
+/// Gets all active titles of a division with their current holders
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `division` - Division to filter by (e.g. "World", "Tag Team")
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Titles in the division, ordered by prestige tier then display order
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_titles_for_division(
+    conn: &mut SqliteConnection,
+    division: &str,
+) -> Result<Vec<TitleWithHolders>, DieselError> {
+    use crate::schema::titles;
+
+    let division_titles = titles::table
+        .filter(titles::is_active.eq(true))
+        .filter(titles::division.eq(division))
+        .order(titles::prestige_tier.asc())
+        .then_order_by(titles::display_order.asc())
+        .then_order_by(titles::name.asc())
+        .load::<Title>(conn)?;
+
+    attach_current_holders(conn, division_titles)
+}
+
+/// Gets all unassigned titles (not assigned to any show)
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Vector of cross-brand titles
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_unassigned_titles(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<TitleWithHolders>, DieselError> {
+    use crate::schema::titles;
+    
+    // Get titles not assigned to any show
+    let all_titles = titles::table
+        .filter(titles::is_active.eq(true))
+        .filter(titles::show_id.is_null())
+        .order(titles::prestige_tier.asc())
+        .then_order_by(titles::display_order.asc())
+        .then_order_by(titles::name.asc())
+        .load::<Title>(conn)?;
+
+    attach_current_holders(conn, all_titles)
+}
+
+/// Finds champions who are not on the roster of their title's show
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<(Title, Wrestler)>)` - Each misaligned title with its champion, ordered by prestige tier then champion name
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Cross-brand titles (no show) are never misaligned. Only active roster
+/// assignments count, so a champion drafted away from the title's show is flagged.
+pub fn internal_get_misaligned_champions(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<(Title, Wrestler)>, DieselError> {
+    use crate::schema::{show_rosters, title_holders, titles, wrestlers};
+    use diesel::dsl::{exists, not};
+
+    title_holders::table
+        .inner_join(titles::table.on(title_holders::title_id.eq(titles::id)))
+        .inner_join(wrestlers::table.on(title_holders::wrestler_id.eq(wrestlers::id)))
+        .filter(title_holders::held_until.is_null())
+        .filter(titles::is_active.eq(true))
+        .filter(titles::show_id.is_not_null())
+        .filter(not(exists(
+            show_rosters::table
+                .filter(show_rosters::wrestler_id.eq(title_holders::wrestler_id))
+                .filter(show_rosters::show_id.nullable().eq(titles::show_id))
+                .filter(show_rosters::is_active.eq(true)),
+        )))
+        .select((Title::as_select(), Wrestler::as_select()))
+        .order((titles::prestige_tier.asc(), titles::display_order.asc(), wrestlers::name.asc()))
+        .load::<(Title, Wrestler)>(conn)
+}
+
+/// Tauri command to get titles assigned to a specific show
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Vector of titles for the show
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_titles_for_show(
+    state: State<'_, DbState>,
+    show_id: i32,
+) -> Result<Vec<TitleWithHolders>, String> {
+    validate_id(show_id, "show_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+    
+    internal_get_titles_for_show(&mut conn, show_id)
+        .map_err(|e| {
+            error!("Error fetching titles for show: {}", e);
+            format!("Failed to fetch titles for show: {}", e)
+        })
+}
+
+/// Tauri command to get the active titles of a division
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `division` - Division to filter by
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Titles in the division with holder information
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_titles_for_division(
+    state: State<'_, DbState>,
+    division: String,
+) -> Result<Vec<TitleWithHolders>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_titles_for_division(&mut conn, &division).map_err(|e| {
+        error!("Error fetching titles for division {}: {}", division, e);
+        AppError::from(e).with_context("Failed to fetch titles for division")
+    })
+}
+
+/// Tauri command to get cross-brand titles not assigned to any show
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Vector of unassigned titles
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_unassigned_titles(
+    state: State<'_, DbState>,
+) -> Result<Vec<TitleWithHolders>, String> {
+    let mut conn = get_connection(&state)?;
+    
+    internal_get_unassigned_titles(&mut conn)
+        .map_err(|e| {
+            error!("Error fetching unassigned titles: {}", e);
+            format!("Failed to fetch unassigned titles: {}", e)
+        })
+}
+
+/// Tauri command to find champions who are not on their title's show
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<(Title, Wrestler)>)` - Misaligned titles with their champions
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_misaligned_champions(state: State<'_, DbState>) -> Result<Vec<(Title, Wrestler)>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_misaligned_champions(&mut conn).map_err(|e| {
+        error!("Error finding misaligned champions: {}", e);
+        AppError::from(e).with_context("Failed to find misaligned champions")
+    })
+}
+
+/// Gets all titles a wrestler currently holds
+///
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+///
+/// # Returns
+/// * `Ok(Vec<Title>)` - Titles with an active reign for the wrestler (empty for non-champions)
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_current_titles_for_wrestler(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+) -> Result<Vec<Title>, DieselError> {
+    use crate::schema::{titles, title_holders};
+
+    titles::table
+        .inner_join(title_holders::table.on(titles::id.eq(title_holders::title_id)))
+        .filter(title_holders::wrestler_id.eq(wrestler_id))
+        .filter(title_holders::held_until.is_null())
+        .select(Title::as_select())
+        .order(titles::prestige_tier.asc())
+        .then_order_by(titles::display_order.asc())
+        .then_order_by(titles::name.asc())
+        .load::<Title>(conn)
+}
+
+/// Tauri command to get the titles a wrestler currently holds
+///
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
+///
+/// # Returns
+/// * `Ok(Vec<Title>)` - Titles currently held by the wrestler
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_current_titles_for_wrestler(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+) -> Result<Vec<Title>, String> {
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+
+    internal_get_current_titles_for_wrestler(&mut conn, wrestler_id)
+        .map_err(|e| {
+            error!("Error fetching current titles for wrestler {}: {}", wrestler_id, e);
+            format!("Failed to fetch current titles for wrestler: {}", e)
+        })
+}
+
+/// Converts a title's prestige tier into leaderboard points
+///
+/// Tiers are inverted so the most prestigious titles score highest:
+/// tier 1 = 4 points, tier 2 = 3, tier 3 = 2, tier 4 (and anything lower) = 1.
+pub fn prestige_points_for_tier(prestige_tier: i32) -> i32 {
+    (SPECIALTY_CHAMPIONSHIP_TIER + 1 - prestige_tier).clamp(1, SPECIALTY_CHAMPIONSHIP_TIER)
+}
+
+/// Gets current champions ranked by the prestige of the titles they hold
+///
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+///
+/// # Returns
+/// * `Ok(Vec<(Wrestler, i32)>)` - Champions with their summed prestige points, highest first
+/// * `Err(DieselError)` - Database error if query fails
+///
+/// # Note
+/// Only active reigns count; wrestlers holding nothing are not included
+pub fn internal_get_prestige_leaderboard(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<(Wrestler, i32)>, DieselError> {
+    use crate::schema::{titles, title_holders, wrestlers};
+
+    let active_reigns = title_holders::table
+        .inner_join(wrestlers::table.on(title_holders::wrestler_id.eq(wrestlers::id)))
+        .inner_join(titles::table.on(title_holders::title_id.eq(titles::id)))
+        .filter(title_holders::held_until.is_null())
+        .select((Wrestler::as_select(), titles::prestige_tier))
+        .load::<(Wrestler, i32)>(conn)?;
+
+    let mut leaderboard: Vec<(Wrestler, i32)> = Vec::new();
+    for (wrestler, prestige_tier) in active_reigns {
+        let points = prestige_points_for_tier(prestige_tier);
+        match leaderboard.iter_mut().find(|(w, _)| w.id == wrestler.id) {
+            Some((_, total)) => *total += points,
+            None => leaderboard.push((wrestler, points)),
+        }
+    }
+
+    leaderboard.sort_by(|(a, a_points), (b, b_points)| {
+        b_points.cmp(a_points).then_with(|| a.name.cmp(&b.name))
+    });
+
+    Ok(leaderboard)
+}
+
+/// Tauri command to get the current champions prestige leaderboard
+///
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+///
+/// # Returns
+/// * `Ok(Vec<(Wrestler, i32)>)` - Champions with their prestige points, highest first
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_prestige_leaderboard(state: State<'_, DbState>) -> Result<Vec<(Wrestler, i32)>, String> {
+    let mut conn = get_connection(&state)?;
+
+    internal_get_prestige_leaderboard(&mut conn)
+        .map_err(|e| {
+            error!("Error fetching prestige leaderboard: {}", e);
+            format!("Failed to fetch prestige leaderboard: {}", e)
+        })
+}
+
+/// Gets the wrestlers with the most cumulative days as champion
+///
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `limit` - Maximum number of wrestlers to return
+///
+/// # Returns
+/// * `Ok(Vec<(Wrestler, i64)>)` - Wrestlers with their total days holding any title, most first
+/// * `Err(DieselError)` - Database error if query fails
+///
+/// # Note
+/// Sums every reign, ended or ongoing (counted up to now), across all titles
+pub fn internal_get_dominant_champions(
+    conn: &mut SqliteConnection,
+    limit: usize,
+) -> Result<Vec<(Wrestler, i64)>, DieselError> {
+    use crate::schema::{title_holders, wrestlers};
+
+    let reigns = title_holders::table
+        .inner_join(wrestlers::table)
+        .select((
+            Wrestler::as_select(),
+            title_holders::held_since,
+            title_holders::held_until,
+        ))
+        .load::<(Wrestler, chrono::NaiveDateTime, Option<chrono::NaiveDateTime>)>(conn)?;
+
+    let now = Utc::now().naive_utc();
+    let mut totals: Vec<(Wrestler, i64)> = Vec::new();
+    for (wrestler, held_since, held_until) in reigns {
+        let days = (held_until.unwrap_or(now) - held_since).num_days();
+        match totals.iter_mut().find(|(w, _)| w.id == wrestler.id) {
+            Some((_, total)) => *total += days,
+            None => totals.push((wrestler, days)),
+        }
+    }
+
+    totals.sort_by(|(a, a_days), (b, b_days)| {
+        b_days.cmp(a_days).then_with(|| a.name.cmp(&b.name))
+    });
+    totals.truncate(limit);
+
+    Ok(totals)
+}
+
+/// Tauri command to get the wrestlers with the most cumulative days as champion
+///
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `limit` - Maximum number of wrestlers to return
+///
+/// # Returns
+/// * `Ok(Vec<(Wrestler, i64)>)` - Wrestlers with their total days as champion, most first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_dominant_champions(
+    state: State<'_, DbState>,
+    limit: usize,
+) -> Result<Vec<(Wrestler, i64)>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_dominant_champions(&mut conn, limit).map_err(|e| {
+        error!("Error fetching dominant champions: {}", e);
+        AppError::from(e).with_context("Failed to fetch dominant champions")
+    })
+}
+
+/// Gets every title reign a wrestler has had
+///
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+///
+/// # Returns
+/// * `Ok(Vec<PastReign>)` - All reigns, most recent first
+/// * `Err(DieselError)` - Database error if query fails
+///
+/// # Note
+/// Ongoing reigns are counted up to now and flagged with `is_current`
+pub fn internal_get_title_history_for_wrestler(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+) -> Result<Vec<PastReign>, DieselError> {
+    use crate::schema::{titles, title_holders};
+
+    let reigns = title_holders::table
+        .inner_join(titles::table.on(title_holders::title_id.eq(titles::id)))
+        .filter(title_holders::wrestler_id.eq(wrestler_id))
+        .select((TitleHolder::as_select(), titles::name))
+        .order(title_holders::held_since.desc())
+        .then_order_by(title_holders::id.desc())
+        .load::<(TitleHolder, String)>(conn)?;
+
+    let now = Utc::now().naive_utc();
+
+    Ok(reigns
+        .into_iter()
+        .map(|(holder, title_name)| {
+            let end = holder.held_until.unwrap_or(now);
+            PastReign {
+                title_id: holder.title_id,
+                title_name,
+                held_since: holder.held_since,
+                held_until: holder.held_until,
+                days: (end - holder.held_since).num_days() as i32,
+                is_current: holder.held_until.is_none(),
+            }
+        })
+        .collect())
+}
+
+/// Tauri command to get a wrestler's full championship history
+///
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
+///
+/// # Returns
+/// * `Ok(Vec<PastReign>)` - All reigns, most recent first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_title_history_for_wrestler(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+) -> Result<Vec<PastReign>, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_title_history_for_wrestler(&mut conn, wrestler_id).map_err(|e| {
+        error!("Error fetching title history for wrestler {}: {}", wrestler_id, e);
+        AppError::from(e).with_context("Failed to fetch title history")
+    })
+}
+
+/// Gets every title reign a wrestler has had in order, for a career timeline
+///
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+///
+/// # Returns
+/// * `Ok(Vec<CareerReign>)` - Reigns across all titles, oldest first
+/// * `Err(DieselError)` - Database error if query fails
+///
+/// # Note
+/// Same reigns as `get_title_history_for_wrestler` in chronological order;
+/// an ongoing reign has no end and counts days up to now
+pub fn internal_get_wrestler_title_timeline(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+) -> Result<Vec<CareerReign>, DieselError> {
+    Ok(internal_get_title_history_for_wrestler(conn, wrestler_id)?
+        .into_iter()
+        .rev()
+        .map(|reign| CareerReign {
+            title_id: reign.title_id,
+            title_name: reign.title_name,
+            held_since: reign.held_since,
+            held_until: reign.held_until,
+            days: reign.days,
+        })
+        .collect())
+}
+
+/// Tauri command to get a wrestler's championship reigns in chronological order
+///
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
+///
+/// # Returns
+/// * `Ok(Vec<CareerReign>)` - Reigns across all titles, oldest first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_wrestler_title_timeline(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+) -> Result<Vec<CareerReign>, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_wrestler_title_timeline(&mut conn, wrestler_id).map_err(|e| {
+        error!("Error fetching title timeline for wrestler {}: {}", wrestler_id, e);
+        AppError::from(e).with_context("Failed to fetch wrestler title timeline")
+    })
+}
+
+/// Gets a wrestler's career accomplishments for their profile
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+/// 
+/// # Returns
+/// * `Ok(Accolades)` - Title reigns and match record in one summary
+/// * `Err(DieselError)` - NotFound if the wrestler does not exist, or a database error
+pub fn internal_get_wrestler_accolades(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+) -> Result<Accolades, DieselError> {
+    use crate::schema::wrestlers;
+
+    let wrestler = wrestlers::table
+        .find(wrestler_id)
+        .first::<Wrestler>(conn)?;
+    let reigns = internal_get_title_history_for_wrestler(conn, wrestler_id)?;
+
+    let mut titles_held_names: Vec<String> = reigns
+        .iter()
+        .map(|reign| reign.title_name.clone())
+        .collect();
+    titles_held_names.sort();
+    titles_held_names.dedup();
+
+    Ok(Accolades {
+        total_reigns: reigns.len() as i32,
+        titles_held_names,
+        match_wins: wrestler.wins,
+        match_losses: wrestler.losses,
+        win_rate: wrestler.win_rate(),
+        longest_reign_days: reigns.iter().map(|reign| reign.days).max(),
+    })
+}
+
+/// Tauri command to get a wrestler's career accomplishments
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
+/// 
+/// # Returns
+/// * `Ok(Accolades)` - Title reigns and match record in one summary
+/// * `Err(AppError)` - Categorized error if the wrestler is missing or the query fails
+#[tauri::command]
+pub fn get_wrestler_accolades(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+) -> Result<Accolades, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_wrestler_accolades(&mut conn, wrestler_id).map_err(|e| {
+        error!("Error fetching accolades for wrestler {}: {}", wrestler_id, e);
+        AppError::from(e).with_context("Failed to fetch wrestler accolades")
+    })
+}
+
+/// Counts the successful defenses of every reign of a title
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title
+/// 
+/// # Returns
+/// * `Ok(Vec<(TitleHolderInfo, i32)>)` - Each reign with its defense count, oldest first
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// A defense is a decided title match for this title won by the reigning
+/// champion, dated after the day the reign began and no later than the day it
+/// ended. Matches without a scheduled date fall back to their creation date.
+/// The match a champion won the title in shares the reign's first day, so it
+/// is never counted as a defense.
+fn reign_defense_counts(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+) -> Result<Vec<(TitleHolderInfo, i32)>, DieselError> {
+    use crate::schema::{matches, title_holders, wrestlers};
+
+    let reigns = title_holders::table
+        .inner_join(wrestlers::table)
+        .filter(title_holders::title_id.eq(title_id))
+        .order(title_holders::held_since.asc())
+        .then_order_by(title_holders::id.asc())
+        .select((TitleHolder::as_select(), wrestlers::name, wrestlers::gender))
+        .load::<(TitleHolder, String, String)>(conn)?;
+
+    let title_wins = matches::table
+        .filter(matches::title_id.eq(title_id))
+        .filter(matches::is_title_match.eq(true))
+        .filter(matches::winner_id.is_not_null())
+        .select((matches::winner_id, matches::scheduled_date, matches::created_at))
+        .load::<(Option<i32>, Option<chrono::NaiveDate>, Option<chrono::NaiveDateTime>)>(conn)?;
+
+    Ok(reigns
+        .into_iter()
+        .map(|(holder, wrestler_name, wrestler_gender)| {
+            let defenses = title_wins
+                .iter()
+                .filter(|(winner_id, scheduled_date, created_at)| {
+                    let Some(date) = scheduled_date.or(created_at.map(|created| created.date())) else {
+                        return false;
+                    };
+                    *winner_id == Some(holder.wrestler_id)
+                        && date > holder.held_since.date()
+                        && holder.held_until.is_none_or(|until| date <= until.date())
+                })
+                .count() as i32;
+
+            (TitleHolderInfo { holder, wrestler_name, wrestler_gender }, defenses)
+        })
+        .collect())
+}
+
+/// Finds the reign with the most successful defenses of a title
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title
+/// 
+/// # Returns
+/// * `Ok(Some((TitleHolderInfo, i32)))` - The record reign and its defense count
+/// * `Ok(None)` - If the title has never been held or never been defended
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Ties go to the earlier reign, which set the record first
+pub fn internal_get_most_defended_reign(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+) -> Result<Option<(TitleHolderInfo, i32)>, DieselError> {
+    let mut record: Option<(TitleHolderInfo, i32)> = None;
+
+    for (reign, defenses) in reign_defense_counts(conn, title_id)? {
+        if defenses > record.as_ref().map_or(0, |(_, best)| *best) {
+            record = Some((reign, defenses));
+        }
+    }
+
+    Ok(record)
+}
+
+/// Gets active titles that aren't being defended
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Undefended titles with their current holders, in display order
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// A title is undefended when its current reign has no defenses (as counted by
+/// `get_title_statistics`) or it has never had a champion at all. Vacant titles
+/// with past reigns are not included since there is no one to defend them.
+pub fn internal_get_undefended_titles(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<TitleWithHolders>, DieselError> {
+    use crate::schema::titles;
+
+    let active_titles = titles::table
+        .filter(titles::is_active.eq(true))
+        .order(titles::prestige_tier.asc())
+        .then_order_by(titles::display_order.asc())
+        .then_order_by(titles::name.asc())
+        .load::<Title>(conn)?;
+
+    let mut undefended = Vec::new();
+    for title in active_titles {
+        let reigns = reign_defense_counts(conn, title.id)?;
+        let current_defenses: Vec<i32> = reigns
+            .iter()
+            .filter(|(reign, _)| reign.holder.held_until.is_none())
+            .map(|(_, defenses)| *defenses)
+            .collect();
+
+        let never_held = reigns.is_empty();
+        let current_undefended = !current_defenses.is_empty() && current_defenses.iter().all(|&d| d == 0);
+        if never_held || current_undefended {
+            undefended.push(title);
+        }
+    }
+
+    attach_current_holders(conn, undefended)
+}
+
+/// Computes headline reign statistics for a title
+///
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title
+///
+/// # Returns
+/// * `Ok(TitleStats)` - Aggregated reign statistics
+/// * `Err(AppError::NotFound)` - If the title does not exist
+/// * `Err(AppError::Database)` - Database error if query fails
+///
+/// # Note
+/// Holders that share the same start and end form one reign, so a tag team
+/// counts once towards the reign totals while each member is a distinct
+/// champion. Ongoing reigns are counted up to now. A title that has never had
+/// a holder returns zero counts and `None` for every day-based statistic. The
+/// defense record is `None` until some reign has been successfully defended.
+pub fn internal_get_title_statistics(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+) -> Result<TitleStats, AppError> {
+    use crate::schema::{title_holders, titles};
+    use std::collections::HashSet;
+
+    let title_exists = titles::table
+        .find(title_id)
+        .select(titles::id)
+        .first::<i32>(conn)
+        .optional()?
+        .is_some();
+    if !title_exists {
+        return Err(AppError::NotFound(format!("Title {} not found", title_id)));
+    }
+
+    let holders = title_holders::table
+        .filter(title_holders::title_id.eq(title_id))
+        .select(TitleHolder::as_select())
+        .order(title_holders::held_since.desc())
+        .load::<TitleHolder>(conn)?;
+
+    let distinct_champions = holders
+        .iter()
+        .map(|holder| holder.wrestler_id)
+        .collect::<HashSet<_>>()
+        .len();
+
+    // Sorted newest first, with open reigns ahead of ended ones starting at the same time
+    let mut reigns: Vec<_> = holders
+        .iter()
+        .map(|holder| (holder.held_since, holder.held_until))
+        .collect();
+    reigns.sort_by_key(|&(held_since, held_until)| (std::cmp::Reverse(held_since), held_until));
+    reigns.dedup();
+
+    let now = Utc::now().naive_utc();
+    let reign_days: Vec<i32> = reigns
+        .iter()
+        .map(|(held_since, held_until)| (held_until.unwrap_or(now) - *held_since).num_days() as i32)
+        .collect();
+
+    // Reigns are sorted newest first, so the first open reign is the current one
+    let current_reign_days = reigns
+        .iter()
+        .zip(&reign_days)
+        .find(|((_, held_until), _)| held_until.is_none())
+        .map(|(_, days)| *days);
+
+    let average_reign_days = if reign_days.is_empty() {
+        None
+    } else {
+        Some(reign_days.iter().map(|&days| days as f64).sum::<f64>() / reign_days.len() as f64)
+    };
+
+    let most_defended = internal_get_most_defended_reign(conn, title_id)?;
+
+    Ok(TitleStats {
+        total_reigns: reigns.len() as i32,
+        distinct_champions: distinct_champions as i32,
+        longest_reign_days: reign_days.iter().copied().max(),
+        current_reign_days,
+        average_reign_days,
+        most_defenses_in_a_reign: most_defended.as_ref().map(|(_, defenses)| *defenses),
+        most_defended_champion: most_defended.map(|(reign, _)| reign.wrestler_name),
+    })
+}
+
+/// Tauri command to get headline reign statistics for a title
+///
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `title_id` - ID of the title
+///
+/// # Returns
+/// * `Ok(TitleStats)` - Aggregated reign statistics
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_title_statistics(
+    state: State<'_, DbState>,
+    title_id: i32,
+) -> Result<TitleStats, AppError> {
+    validate_id(title_id, "title_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_title_statistics(&mut conn, title_id).map_err(|e| {
+        error!("Error fetching statistics for title {}: {}", title_id, e);
+        e.with_context("Failed to fetch title statistics")
+    })
+}
+
+/// Tauri command to get active titles that aren't being defended
+///
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+///
+/// # Returns
+/// * `Ok(Vec<TitleWithHolders>)` - Titles whose current reign is undefended or that were never held
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_undefended_titles(state: State<'_, DbState>) -> Result<Vec<TitleWithHolders>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_undefended_titles(&mut conn).map_err(|e| {
+        error!("Error fetching undefended titles: {}", e);
+        AppError::from(e).with_context("Failed to fetch undefended titles")
+    })
+}
+
+/// Gets the first-ever champion of a title
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title
+/// 
+/// # Returns
+/// * `Ok(Some((Wrestler, NaiveDateTime)))` - The inaugural champion and when their reign began
+/// * `Ok(None)` - If the title has never had a holder
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_inaugural_champion(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+) -> Result<Option<(Wrestler, chrono::NaiveDateTime)>, DieselError> {
+    use crate::schema::{title_holders, wrestlers};
+
+    title_holders::table
+        .inner_join(wrestlers::table)
+        .filter(title_holders::title_id.eq(title_id))
+        .order((title_holders::held_since.asc(), title_holders::id.asc()))
+        .select((Wrestler::as_select(), title_holders::held_since))
+        .first::<(Wrestler, chrono::NaiveDateTime)>(conn)
+        .optional()
+}
+
+/// Tauri command to get the first-ever champion of a title
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `title_id` - ID of the title
+/// 
+/// # Returns
+/// * `Ok(Option<(Wrestler, NaiveDateTime)>)` - The inaugural champion and reign start, None if never held
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_inaugural_champion(
+    state: State<'_, DbState>,
+    title_id: i32,
+) -> Result<Option<(Wrestler, chrono::NaiveDateTime)>, AppError> {
+    validate_id(title_id, "title_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_inaugural_champion(&mut conn, title_id).map_err(|e| {
+        error!("Error fetching inaugural champion for title {}: {}", title_id, e);
+        AppError::from(e).with_context("Failed to fetch inaugural champion")
+    })
+}
+
+/// Gets every reign of a title in chronological order for a timeline view
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title
+/// 
+/// # Returns
+/// * `Ok(Vec<TimelineSegment>)` - Reigns sorted by start, oldest first
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Start and end times are returned exactly as stored: a title change ends the
+/// old reign at the same instant the new one starts, so consecutive segments
+/// touch without a gap. Ongoing reigns have no end and count days up to now.
+pub fn internal_get_title_timeline(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+) -> Result<Vec<TimelineSegment>, DieselError> {
+    use crate::schema::{title_holders, wrestlers};
+
+    let reigns = title_holders::table
+        .inner_join(wrestlers::table)
+        .filter(title_holders::title_id.eq(title_id))
+        .order(title_holders::held_since.asc())
+        .then_order_by(title_holders::id.asc())
+        .select((TitleHolder::as_select(), wrestlers::name))
+        .load::<(TitleHolder, String)>(conn)?;
+
+    let now = Utc::now().naive_utc();
+
+    Ok(reigns
+        .into_iter()
+        .map(|(holder, wrestler_name)| TimelineSegment {
+            wrestler_id: holder.wrestler_id,
+            wrestler_name,
+            held_since: holder.held_since,
+            held_until: holder.held_until,
+            days: (holder.held_until.unwrap_or(now) - holder.held_since).num_days() as i32,
+        })
+        .collect())
+}
+
+/// Tauri command to get every reign of a title in chronological order
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `title_id` - ID of the title
+/// 
+/// # Returns
+/// * `Ok(Vec<TimelineSegment>)` - Reigns sorted by start, oldest first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_title_timeline(
+    state: State<'_, DbState>,
+    title_id: i32,
+) -> Result<Vec<TimelineSegment>, AppError> {
+    validate_id(title_id, "title_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_title_timeline(&mut conn, title_id).map_err(|e| {
+        error!("Error fetching timeline for title {}: {}", title_id, e);
+        AppError::from(e).with_context("Failed to fetch title timeline")
+    })
+}
+
+/// Gets every point where a title was left vacant
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `title_id` - ID of the title
+/// 
+/// # Returns
+/// * `Ok(Vec<VacancyEvent>)` - Vacancies with their recorded reason, oldest first
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// A reign that ended without another reign starting at the same instant is
+/// a vacancy; title changes always start the new reign when the old one ends.
+/// Co-holders vacated together are reported as a single event.
+pub fn internal_get_vacancy_history(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+) -> Result<Vec<VacancyEvent>, DieselError> {
+    use crate::schema::title_holders;
+
+    let reign_starts = title_holders::table
+        .filter(title_holders::title_id.eq(title_id))
+        .select(title_holders::held_since)
+        .load::<chrono::NaiveDateTime>(conn)?;
+
+    let ended_reigns = title_holders::table
+        .filter(title_holders::title_id.eq(title_id))
+        .filter(title_holders::held_until.is_not_null())
+        .order(title_holders::held_until.asc())
+        .then_order_by(title_holders::id.asc())
+        .select((title_holders::held_until, title_holders::change_method))
+        .load::<(Option<chrono::NaiveDateTime>, Option<String>)>(conn)?;
+
+    let mut events: Vec<VacancyEvent> = Vec::new();
+    for (held_until, change_method) in ended_reigns {
+        let Some(when) = held_until else { continue };
+        if reign_starts.contains(&when) || events.last().is_some_and(|event| event.when == when) {
+            continue;
+        }
+        events.push(VacancyEvent { when, reason: change_method });
+    }
+
+    Ok(events)
+}
+
+/// Tauri command to get every point where a title was left vacant
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `title_id` - ID of the title
+/// 
+/// # Returns
+/// * `Ok(Vec<VacancyEvent>)` - Vacancies with their recorded reason, oldest first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_vacancy_history(
+    state: State<'_, DbState>,
+    title_id: i32,
+) -> Result<Vec<VacancyEvent>, AppError> {
+    validate_id(title_id, "title_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_vacancy_history(&mut conn, title_id).map_err(|e| {
+        error!("Error fetching vacancy history for title {}: {}", title_id, e);
+        AppError::from(e).with_context("Failed to fetch vacancy history")
+    })
+}
+
+/// Gets the average reign length of every title that has been held
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<(String, f64)>)` - (title name, average days) pairs, shortest average first
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Ongoing reigns are included and counted up to now, matching `get_title_statistics`,
+/// so a long current reign lifts the average even before it ends. Titles that
+/// have never been held are left out.
+pub fn internal_get_average_reigns(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<(String, f64)>, DieselError> {
+    use crate::schema::{title_holders, titles};
+    use std::collections::BTreeMap;
+
+    let reigns = title_holders::table
+        .inner_join(titles::table)
+        .select((titles::id, titles::name, title_holders::held_since, title_holders::held_until))
+        .load::<(i32, String, chrono::NaiveDateTime, Option<chrono::NaiveDateTime>)>(conn)?;
+
+    let now = Utc::now().naive_utc();
+    let mut days_by_title: BTreeMap<i32, (String, Vec<i64>)> = BTreeMap::new();
+    for (title_id, title_name, held_since, held_until) in reigns {
+        days_by_title
+            .entry(title_id)
+            .or_insert_with(|| (title_name, Vec::new()))
+            .1
+            .push((held_until.unwrap_or(now) - held_since).num_days());
+    }
+
+    let mut averages: Vec<(String, f64)> = days_by_title
+        .into_values()
+        .map(|(title_name, days)| {
+            let average = days.iter().sum::<i64>() as f64 / days.len() as f64;
+            (title_name, average)
+        })
+        .collect();
+    averages.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+
+    Ok(averages)
+}
+
+/// Tauri command to get the average reign length of every title
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<(String, f64)>)` - (title name, average days) pairs, shortest average first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_average_reigns(state: State<'_, DbState>) -> Result<Vec<(String, f64)>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_average_reigns(&mut conn).map_err(|e| {
+        error!("Error fetching average reign lengths: {}", e);
+        AppError::from(e).with_context("Failed to fetch average reign lengths")
+    })
+}
+
+/// Counts how title changes have happened across the universe
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `include_unknown` - Whether reigns without a change method are counted as "Unknown"
+/// 
+/// # Returns
+/// * `Ok(Vec<(String, i64)>)` - (change method, count) pairs, most common first
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_change_method_stats(
+    conn: &mut SqliteConnection,
+    include_unknown: bool,
+) -> Result<Vec<(String, i64)>, DieselError> {
+    use crate::schema::title_holders;
+    use diesel::dsl::count_star;
+
+    let counts = title_holders::table
+        .group_by(title_holders::change_method)
+        .select((title_holders::change_method, count_star()))
+        .load::<(Option<String>, i64)>(conn)?;
+
+    let mut stats: Vec<(String, i64)> = counts
+        .into_iter()
+        .filter_map(|(change_method, count)| match change_method {
+            Some(change_method) => Some((change_method, count)),
+            None if include_unknown => Some(("Unknown".to_string(), count)),
+            None => None,
+        })
+        .collect();
+    stats.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    Ok(stats)
+}
+
+/// Tauri command to count how title changes have happened
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `include_unknown` - Whether to count reigns without a change method (defaults to false)
+/// 
+/// # Returns
+/// * `Ok(Vec<(String, i64)>)` - (change method, count) pairs, most common first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_change_method_stats(
+    state: State<'_, DbState>,
+    include_unknown: Option<bool>,
+) -> Result<Vec<(String, i64)>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_change_method_stats(&mut conn, include_unknown.unwrap_or(false)).map_err(|e| {
+        error!("Error fetching change method stats: {}", e);
+        AppError::from(e).with_context("Failed to fetch change method stats")
+    })
+}
+
+/// Counts active titles per division
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<(String, i64)>)` - Each division with its active title count, largest first
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_count_titles_by_division(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<(String, i64)>, DieselError> {
+    use crate::schema::titles;
+    use diesel::dsl::count_star;
+
+    titles::table
+        .filter(titles::is_active.eq(true))
+        .group_by(titles::division)
+        .select((titles::division, count_star()))
+        .order((count_star().desc(), titles::division.asc()))
+        .load::<(String, i64)>(conn)
+}
+
+/// Counts the reigns that are currently running
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(i64)` - Number of title holder rows without an end date
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Counts reigns, not titles: a tag team title held by two wrestlers counts twice.
+pub fn internal_count_active_reigns(conn: &mut SqliteConnection) -> Result<i64, DieselError> {
+    use crate::schema::title_holders;
+
+    title_holders::table
+        .filter(title_holders::held_until.is_null())
+        .count()
+        .get_result::<i64>(conn)
+}
+
+/// Lists every division used by a title
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - Distinct divisions in alphabetical order
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_distinct_divisions(conn: &mut SqliteConnection) -> Result<Vec<String>, DieselError> {
+    use crate::schema::titles;
+
+    titles::table
+        .select(titles::division)
+        .distinct()
+        .order(titles::division.asc())
+        .load::<String>(conn)
+}
+
+/// Tauri command to count active titles per division
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<(String, i64)>)` - (division, count) pairs, largest first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn count_titles_by_division(state: State<'_, DbState>) -> Result<Vec<(String, i64)>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_count_titles_by_division(&mut conn).map_err(|e| {
+        error!("Error counting titles by division: {}", e);
+        AppError::from(e).with_context("Failed to count titles by division")
+    })
+}
+
+/// Tauri command to count the reigns that are currently running
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(i64)` - Number of active reigns
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn count_active_reigns(state: State<'_, DbState>) -> Result<i64, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_count_active_reigns(&mut conn).map_err(|e| {
+        error!("Error counting active reigns: {}", e);
+        AppError::from(e).with_context("Failed to count active reigns")
+    })
+}
+
+/// Tauri command to list every division used by a title
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - Distinct divisions in alphabetical order
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_distinct_divisions(state: State<'_, DbState>) -> Result<Vec<String>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_distinct_divisions(&mut conn).map_err(|e| {
+        error!("Error loading divisions: {}", e);
+        AppError::from(e).with_context("Failed to load divisions")
+    })
+}
+
+/// Tauri command to create comprehensive test data for development
+/// 
+/// Creates the following test data:
+/// - 2 shows (Monday Night RAW, Friday Night SmackDown)
+/// - 5 wrestlers with detailed profiles and signature moves
+/// - 15 championship titles across different tiers
+/// - Show roster assignments
+/// - Title holders
+/// - Sample matches with participants
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(String)` - Summary of created test data
+/// * `Err(String)` - Error message if creation fails or data already exists
+#[tauri::command]
+pub fn create_test_data(state: State<'_, DbState>) -> Result<String, String> {
+    let mut conn = get_connection(&state)?;
+    
+    // Check if specific test data already exists
+    let existing_shows = internal_get_shows(&mut conn).map_err(|e| format!("Error checking shows: {}", e))?;
+    
+    // Check for specific test shows instead of any shows
+    let test_show_names = ["Monday Night RAW", "Friday Night SmackDown"];
+    let existing_test_shows: Vec<_> = existing_shows.iter()
+        .filter(|show| test_show_names.contains(&show.name.as_str()))
+        .collect();
+    
+    if !existing_test_shows.is_empty() {
+        return Ok(format!("Test data already exists: found {} test shows", existing_test_shows.len()));
+    }
+    
+    // Create test shows
+    let test_shows = vec![
+        ("Monday Night RAW", "WWE's flagship weekly show featuring the biggest superstars"),
+        ("Friday Night SmackDown", "The longest-running weekly episodic TV show in history"),
+    ];
+    
+    for (name, description) in test_shows {
+        internal_create_show(&mut conn, name, description)
+            .map_err(|e| format!("Failed to create show '{}': {}", name, e))?;
+    }
+    
+    // Create test wrestlers with detailed information
+    let test_wrestlers = vec![
+        (
+            "The Rock", "Dwayne Johnson", "The People's Champion", "Male", 245, 67,
+            "6'5\"", "260 lbs", 1996, "WWE", 9, 6, 7, 9, 10, 8,
+            "The Rock is one of the most electrifying superstars in sports entertainment history. Known for his incredible charisma, devastating finishing moves, and ability to captivate audiences worldwide. From his days as 'Rocky Maivia' to becoming 'The People's Champion,' The Rock has dominated both the wrestling ring and Hollywood.",
+            true // This wrestler is user-created for testing modifications
+        ),
+        (
+            "Stone Cold Steve Austin", "Steven James Anderson", "The Texas Rattlesnake", "Male", 312, 89,
+            "6'2\"", "252 lbs", 1989, "WWE", 8, 7, 6, 8, 9, 7,
+            "Stone Cold Steve Austin is the beer-drinking, hell-raising anti-hero who defined the Attitude Era. With his rebellious nature and iconic catchphrases, Austin became the face of WWE during its most successful period.",
+            false
+        ),
+        (
+            "Becky Lynch", "Rebecca Quin", "The Man", "Female", 156, 43,
+            "5'6\"", "135 lbs", 2013, "WWE", 7, 8, 9, 8, 9, 8,
+            "Becky Lynch transformed from 'The Irish Lass Kicker' to 'The Man' - the top superstar in all of WWE. Her journey from underdog to champion inspired millions and redefined what it means to be a top star in sports entertainment.",
+            false
+        ),
+        (
+            "Charlotte Flair", "Ashley Elizabeth Fliehr", "The Queen", "Female", 198, 52,
+            "5'10\"", "143 lbs", 2012, "WWE", 7, 8, 8, 8, 9, 9,
+            "Charlotte Flair is a second-generation superstar who has established herself as one of the most dominant competitors in WWE history. The daughter of 'Nature Boy' Ric Flair, she has carved out her own legendary legacy.",
+            false
+        ),
+        (
+            "John Cena", "John Felix Anthony Cena Jr.", "The Cenation Leader", "Male", 289, 78,
+            "6'1\"", "251 lbs", 2002, "WWE", 8, 7, 7, 9, 10, 8,
+            "John Cena is a 16-time World Champion who became the face of WWE for over a decade. Known for his 'Never Give Up' attitude and incredible work ethic, Cena has inspired millions of fans worldwide while also pursuing a successful acting career.",
+            false
+        ),
+    ];
+    
+    for (name, real_name, nickname, gender, wins, losses, height, weight, debut_year, _unused_promotion, strength, speed, agility, stamina, charisma, technique, biography, is_user_created) in test_wrestlers {
+        let wrestler = internal_create_enhanced_wrestler(
+            &mut conn, name, real_name, nickname, gender, wins, losses, 
+            height, weight, debut_year, strength, speed, agility, 
+            stamina, charisma, technique, biography, is_user_created
+        ).map_err(|e| format!("Failed to create wrestler '{}': {}", name, e))?;
+        
+        // Add signature moves for each wrestler
+        let moves = match name {
+            "The Rock" => vec![
+                ("Rock Bottom", "primary"),
+                ("People's Elbow", "primary"), 
+                ("Samoan Drop", "secondary"),
+                ("Spinebuster", "secondary"),
+            ],
+            "Stone Cold Steve Austin" => vec![
+                ("Stone Cold Stunner", "primary"),
+                ("Lou Thesz Press", "secondary"),
+                ("Slingshot Suplex", "secondary"),
+            ],
+            "Becky Lynch" => vec![
+                ("Dis-arm-her", "primary"),
+                ("Manhandle Slam", "primary"),
+                ("Bex-Ploder", "secondary"),
+                ("Leg Drop", "secondary"),
+            ],
+            "Charlotte Flair" => vec![
+                ("Figure Eight", "primary"),
+                ("Natural Selection", "primary"),
+                ("Big Boot", "secondary"),
+                ("Spear", "secondary"),
+            ],
+            "John Cena" => vec![
+                ("Attitude Adjustment", "primary"),
+                ("STFU/STF", "primary"),
+                ("Five Knuckle Shuffle", "secondary"),
+                ("Flying Shoulder Block", "secondary"),
+            ],
+            _ => vec![],
+        };
+        
+        for (move_name, move_type) in moves {
+            internal_create_signature_move(&mut conn, wrestler.id, move_name, move_type)
+                .map_err(|e| format!("Failed to create move '{}' for '{}': {}", move_name, name, e))?;
+        }
+    }
+    
+    // Create test titles
+    let all_shows = internal_get_shows(&mut conn).map_err(|e| format!("Error getting shows: {}", e))?;
+    let raw_show = all_shows.iter()
+        .find(|show| show.name == "Monday Night RAW");
+    let smackdown_show = all_shows.iter()
+        .find(|show| show.name == "Friday Night SmackDown");
+
+    let test_titles = vec![
+        // Tier 1 - World Championships
+        ("World Heavyweight Championship", "Singles", "World", "Male", raw_show.map(|s| s.id)),
+        ("WWE Championship", "Singles", "WWE Championship", "Male", smackdown_show.map(|s| s.id)),
+        ("Women's World Championship", "Singles", "Women's World", "Female", raw_show.map(|s| s.id)),
+        ("WWE Women's Championship", "Singles", "WWE Women's Championship", "Female", smackdown_show.map(|s| s.id)),
+        
+        // Tier 2 - Secondary Championships
+        ("Intercontinental Championship", "Singles", "Intercontinental", "Male", raw_show.map(|s| s.id)),
+        ("United States Championship", "Singles", "United States", "Male", smackdown_show.map(|s| s.id)),
+        ("Women's Intercontinental Championship", "Singles", "Women's Intercontinental", "Female", raw_show.map(|s| s.id)),
+        ("Women's United States Championship", "Singles", "Women's United States", "Female", smackdown_show.map(|s| s.id)),
+        
+        // Tier 3 - Tag Team Championships
+        ("World Tag Team Championship", "Tag Team", "World Tag Team", "Male", raw_show.map(|s| s.id)),
+        ("WWE Tag Team Championship", "Tag Team", "WWE Tag Team", "Male", smackdown_show.map(|s| s.id)),
+        ("Women's Tag Team Championship", "Tag Team", "Women's Tag Team", "Female", None), // Cross-brand
+        
+        // Tier 4 - Specialty Championships
+        ("Money in the Bank", "Singles", "Money in the Bank", "Mixed", None),
+        ("Hardcore Championship", "Singles", "Hardcore", "Mixed", None),
+        ("Speed Championship", "Singles", "Speed", "Mixed", None),
+        ("24/7 Championship", "Singles", "24/7", "Mixed", None),
+    ];
+
+    let mut title_count = 0;
+    for (name, title_type, division, gender, show_id) in test_titles {
+        internal_create_belt(&mut conn, name, title_type, division, gender, show_id, None, false)
+            .map_err(|e| format!("Failed to create title '{}': {}", name, e))?;
+        title_count += 1;
+    }
+    
+    // Assign wrestlers to show rosters
+    let all_wrestlers = internal_get_wrestlers(&mut conn).map_err(|e| format!("Error getting wrestlers: {}", e))?;
+    let raw_show_id = raw_show.map(|s| s.id).ok_or("RAW show not found")?;
+    let smackdown_show_id = smackdown_show.map(|s| s.id).ok_or("SmackDown show not found")?;
+    
+    // Assign all 5 wrestlers to RAW
+    for wrestler in &all_wrestlers {
+        internal_assign_wrestler_to_show(&mut conn, raw_show_id, wrestler.id)
+            .map_err(|e| format!("Failed to assign wrestler {} to RAW: {}", wrestler.name, e))?;
+    }
+    
+    // Assign 3 wrestlers to SmackDown (Charlotte, Becky, and Stone Cold)
+    let smackdown_wrestlers = ["Charlotte Flair", "Becky Lynch", "Stone Cold Steve Austin"];
+    for wrestler in &all_wrestlers {
+        if smackdown_wrestlers.contains(&wrestler.name.as_str()) {
+            internal_assign_wrestler_to_show(&mut conn, smackdown_show_id, wrestler.id)
+                .map_err(|e| format!("Failed to assign wrestler {} to SmackDown: {}", wrestler.name, e))?;
+        }
+    }
+    
+    // Assign title holders
+    let all_titles = internal_get_titles(&mut conn).map_err(|e| format!("Error getting titles: {}", e))?;
+    
+    // Make The Rock the World Heavyweight Champion
+    if let Some(rock) = all_wrestlers.iter().find(|w| w.name == "The Rock") {
+        if let Some(whc) = all_titles.iter().find(|t| t.title.name == "World Heavyweight Championship") {
+            internal_update_title_holder(
+                &mut conn,
+                whc.title.id,
+                rock.id,
+                Some("Monday Night RAW"),
+                Some("Won in tournament final"),
+                None
+            ).map_err(|e| format!("Failed to assign World Heavyweight Championship: {}", e))?;
+        }
+    }
+    
+    // Make Charlotte Flair the WWE Women's Champion
+    if let Some(charlotte) = all_wrestlers.iter().find(|w| w.name == "Charlotte Flair") {
+        if let Some(wwe_womens) = all_titles.iter().find(|t| t.title.name == "WWE Women's Championship") {
+            internal_update_title_holder(
+                &mut conn,
+                wwe_womens.title.id,
+                charlotte.id,
+                Some("Friday Night SmackDown"),
+                Some("Defeated previous champion"),
+                None
+            ).map_err(|e| format!("Failed to assign WWE Women's Championship: {}", e))?;
+        }
+    }
+    
+    // Create sample matches
+    let match_data_list = vec![
+        // RAW matches
+        MatchData {
+            show_id: raw_show_id,
+            match_name: Some("World Heavyweight Championship Match".to_string()),
+            match_type: "Singles".to_string(),
+            match_stipulation: Some("Standard".to_string()),
+            scheduled_date: None,
+            match_order: Some(5),
+            is_title_match: true,
+            title_id: all_titles.iter().find(|t| t.title.name == "World Heavyweight Championship").map(|t| t.title.id),
+        },
+        MatchData {
+            show_id: raw_show_id,
+            match_name: Some("Grudge Match".to_string()),
+            match_type: "Singles".to_string(),
+            match_stipulation: Some("No Disqualification".to_string()),
+            scheduled_date: None,
+            match_order: Some(3),
+            is_title_match: false,
+            title_id: None,
+        },
+        MatchData {
+            show_id: raw_show_id,
+            match_name: Some("Opening Contest".to_string()),
+            match_type: "Singles".to_string(),
+            match_stipulation: Some("Standard".to_string()),
+            scheduled_date: None,
+            match_order: Some(1),
+            is_title_match: false,
+            title_id: None,
+        },
         // SmackDown matches
         MatchData {
             show_id: smackdown_show_id,
@@ -1814,700 +6176,3574 @@ pub fn create_test_data(state: State<'_, DbState>) -> Result<String, String> {
         },
     ];
     
-    let mut match_count = 0;
-    for match_data in match_data_list {
-        let created_match = internal_create_match(&mut conn, &match_data)
-            .map_err(|e| format!("Failed to create match '{}': {}", match_data.match_name.as_deref().unwrap_or("Unknown"), e))?;
-        
-        // Add participants based on match
-        match match_data.match_name.as_deref().unwrap_or("") {
-            "World Heavyweight Championship Match" => {
-                // The Rock vs John Cena
-                if let Some(rock) = all_wrestlers.iter().find(|w| w.name == "The Rock") {
-                    internal_add_wrestler_to_match(&mut conn, created_match.id, rock.id, None, Some(1))
-                        .map_err(|e| format!("Failed to add The Rock to match: {}", e))?;
-                }
-                if let Some(cena) = all_wrestlers.iter().find(|w| w.name == "John Cena") {
-                    internal_add_wrestler_to_match(&mut conn, created_match.id, cena.id, None, Some(2))
-                        .map_err(|e| format!("Failed to add John Cena to match: {}", e))?;
-                }
-                // Set The Rock as winner
-                if let Some(rock) = all_wrestlers.iter().find(|w| w.name == "The Rock") {
-                    internal_set_match_winner(&mut conn, created_match.id, rock.id)
-                        .map_err(|e| format!("Failed to set match winner: {}", e))?;
-                }
-            },
-            "Grudge Match" => {
-                // Stone Cold vs The Rock
-                if let Some(austin) = all_wrestlers.iter().find(|w| w.name == "Stone Cold Steve Austin") {
-                    internal_add_wrestler_to_match(&mut conn, created_match.id, austin.id, None, Some(1))
-                        .map_err(|e| format!("Failed to add Stone Cold to match: {}", e))?;
-                }
-                if let Some(rock) = all_wrestlers.iter().find(|w| w.name == "The Rock") {
-                    internal_add_wrestler_to_match(&mut conn, created_match.id, rock.id, None, Some(2))
-                        .map_err(|e| format!("Failed to add The Rock to match: {}", e))?;
-                }
-            },
-            "Opening Contest" => {
-                // Becky Lynch vs John Cena (intergender match)
-                if let Some(becky) = all_wrestlers.iter().find(|w| w.name == "Becky Lynch") {
-                    internal_add_wrestler_to_match(&mut conn, created_match.id, becky.id, None, Some(1))
-                        .map_err(|e| format!("Failed to add Becky Lynch to match: {}", e))?;
-                }
-                if let Some(cena) = all_wrestlers.iter().find(|w| w.name == "John Cena") {
-                    internal_add_wrestler_to_match(&mut conn, created_match.id, cena.id, None, Some(2))
-                        .map_err(|e| format!("Failed to add John Cena to match: {}", e))?;
-                }
-            },
-            "WWE Women's Championship Match" => {
-                // Charlotte vs Becky
-                if let Some(charlotte) = all_wrestlers.iter().find(|w| w.name == "Charlotte Flair") {
-                    internal_add_wrestler_to_match(&mut conn, created_match.id, charlotte.id, None, Some(1))
-                        .map_err(|e| format!("Failed to add Charlotte to match: {}", e))?;
-                }
-                if let Some(becky) = all_wrestlers.iter().find(|w| w.name == "Becky Lynch") {
-                    internal_add_wrestler_to_match(&mut conn, created_match.id, becky.id, None, Some(2))
-                        .map_err(|e| format!("Failed to add Becky to match: {}", e))?;
-                }
-                // Set Charlotte as winner
-                if let Some(charlotte) = all_wrestlers.iter().find(|w| w.name == "Charlotte Flair") {
-                    internal_set_match_winner(&mut conn, created_match.id, charlotte.id)
-                        .map_err(|e| format!("Failed to set match winner: {}", e))?;
-                }
-            },
-            "Main Event Singles Match" => {
-                // Stone Cold vs Charlotte
-                if let Some(austin) = all_wrestlers.iter().find(|w| w.name == "Stone Cold Steve Austin") {
-                    internal_add_wrestler_to_match(&mut conn, created_match.id, austin.id, None, Some(1))
-                        .map_err(|e| format!("Failed to add Stone Cold to match: {}", e))?;
-                }
-                if let Some(charlotte) = all_wrestlers.iter().find(|w| w.name == "Charlotte Flair") {
-                    internal_add_wrestler_to_match(&mut conn, created_match.id, charlotte.id, None, Some(2))
-                        .map_err(|e| format!("Failed to add Charlotte to match: {}", e))?;
+    let mut match_count = 0;
+    for match_data in match_data_list {
+        let created_match = internal_create_match(&mut conn, &match_data)
+            .map_err(|e| format!("Failed to create match '{}': {}", match_data.match_name.as_deref().unwrap_or("Unknown"), e))?;
+        
+        // Add participants based on match
+        match match_data.match_name.as_deref().unwrap_or("") {
+            "World Heavyweight Championship Match" => {
+                // The Rock vs John Cena
+                if let Some(rock) = all_wrestlers.iter().find(|w| w.name == "The Rock") {
+                    internal_add_wrestler_to_match(&mut conn, created_match.id, rock.id, None, Some(1))
+                        .map_err(|e| format!("Failed to add The Rock to match: {}", e))?;
+                }
+                if let Some(cena) = all_wrestlers.iter().find(|w| w.name == "John Cena") {
+                    internal_add_wrestler_to_match(&mut conn, created_match.id, cena.id, None, Some(2))
+                        .map_err(|e| format!("Failed to add John Cena to match: {}", e))?;
+                }
+                // Set The Rock as winner
+                if let Some(rock) = all_wrestlers.iter().find(|w| w.name == "The Rock") {
+                    internal_set_match_winner(&mut conn, created_match.id, rock.id)
+                        .map_err(|e| format!("Failed to set match winner: {}", e))?;
+                }
+            },
+            "Grudge Match" => {
+                // Stone Cold vs The Rock
+                if let Some(austin) = all_wrestlers.iter().find(|w| w.name == "Stone Cold Steve Austin") {
+                    internal_add_wrestler_to_match(&mut conn, created_match.id, austin.id, None, Some(1))
+                        .map_err(|e| format!("Failed to add Stone Cold to match: {}", e))?;
+                }
+                if let Some(rock) = all_wrestlers.iter().find(|w| w.name == "The Rock") {
+                    internal_add_wrestler_to_match(&mut conn, created_match.id, rock.id, None, Some(2))
+                        .map_err(|e| format!("Failed to add The Rock to match: {}", e))?;
+                }
+            },
+            "Opening Contest" => {
+                // Becky Lynch vs John Cena (intergender match)
+                if let Some(becky) = all_wrestlers.iter().find(|w| w.name == "Becky Lynch") {
+                    internal_add_wrestler_to_match(&mut conn, created_match.id, becky.id, None, Some(1))
+                        .map_err(|e| format!("Failed to add Becky Lynch to match: {}", e))?;
+                }
+                if let Some(cena) = all_wrestlers.iter().find(|w| w.name == "John Cena") {
+                    internal_add_wrestler_to_match(&mut conn, created_match.id, cena.id, None, Some(2))
+                        .map_err(|e| format!("Failed to add John Cena to match: {}", e))?;
+                }
+            },
+            "WWE Women's Championship Match" => {
+                // Charlotte vs Becky
+                if let Some(charlotte) = all_wrestlers.iter().find(|w| w.name == "Charlotte Flair") {
+                    internal_add_wrestler_to_match(&mut conn, created_match.id, charlotte.id, None, Some(1))
+                        .map_err(|e| format!("Failed to add Charlotte to match: {}", e))?;
+                }
+                if let Some(becky) = all_wrestlers.iter().find(|w| w.name == "Becky Lynch") {
+                    internal_add_wrestler_to_match(&mut conn, created_match.id, becky.id, None, Some(2))
+                        .map_err(|e| format!("Failed to add Becky to match: {}", e))?;
+                }
+                // Set Charlotte as winner
+                if let Some(charlotte) = all_wrestlers.iter().find(|w| w.name == "Charlotte Flair") {
+                    internal_set_match_winner(&mut conn, created_match.id, charlotte.id)
+                        .map_err(|e| format!("Failed to set match winner: {}", e))?;
+                }
+            },
+            "Main Event Singles Match" => {
+                // Stone Cold vs Charlotte
+                if let Some(austin) = all_wrestlers.iter().find(|w| w.name == "Stone Cold Steve Austin") {
+                    internal_add_wrestler_to_match(&mut conn, created_match.id, austin.id, None, Some(1))
+                        .map_err(|e| format!("Failed to add Stone Cold to match: {}", e))?;
+                }
+                if let Some(charlotte) = all_wrestlers.iter().find(|w| w.name == "Charlotte Flair") {
+                    internal_add_wrestler_to_match(&mut conn, created_match.id, charlotte.id, None, Some(2))
+                        .map_err(|e| format!("Failed to add Charlotte to match: {}", e))?;
+                }
+            },
+            _ => {}
+        }
+        
+        match_count += 1;
+    }
+    
+    info!("Test data created successfully");
+    Ok(format!("Test data created: 2 shows, 5 wrestlers, {} titles, show rosters assigned, 2 title holders, and {} matches with participants", title_count, match_count))
+}
+
+// ===== Show Roster Operations =====
+
+/// Gets all wrestlers assigned to a specific show's roster
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Vector of wrestlers on the show's roster
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Only returns active roster assignments
+pub fn internal_get_wrestlers_for_show(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<Vec<Wrestler>, DieselError> {
+    use crate::schema::{wrestlers, show_rosters};
+    
+    wrestlers::table
+        .inner_join(show_rosters::table.on(wrestlers::id.eq(show_rosters::wrestler_id)))
+        .filter(show_rosters::show_id.eq(show_id))
+        .filter(show_rosters::is_active.eq(true))
+        .select(Wrestler::as_select())
+        .order(wrestlers::name.asc())
+        .load::<Wrestler>(conn)
+}
+
+/// Gets a show's roster with each wrestler's record and title status
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(Vec<RosterMember>)` - Roster members ordered by name
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_show_roster_detailed(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<Vec<RosterMember>, DieselError> {
+    use crate::schema::title_holders;
+
+    let roster = internal_get_wrestlers_for_show(conn, show_id)?;
+    let roster_ids: Vec<i32> = roster.iter().map(|w| w.id).collect();
+
+    let champion_ids = title_holders::table
+        .filter(title_holders::wrestler_id.eq_any(&roster_ids))
+        .filter(title_holders::held_until.is_null())
+        .select(title_holders::wrestler_id)
+        .load::<i32>(conn)?;
+
+    Ok(roster
+        .into_iter()
+        .map(|wrestler| RosterMember {
+            wins: wrestler.wins,
+            losses: wrestler.losses,
+            is_champion: champion_ids.contains(&wrestler.id),
+            wrestler,
+        })
+        .collect())
+}
+
+/// Gets all wrestlers not currently assigned to any show (internal function)
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Vector of unassigned wrestlers
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Uses LEFT JOIN to find wrestlers with no active show roster assignments
+pub fn internal_get_unassigned_wrestlers(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<Wrestler>, DieselError> {
+    use crate::schema::{wrestlers, show_rosters};
+    
+    wrestlers::table
+        .left_join(
+            show_rosters::table.on(
+                wrestlers::id.eq(show_rosters::wrestler_id)
+                    .and(show_rosters::is_active.eq(true))
+            )
+        )
+        .filter(show_rosters::wrestler_id.is_null())
+        .select(Wrestler::as_select())
+        .order(wrestlers::name.asc())
+        .load::<Wrestler>(conn)
+}
+
+/// Gets unassigned wrestlers along with their record and current titles
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<FreeAgent>)` - Free agents ordered by overall rating (unrated last), then name
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_free_agents(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<FreeAgent>, DieselError> {
+    use crate::schema::{titles, title_holders};
+
+    let unassigned = internal_get_unassigned_wrestlers(conn)?;
+    let wrestler_ids: Vec<i32> = unassigned.iter().map(|w| w.id).collect();
+
+    let held_titles = title_holders::table
+        .inner_join(titles::table.on(title_holders::title_id.eq(titles::id)))
+        .filter(title_holders::wrestler_id.eq_any(&wrestler_ids))
+        .filter(title_holders::held_until.is_null())
+        .select((title_holders::wrestler_id, titles::name))
+        .order(titles::prestige_tier.asc())
+        .load::<(i32, String)>(conn)?;
+
+    let mut free_agents: Vec<FreeAgent> = unassigned
+        .into_iter()
+        .map(|wrestler| FreeAgent {
+            win_rate: wrestler.win_rate(),
+            overall_rating: wrestler.overall_rating(),
+            current_titles: held_titles
+                .iter()
+                .filter(|(wrestler_id, _)| *wrestler_id == wrestler.id)
+                .map(|(_, title_name)| title_name.clone())
+                .collect(),
+            wrestler,
+        })
+        .collect();
+
+    // Stable sort keeps the name ordering for equal ratings
+    free_agents.sort_by(|a, b| {
+        b.overall_rating
+            .partial_cmp(&a.overall_rating)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(free_agents)
+}
+
+/// Gets rostered wrestlers who have never been booked in a match
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Benchwarmers ordered by name
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Only active roster assignments count, so free agents are never included.
+/// Any match participation, in any role, takes a wrestler off the bench.
+pub fn internal_get_benchwarmers(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<Wrestler>, DieselError> {
+    use crate::schema::{match_participants, show_rosters, wrestlers};
+    use diesel::dsl::{exists, not};
+
+    wrestlers::table
+        .filter(exists(
+            show_rosters::table
+                .filter(show_rosters::wrestler_id.eq(wrestlers::id))
+                .filter(show_rosters::is_active.eq(true)),
+        ))
+        .filter(not(exists(
+            match_participants::table.filter(match_participants::wrestler_id.eq(wrestlers::id)),
+        )))
+        .select(Wrestler::as_select())
+        .order(wrestlers::name.asc())
+        .load::<Wrestler>(conn)
+}
+
+/// Gets the current active show assignment for a wrestler
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler to check
+/// 
+/// # Returns
+/// * `Ok(Option<ShowRoster>)` - The active assignment if found, None otherwise
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_current_show_for_wrestler(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+) -> Result<Option<ShowRoster>, DieselError> {
+    use crate::schema::show_rosters;
+    
+    show_rosters::table
+        .filter(show_rosters::wrestler_id.eq(wrestler_id))
+        .filter(show_rosters::is_active.eq(true))
+        .first::<ShowRoster>(conn)
+        .optional()
+}
+
+/// Gets the roster assignment mode
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(String)` - "exclusive" or "multi"
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Defaults to "exclusive" until a mode has been set, and for any unrecognized
+/// value stored through `set_setting`
+pub fn internal_get_roster_mode(conn: &mut SqliteConnection) -> Result<String, DieselError> {
+    let mode = internal_get_setting(conn, ROSTER_MODE_KEY)?
+        .filter(|mode| ROSTER_MODES.contains(&mode.as_str()));
+
+    Ok(mode.unwrap_or_else(|| ROSTER_MODE_EXCLUSIVE.to_string()))
+}
+
+/// Sets the roster assignment mode
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `mode` - "exclusive" to transfer wrestlers between shows, "multi" to allow several shows
+/// 
+/// # Returns
+/// * `Ok(String)` - The stored mode
+/// * `Err(AppError::Validation)` - If the mode is not recognized
+/// * `Err(AppError::Database)` - Database error if the update fails
+/// 
+/// # Note
+/// Existing assignments are left as they are; the mode only affects later assignments
+pub fn internal_set_roster_mode(conn: &mut SqliteConnection, mode: &str) -> Result<String, AppError> {
+    if !ROSTER_MODES.contains(&mode) {
+        return Err(AppError::Validation(format!(
+            "roster_mode: '{}' is not one of {}",
+            mode,
+            ROSTER_MODES.join(", ")
+        )));
+    }
+
+    internal_set_setting(conn, ROSTER_MODE_KEY, mode).map(|setting| setting.value)
+}
+
+/// Assigns a wrestler to a show's roster, following the roster mode
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// * `wrestler_id` - ID of the wrestler to assign
+/// 
+/// # Returns
+/// * `Ok(())` - If assignment was successful or already exists
+/// * `Err(DieselError)` - Database error if assignment fails
+/// 
+/// # Note
+/// In "exclusive" mode (the default) a wrestler can only be on one show at a time,
+/// so assigning them transfers them off their current show. In "multi" mode their
+/// other assignments stay active.
+/// Uses database transaction for atomicity
+pub fn internal_assign_wrestler_to_show(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+    wrestler_id: i32,
+) -> Result<(), DieselError> {
+    use crate::schema::show_rosters;
+    use chrono::Utc;
+    
+    // Use transaction for atomicity
+    conn.transaction::<(), DieselError, _>(|conn| {
+        // Check if wrestler is already assigned to the target show
+        let existing_assignment = show_rosters::table
+            .filter(show_rosters::show_id.eq(show_id))
+            .filter(show_rosters::wrestler_id.eq(wrestler_id))
+            .first::<ShowRoster>(conn)
+            .optional()?;
+        
+        if let Some(assignment) = existing_assignment {
+            if assignment.is_active {
+                // Wrestler already assigned to this show and active - nothing to do
+                info!("Wrestler {} already assigned to show {} (active)", wrestler_id, show_id);
+                return Ok(());
+            } else {
+                // Reactivate the existing inactive assignment to this show
+                info!("Reactivating existing assignment for wrestler {} to show {}", wrestler_id, show_id);
+                diesel::update(show_rosters::table)
+                    .filter(show_rosters::id.eq(assignment.id))
+                    .set((
+                        show_rosters::is_active.eq(true),
+                        show_rosters::assigned_at.eq(Some(Utc::now().naive_utc())),
+                    ))
+                    .execute(conn)?;
+                return Ok(());
+            }
+        }
+        
+        // Shared rosters keep every other assignment
+        if internal_get_roster_mode(conn)? == ROSTER_MODE_MULTI {
+            info!("Assigning wrestler {} to show {} (multi-show roster)", wrestler_id, show_id);
+            diesel::insert_into(show_rosters::table)
+                .values(&NewShowRoster {
+                    show_id,
+                    wrestler_id,
+                    assigned_at: Some(Utc::now().naive_utc()),
+                    is_active: true,
+                })
+                .execute(conn)?;
+            return Ok(());
+        }
+        
+        // Check if wrestler is currently assigned to ANY other show
+        let current_assignment = internal_get_current_show_for_wrestler(conn, wrestler_id)?;
+        
+        if let Some(current) = current_assignment {
+            if current.show_id == show_id {
+                // Already assigned to target show (shouldn't happen due to check above, but defensive)
+                info!("Wrestler {} already assigned to show {} (active)", wrestler_id, show_id);
+                return Ok(());
+            }
+            
+            // Deactivate current assignment (transfer)
+            info!("Transferring wrestler {} from show {} to show {}", wrestler_id, current.show_id, show_id);
+            diesel::update(show_rosters::table)
+                .filter(show_rosters::id.eq(current.id))
+                .set(show_rosters::is_active.eq(false))
+                .execute(conn)?;
+        } else {
+            info!("Assigning wrestler {} to show {} (new assignment)", wrestler_id, show_id);
+        }
+        
+        // Create new assignment to target show
+        let new_assignment = NewShowRoster {
+            show_id,
+            wrestler_id,
+            assigned_at: Some(Utc::now().naive_utc()),
+            is_active: true,
+        };
+        
+        diesel::insert_into(show_rosters::table)
+            .values(&new_assignment)
+            .execute(conn)?;
+        
+        Ok(())
+    })?;
+
+    internal_log_operation(
+        conn,
+        "roster_assigned",
+        "wrestler",
+        Some(wrestler_id),
+        Some(&format!("Assigned to show {}", show_id)),
+    );
+
+    Ok(())
+}
+
+/// Removes a wrestler from a show's roster
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// * `wrestler_id` - ID of the wrestler to remove
+/// 
+/// # Returns
+/// * `Ok(())` - If removal was successful
+/// * `Err(DieselError)` - Database error if removal fails
+/// 
+/// # Note
+/// Sets is_active to false rather than deleting the record
+pub fn internal_remove_wrestler_from_show(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+    wrestler_id: i32,
+) -> Result<(), DieselError> {
+    use crate::schema::show_rosters;
+    
+    let removed = diesel::update(show_rosters::table)
+        .filter(show_rosters::show_id.eq(show_id))
+        .filter(show_rosters::wrestler_id.eq(wrestler_id))
+        .filter(show_rosters::is_active.eq(true))
+        .set(show_rosters::is_active.eq(false))
+        .execute(conn)?;
+
+    if removed > 0 {
+        internal_log_operation(
+            conn,
+            "roster_removed",
+            "wrestler",
+            Some(wrestler_id),
+            Some(&format!("Removed from show {}", show_id)),
+        );
+    }
+    
+    Ok(())
+}
+
+/// Reactivates a wrestler's removed assignment to a specific show
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// * `wrestler_id` - ID of the wrestler
+/// 
+/// # Returns
+/// * `Ok(ShowRoster)` - The reactivated (or already active) assignment
+/// * `Err(AppError::NotFound)` - If the wrestler was never assigned to the show
+/// * `Err(AppError::Database)` - Database error if the update fails
+/// 
+/// # Note
+/// Unlike `internal_assign_wrestler_to_show`, this never transfers the wrestler:
+/// assignments to other shows stay active, so the wrestler can temporarily
+/// appear on several rosters. The original `assigned_at` is kept.
+pub fn internal_restore_roster_assignment(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+    wrestler_id: i32,
+) -> Result<ShowRoster, AppError> {
+    use crate::schema::show_rosters;
+
+    let assignment = show_rosters::table
+        .filter(show_rosters::show_id.eq(show_id))
+        .filter(show_rosters::wrestler_id.eq(wrestler_id))
+        .first::<ShowRoster>(conn)
+        .optional()?
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Wrestler {} has no roster assignment on show {}",
+                wrestler_id, show_id
+            ))
+        })?;
+
+    if assignment.is_active {
+        return Ok(assignment);
+    }
+
+    diesel::update(show_rosters::table.find(assignment.id))
+        .set(show_rosters::is_active.eq(true))
+        .returning(ShowRoster::as_returning())
+        .get_result(conn)
+        .map_err(AppError::from)
+}
+
+/// Tauri command to get all wrestlers on a show's roster
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(Vec<Wrestler>)` - Vector of wrestlers on the roster
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_wrestlers_for_show(
+    state: State<'_, DbState>,
+    show_id: i32,
+) -> Result<Vec<Wrestler>, String> {
+    validate_id(show_id, "show_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+    
+    internal_get_wrestlers_for_show(&mut conn, show_id)
+        .map_err(|e| {
+            error!("Error loading wrestlers for show: {}", e);
+            format!("Failed to load wrestlers for show: {}", e)
+        })
+}
+
+/// Tauri command to get a show's roster with records and title status
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(Vec<RosterMember>)` - Roster members ordered by name
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_show_roster_detailed(
+    state: State<'_, DbState>,
+    show_id: i32,
+) -> Result<Vec<RosterMember>, AppError> {
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_show_roster_detailed(&mut conn, show_id).map_err(|e| {
+        error!("Error loading detailed roster for show {}: {}", show_id, e);
+        AppError::from(e).with_context("Failed to load show roster")
+    })
+}
+
+/// Summarizes the make-up of a show's active roster
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(RosterBalance)` - Roster size and wrestler count per gender, ordered by gender
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Wrestlers have no face/heel alignment yet, so only the gender split is reported
+pub fn internal_get_roster_balance(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<RosterBalance, DieselError> {
+    use crate::schema::{show_rosters, wrestlers};
+    use diesel::dsl::count_star;
+
+    let gender_split = show_rosters::table
+        .inner_join(wrestlers::table.on(show_rosters::wrestler_id.eq(wrestlers::id)))
+        .filter(show_rosters::show_id.eq(show_id))
+        .filter(show_rosters::is_active.eq(true))
+        .group_by(wrestlers::gender)
+        .select((wrestlers::gender, count_star()))
+        .order(wrestlers::gender.asc())
+        .load::<(String, i64)>(conn)?;
+
+    Ok(RosterBalance {
+        show_id,
+        total: gender_split.iter().map(|(_, count)| count).sum(),
+        gender_split,
+    })
+}
+
+/// Ranks a wrestler among their show's active roster by win rate
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// * `wrestler_id` - ID of the wrestler
+/// 
+/// # Returns
+/// * `Ok(Some(RankPosition))` - The wrestler's position (1 is best) out of the roster size
+/// * `Ok(None)` - If the wrestler is not on the show's active roster
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Tied win rates share a position. Wrestlers without any recorded matches
+/// rank below everyone with a record.
+pub fn internal_get_wrestler_rank_in_show(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+    wrestler_id: i32,
+) -> Result<Option<RankPosition>, DieselError> {
+    let roster = internal_get_wrestlers_for_show(conn, show_id)?;
+
+    let Some(wrestler) = roster.iter().find(|w| w.id == wrestler_id) else {
+        return Ok(None);
+    };
+    let win_rate = wrestler.win_rate();
+
+    // Option ordering puts None below every Some, so unrecorded wrestlers sink
+    let ahead = roster.iter().filter(|other| other.win_rate() > win_rate).count();
+
+    Ok(Some(RankPosition {
+        position: ahead as i32 + 1,
+        total: roster.len() as i32,
+        win_rate,
+    }))
+}
+
+/// Tauri command to summarize the make-up of a show's active roster
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(RosterBalance)` - Roster size and wrestler count per gender
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_roster_balance(
+    state: State<'_, DbState>,
+    show_id: i32,
+) -> Result<RosterBalance, AppError> {
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_roster_balance(&mut conn, show_id).map_err(|e| {
+        error!("Error loading roster balance for show {}: {}", show_id, e);
+        AppError::from(e).with_context("Failed to load roster balance")
+    })
+}
+
+/// Tauri command to rank a wrestler among their show's roster by win rate
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// * `wrestler_id` - ID of the wrestler
+/// 
+/// # Returns
+/// * `Ok(Option<RankPosition>)` - The wrestler's position, None if they aren't on the show
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_wrestler_rank_in_show(
+    state: State<'_, DbState>,
+    show_id: i32,
+    wrestler_id: i32,
+) -> Result<Option<RankPosition>, AppError> {
+    validate_id(show_id, "show_id")?;
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_wrestler_rank_in_show(&mut conn, show_id, wrestler_id).map_err(|e| {
+        error!("Error ranking wrestler {} on show {}: {}", wrestler_id, show_id, e);
+        AppError::from(e).with_context("Failed to rank wrestler")
+    })
+}
+
+/// Tauri command to assign a wrestler to a show's roster
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// * `wrestler_id` - ID of the wrestler to assign
+/// 
+/// # Returns
+/// * `Ok(String)` - Success message
+/// * `Err(String)` - Error message if assignment fails
+#[tauri::command]
+pub fn assign_wrestler_to_show(
+    state: State<'_, DbState>,
+    show_id: i32,
+    wrestler_id: i32,
+) -> Result<String, String> {
+    validate_id(show_id, "show_id").map_err(|e| e.to_string())?;
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+    
+    internal_assign_wrestler_to_show(&mut conn, show_id, wrestler_id)
+        .map_err(|e| {
+            error!("Error assigning wrestler to show: {}", e);
+            format!("Failed to assign wrestler to show: {}", e)
+        })
+        .map(|_| "Wrestler assigned to show successfully".to_string())
+}
+
+/// Tauri command to remove a wrestler from a show's roster
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// * `wrestler_id` - ID of the wrestler to remove
+/// 
+/// # Returns
+/// * `Ok(String)` - Success message
+/// * `Err(String)` - Error message if removal fails
+#[tauri::command]
+pub fn remove_wrestler_from_show(
+    state: State<'_, DbState>,
+    show_id: i32,
+    wrestler_id: i32,
+) -> Result<String, String> {
+    validate_id(show_id, "show_id").map_err(|e| e.to_string())?;
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+    
+    internal_remove_wrestler_from_show(&mut conn, show_id, wrestler_id)
+        .map_err(|e| {
+            error!("Error removing wrestler from show: {}", e);
+            format!("Failed to remove wrestler from show: {}", e)
+        })
+        .map(|_| "Wrestler removed from show successfully".to_string())
+}
+
+/// Tauri command to reactivate a wrestler's removed assignment to a show
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// * `wrestler_id` - ID of the wrestler
+/// 
+/// # Returns
+/// * `Ok(ShowRoster)` - The reactivated assignment
+/// * `Err(AppError)` - Categorized error if the assignment is missing or the update fails
+/// 
+/// # Note
+/// Use `assign_wrestler_to_show` for a normal move between shows; this keeps
+/// any other active assignments in place
+#[tauri::command]
+pub fn restore_roster_assignment(
+    state: State<'_, DbState>,
+    show_id: i32,
+    wrestler_id: i32,
+) -> Result<ShowRoster, AppError> {
+    validate_id(show_id, "show_id")?;
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_restore_roster_assignment(&mut conn, show_id, wrestler_id)
+        .inspect(|_| {
+            info!("Restored roster assignment for wrestler {} on show {}", wrestler_id, show_id);
+        })
+        .map_err(|e| {
+            error!("Error restoring roster assignment: {}", e);
+            e.with_context("Failed to restore roster assignment")
+        })
+}
+
+/// Tauri command to get the roster assignment mode
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(String)` - "exclusive" or "multi"
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_roster_mode(state: State<'_, DbState>) -> Result<String, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_roster_mode(&mut conn).map_err(|e| {
+        error!("Error loading roster mode: {}", e);
+        AppError::from(e).with_context("Failed to load roster mode")
+    })
+}
+
+/// Tauri command to set the roster assignment mode
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `mode` - "exclusive" or "multi"
+/// 
+/// # Returns
+/// * `Ok(String)` - The stored mode
+/// * `Err(AppError)` - Categorized error if the mode is invalid or the update fails
+#[tauri::command]
+pub fn set_roster_mode(state: State<'_, DbState>, mode: String) -> Result<String, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_set_roster_mode(&mut conn, &mode)
+        .inspect(|mode| {
+            info!("Roster mode set to '{}'", mode);
+        })
+        .map_err(|e| {
+            error!("Error setting roster mode: {}", e);
+            e.with_context("Failed to set roster mode")
+        })
+}
+
+/// Internal function to get shows that a wrestler is currently assigned to
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+/// 
+/// # Returns
+/// * `Ok(Vec<Show>)` - Vector of shows the wrestler is assigned to
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Only returns shows with active roster assignments.
+/// Result set is typically small (1-5 shows per wrestler in most cases).
+/// Returns empty vector if wrestler has no active assignments.
+/// 
+/// # Examples
+/// ```rust,no_run
+/// # use diesel::prelude::*;
+/// # use wwe_universe_manager_lib::db::internal_get_shows_for_wrestler;
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let mut conn = todo!();
+/// # let wrestler_id = 1;
+/// let shows = internal_get_shows_for_wrestler(&mut conn, wrestler_id)?;
+/// for show in shows {
+///     println!("Wrestler is assigned to: {}", show.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn internal_get_shows_for_wrestler(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+) -> Result<Vec<Show>, DieselError> {
+    use crate::schema::{shows, show_rosters};
+    
+    shows::table
+        .inner_join(show_rosters::table.on(shows::id.eq(show_rosters::show_id)))
+        .filter(show_rosters::wrestler_id.eq(wrestler_id))
+        .filter(show_rosters::is_active.eq(true))
+        .select(Show::as_select())
+        .order(shows::name.asc())
+        .load::<Show>(conn)
+}
+
+/// Tauri command to get shows that a wrestler is currently assigned to
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
+/// 
+/// # Returns
+/// * `Ok(Vec<Show>)` - Vector of shows the wrestler is assigned to
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_shows_for_wrestler(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+) -> Result<Vec<Show>, String> {
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+    
+    internal_get_shows_for_wrestler(&mut conn, wrestler_id)
+        .map_err(|e| {
+            error!("Error loading shows for wrestler {}: {}", wrestler_id, e);
+            format!("Failed to load shows for wrestler {}: {}", wrestler_id, e)
+        })
+}
+
+// ===== Match Booking Operations =====
+
+/// Stipulations accepted for a match (a missing stipulation means a standard match)
+const MATCH_STIPULATIONS: &[&str] = &[
+    "Standard",
+    "No DQ",
+    "No Disqualification",
+    "No Holds Barred",
+    "Street Fight",
+    "Last Man Standing",
+    "Submission Match",
+    "Iron Man Match",
+    "Hell in a Cell",
+    "Hardcore",
+    "Falls Count Anywhere",
+];
+
+/// Returns the allowed participant range for a match type
+/// 
+/// # Returns
+/// * `Some((min, max))` - Participant bounds, with `None` meaning no upper limit
+/// * `None` - If the match type is not recognized
+fn match_participant_limits(match_type: &str) -> Option<(usize, Option<usize>)> {
+    match match_type {
+        "Singles" => Some((2, Some(2))),
+        "Tag Team" => Some((4, Some(4))),
+        "Triple Threat" => Some((3, Some(3))),
+        "Fatal 4-Way" => Some((4, Some(4))),
+        "Battle Royal" => Some((3, None)),
+        "Ladder Match" | "Cage Match" | "Steel Cage" => Some((2, None)),
+        _ => None,
+    }
+}
+
+/// Returns the most competitors a match type allows
+/// 
+/// # Returns
+/// * `Some(max)` - The participant cap, e.g. 3 for a Triple Threat
+/// * `None` - If the type has no upper limit (such as a Battle Royal) or is not recognized
+pub fn max_participants_for_type(match_type: &str) -> Option<usize> {
+    match_participant_limits(match_type).and_then(|(_, max)| max)
+}
+
+/// Gets how many competitors a match has and how many its type allows
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// 
+/// # Returns
+/// * `Ok((usize, Option<usize>))` - Current competitors and the cap (None when unlimited)
+/// * `Err(DieselError)` - NotFound if the match does not exist, or a database error
+/// 
+/// # Note
+/// Managers, referees and other non-competing roles don't take up a spot
+pub fn internal_get_match_capacity(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+) -> Result<(usize, Option<usize>), DieselError> {
+    use crate::schema::{match_participants, matches};
+
+    let match_type = matches::table
+        .find(match_id)
+        .select(matches::match_type)
+        .first::<String>(conn)?;
+
+    let current = match_participants::table
+        .filter(match_participants::match_id.eq(match_id))
+        .filter(match_participants::role.eq(COMPETITOR_ROLE))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    Ok((current as usize, max_participants_for_type(&match_type)))
+}
+
+/// Creates a new match for a show
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_data` - MatchData struct containing all match details
+/// 
+/// # Returns
+/// * `Ok(Match)` - The newly created match
+/// * `Err(DieselError)` - Database error if creation fails
+/// 
+/// # Note
+/// Scheduled date should be in "YYYY-MM-DD" format
+pub fn internal_create_match(
+    conn: &mut SqliteConnection,
+    match_data: &MatchData,
+) -> Result<Match, DieselError> {
+    use crate::schema::matches;
+    use chrono::NaiveDate;
+    
+    // Parse the date string if provided
+    let scheduled_date = match_data.scheduled_date.as_ref()
+        .and_then(|date_str| NaiveDate::parse_from_str(date_str, "%Y-%m-%d").ok());
+    
+    let new_match = NewMatch {
+        show_id: match_data.show_id,
+        match_name: match_data.match_name.clone(),
+        match_type: match_data.match_type.clone(),
+        match_stipulation: match_data.match_stipulation.clone(),
+        scheduled_date,
+        match_order: match_data.match_order,
+        winner_id: None, // Will be set later when match is concluded
+        is_title_match: match_data.is_title_match,
+        title_id: match_data.title_id,
+    };
+    
+    diesel::insert_into(matches::table)
+        .values(&new_match)
+        .returning(Match::as_returning())
+        .get_result(conn)
+}
+
+/// Creates a match at a given position on a show's card
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_data` - MatchData struct containing all match details
+/// * `position` - Match order for the new match (1 opens the show)
+/// 
+/// # Returns
+/// * `Ok(Match)` - The newly created match
+/// * `Err(AppError::Validation)` - If the position is below 1
+/// * `Err(AppError::Database)` - Database error if creation fails
+/// 
+/// # Note
+/// Matches already at or after the position move down one place. The
+/// `match_order` in `match_data` is ignored in favour of `position`.
+pub fn internal_insert_match_at(
+    conn: &mut SqliteConnection,
+    match_data: &MatchData,
+    position: i32,
+) -> Result<Match, AppError> {
+    use crate::schema::matches;
+
+    if position < 1 {
+        return Err(AppError::Validation(format!(
+            "position: {} must be at least 1",
+            position
+        )));
+    }
+
+    conn.transaction::<Match, AppError, _>(|conn| {
+        diesel::update(
+            matches::table
+                .filter(matches::show_id.eq(match_data.show_id))
+                .filter(matches::match_order.ge(position)),
+        )
+        .set(matches::match_order.eq(matches::match_order + 1))
+        .execute(conn)?;
+
+        let placed = MatchData {
+            match_order: Some(position),
+            ..match_data.clone()
+        };
+        Ok(internal_create_match(conn, &placed)?)
+    })
+}
+
+/// Gets all matches for a specific show
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(Vec<Match>)` - Vector of matches ordered by match order
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_matches_for_show(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<Vec<Match>, DieselError> {
+    use crate::schema::matches;
+    
+    matches::table
+        .filter(matches::show_id.eq(show_id))
+        .order(matches::match_order.asc())
+        .then_order_by(matches::id.asc())
+        .load::<Match>(conn)
+}
+
+/// Gets the title matches on a show's card with the name of the title at stake
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(Vec<(Match, String)>)` - Title matches with their title name, in card order
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Title matches whose title no longer exists are left out
+pub fn internal_get_title_matches_for_show(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<Vec<(Match, String)>, DieselError> {
+    use crate::schema::{matches, titles};
+
+    matches::table
+        .inner_join(titles::table.on(matches::title_id.eq(titles::id.nullable())))
+        .filter(matches::show_id.eq(show_id))
+        .filter(matches::is_title_match.eq(true))
+        .order(matches::match_order.asc())
+        .then_order_by(matches::id.asc())
+        .select((Match::as_select(), titles::name))
+        .load::<(Match, String)>(conn)
+}
+
+/// Counts the matches booked on a show
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(i64)` - Number of matches on the card (0 for an empty card)
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_count_matches_for_show(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<i64, DieselError> {
+    use crate::schema::matches;
+
+    matches::table
+        .filter(matches::show_id.eq(show_id))
+        .count()
+        .get_result::<i64>(conn)
+}
+
+/// Gets the match order for the next match added to a show's card
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(i32)` - One past the highest match order on the card, or 1 for an empty card
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Gaps in the existing order are not filled, so the new match always goes last
+pub fn internal_next_match_order(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<i32, DieselError> {
+    use crate::schema::matches;
+    use diesel::dsl::max;
+
+    let highest = matches::table
+        .filter(matches::show_id.eq(show_id))
+        .select(max(matches::match_order))
+        .first::<Option<i32>>(conn)?;
+
+    Ok(highest.map_or(1, |order| order + 1))
+}
+
+/// Books a rematch of an existing match
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `source_match_id` - ID of the match to rematch
+/// * `show_id` - ID of the show to book the rematch on
+/// * `scheduled_date` - Optional date for the rematch
+/// 
+/// # Returns
+/// * `Ok(Match)` - The newly created match, placed last on the show's card
+/// * `Err(AppError::NotFound)` - If the source match or the show does not exist
+/// * `Err(AppError::Database)` - Database error if creation fails
+/// 
+/// # Note
+/// Copies the name, type, stipulation and participants (with teams, entrance
+/// orders and roles). The rematch has no winner and is not a title match;
+/// use `set_match_title` to put a title on the line again.
+pub fn internal_create_rematch(
+    conn: &mut SqliteConnection,
+    source_match_id: i32,
+    show_id: i32,
+    scheduled_date: Option<chrono::NaiveDate>,
+) -> Result<Match, AppError> {
+    use crate::schema::{match_participants, matches, shows};
+
+    conn.transaction::<Match, AppError, _>(|conn| {
+        let source = matches::table
+            .find(source_match_id)
+            .first::<Match>(conn)
+            .optional()?
+            .ok_or_else(|| AppError::NotFound(format!("Match {} not found", source_match_id)))?;
+
+        shows::table
+            .find(show_id)
+            .select(shows::id)
+            .first::<i32>(conn)
+            .optional()?
+            .ok_or_else(|| AppError::NotFound(format!("Show {} not found", show_id)))?;
+
+        let match_order = internal_next_match_order(conn, show_id)?;
+        let rematch = diesel::insert_into(matches::table)
+            .values(&NewMatch {
+                show_id,
+                match_name: source.match_name,
+                match_type: source.match_type,
+                match_stipulation: source.match_stipulation,
+                scheduled_date,
+                match_order: Some(match_order),
+                winner_id: None,
+                is_title_match: false,
+                title_id: None,
+            })
+            .returning(Match::as_returning())
+            .get_result(conn)?;
+
+        let participants = match_participants::table
+            .filter(match_participants::match_id.eq(source_match_id))
+            .order(match_participants::id.asc())
+            .load::<MatchParticipant>(conn)?;
+
+        for participant in participants {
+            diesel::insert_into(match_participants::table)
+                .values((
+                    match_participants::match_id.eq(rematch.id),
+                    match_participants::wrestler_id.eq(participant.wrestler_id),
+                    match_participants::team_number.eq(participant.team_number),
+                    match_participants::entrance_order.eq(participant.entrance_order),
+                    match_participants::role.eq(participant.role),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(rematch)
+    })
+}
+
+/// Gets a single match by ID
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match to retrieve
+/// 
+/// # Returns
+/// * `Ok(Some(Match))` - The match if found
+/// * `Ok(None)` - If no match with the given ID exists
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_match_by_id(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+) -> Result<Option<Match>, DieselError> {
+    use crate::schema::matches;
+
+    matches::table
+        .filter(matches::id.eq(match_id))
+        .first::<Match>(conn)
+        .optional()
+}
+
+/// Gets the wrestler recorded as a match's winner
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// 
+/// # Returns
+/// * `Ok(Some(Wrestler))` - The winning wrestler
+/// * `Ok(None)` - If the match has no result yet (or does not exist)
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_match_winner(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+) -> Result<Option<Wrestler>, DieselError> {
+    use crate::schema::{matches, wrestlers};
+
+    matches::table
+        .inner_join(wrestlers::table.on(matches::winner_id.eq(wrestlers::id.nullable())))
+        .filter(matches::id.eq(match_id))
+        .select(Wrestler::as_select())
+        .first::<Wrestler>(conn)
+        .optional()
+}
+
+/// Lists every match type used by a booked match
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - Distinct match types in alphabetical order
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_distinct_match_types(conn: &mut SqliteConnection) -> Result<Vec<String>, DieselError> {
+    use crate::schema::matches;
+
+    matches::table
+        .select(matches::match_type)
+        .distinct()
+        .order(matches::match_type.asc())
+        .load::<String>(conn)
+}
+
+/// Gets matches that do not have a recorded result yet
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - Optional show to limit the results to (None for all shows)
+/// 
+/// # Returns
+/// * `Ok(Vec<Match>)` - Matches without a winner, ordered by scheduled date then match order
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_unfinished_matches(
+    conn: &mut SqliteConnection,
+    show_id: Option<i32>,
+) -> Result<Vec<Match>, DieselError> {
+    use crate::schema::matches;
+
+    let mut query = matches::table
+        .filter(matches::winner_id.is_null())
+        .into_boxed();
+
+    if let Some(show_id) = show_id {
+        query = query.filter(matches::show_id.eq(show_id));
+    }
+
+    query
+        .order(matches::scheduled_date.asc())
+        .then_order_by(matches::match_order.asc())
+        .then_order_by(matches::id.asc())
+        .load::<Match>(conn)
+}
+
+/// Gets shows that have matches scheduled on or after a date
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `from` - First date that counts as upcoming
+/// 
+/// # Returns
+/// * `Ok(Vec<(Show, i64)>)` - Each show with its number of upcoming matches, ordered by show name
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Matches without a scheduled date are not counted, so shows with only past
+/// or unscheduled matches are left out
+pub fn internal_get_shows_with_upcoming_matches(
+    conn: &mut SqliteConnection,
+    from: chrono::NaiveDate,
+) -> Result<Vec<(Show, i64)>, DieselError> {
+    use crate::schema::{matches, shows};
+    use diesel::dsl::count_star;
+
+    shows::table
+        .inner_join(matches::table)
+        .filter(matches::scheduled_date.ge(from))
+        .group_by(shows::id)
+        .select((Show::as_select(), count_star()))
+        .order(shows::name.asc())
+        .load::<(Show, i64)>(conn)
+}
+
+/// Gets the most recent match a wrestler took part in
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+/// 
+/// # Returns
+/// * `Ok(Some((Match, Show)))` - The latest match by scheduled date, then ID, with its show
+/// * `Ok(None)` - If the wrestler has never been booked
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Any participant role counts, so interfering also counts as being seen in action
+pub fn internal_get_last_match_for_wrestler(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+) -> Result<Option<(Match, Show)>, DieselError> {
+    use crate::schema::{match_participants, matches, shows};
+
+    matches::table
+        .inner_join(match_participants::table.on(match_participants::match_id.eq(matches::id)))
+        .inner_join(shows::table.on(matches::show_id.eq(shows::id)))
+        .filter(match_participants::wrestler_id.eq(wrestler_id))
+        .order(matches::scheduled_date.desc())
+        .then_order_by(matches::id.desc())
+        .select((Match::as_select(), Show::as_select()))
+        .first::<(Match, Show)>(conn)
+        .optional()
+}
+
+/// Gets the most recently scheduled matches across all shows
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `limit` - Maximum number of matches to return
+/// 
+/// # Returns
+/// * `Ok(Vec<RecentMatch>)` - Matches with show and winner names, newest scheduled date first
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Unscheduled matches are listed after every dated match
+pub fn internal_get_recent_matches(
+    conn: &mut SqliteConnection,
+    limit: i64,
+) -> Result<Vec<RecentMatch>, DieselError> {
+    use crate::schema::{matches, shows, wrestlers};
+
+    let rows = matches::table
+        .inner_join(shows::table.on(matches::show_id.eq(shows::id)))
+        .left_join(wrestlers::table.on(matches::winner_id.eq(wrestlers::id.nullable())))
+        .order(matches::scheduled_date.is_null().asc())
+        .then_order_by(matches::scheduled_date.desc())
+        .then_order_by(matches::id.desc())
+        .select((Match::as_select(), shows::name, wrestlers::name.nullable()))
+        .limit(limit)
+        .load::<(Match, String, Option<String>)>(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(booked, show_name, winner_name)| RecentMatch {
+            match_id: booked.id,
+            show_id: booked.show_id,
+            show_name,
+            match_name: booked.match_name,
+            match_type: booked.match_type,
+            scheduled_date: booked.scheduled_date,
+            winner_name,
+            is_main_event: booked.is_main_event,
+        })
+        .collect())
+}
+
+/// Adds a wrestler as a participant in a match
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// * `wrestler_id` - ID of the wrestler to add
+/// * `team_number` - Optional team number for tag matches
+/// * `entrance_order` - Optional entrance order
+/// 
+/// # Returns
+/// * `Ok(MatchParticipant)` - The newly created match participant
+/// * `Err(AppError::Validation)` - If the match already has as many competitors as its type allows
+/// * `Err(AppError::NotFound)` - If the match does not exist
+/// * `Err(AppError::Database)` - Database error if addition fails
+/// 
+/// # Note
+/// The wrestler joins as a competitor; use `internal_add_participant_with_role`
+/// for managers, referees and interference
+pub fn internal_add_wrestler_to_match(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+    wrestler_id: i32,
+    team_number: Option<i32>,
+    entrance_order: Option<i32>,
+) -> Result<MatchParticipant, AppError> {
+    internal_add_participant_with_role(conn, match_id, wrestler_id, team_number, entrance_order, COMPETITOR_ROLE)
+}
+
+/// Adds a wrestler to a match in a given role
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// * `wrestler_id` - ID of the wrestler to add
+/// * `team_number` - Optional team number for tag matches
+/// * `entrance_order` - Optional entrance order
+/// * `role` - Role in the match ("Competitor", "Interference", "Manager" or "Referee")
+/// 
+/// # Returns
+/// * `Ok(MatchParticipant)` - The newly created match participant
+/// * `Err(AppError::Validation)` - If the role is not recognized, or a competitor is added to a full match
+/// * `Err(AppError::NotFound)` - If the match does not exist
+/// * `Err(AppError::Database)` - Database error if addition fails
+/// 
+/// # Note
+/// Only competitors count towards the match type's participant limit
+pub fn internal_add_participant_with_role(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+    wrestler_id: i32,
+    team_number: Option<i32>,
+    entrance_order: Option<i32>,
+    role: &str,
+) -> Result<MatchParticipant, AppError> {
+    use crate::schema::match_participants;
+
+    if !PARTICIPANT_ROLES.contains(&role) {
+        return Err(AppError::Validation(format!(
+            "role: '{}' is not one of {}",
+            role,
+            PARTICIPANT_ROLES.join(", ")
+        )));
+    }
+
+    let (current, max) = internal_get_match_capacity(conn, match_id)?;
+    if role == COMPETITOR_ROLE {
+        if let Some(max) = max.filter(|max| current >= *max) {
+            return Err(AppError::Validation(format!(
+                "match_id: Match {} is full ({} of {} participants)",
+                match_id, current, max
+            )));
+        }
+    }
+
+    let new_participant = NewMatchParticipant {
+        match_id,
+        wrestler_id,
+        team_number,
+        entrance_order,
+        role: role.to_string(),
+    };
+    
+    diesel::insert_into(match_participants::table)
+        .values(&new_participant)
+        .returning(MatchParticipant::as_returning())
+        .get_result(conn)
+        .map_err(AppError::from)
+}
+
+/// Gets all participants for a specific match with wrestler details
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// 
+/// # Returns
+/// * `Ok(Vec<(MatchParticipant, Wrestler)>)` - Vector of participants with wrestler data
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_match_participants(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+) -> Result<Vec<(MatchParticipant, Wrestler)>, DieselError> {
+    use crate::schema::{match_participants, wrestlers};
+    
+    match_participants::table
+        .inner_join(wrestlers::table.on(match_participants::wrestler_id.eq(wrestlers::id)))
+        .filter(match_participants::match_id.eq(match_id))
+        .order(match_participants::entrance_order.asc())
+        .then_order_by(match_participants::id.asc())
+        .select((MatchParticipant::as_select(), Wrestler::as_select()))
+        .load::<(MatchParticipant, Wrestler)>(conn)
+}
+
+/// Gets a match's participants grouped by their role
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// 
+/// # Returns
+/// * `Ok(Vec<(String, Vec<Wrestler>)>)` - Each role present with its wrestlers in entrance order
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Roles follow the order of `PARTICIPANT_ROLES`, so competitors come first.
+/// Roles without anyone in them are left out.
+pub fn internal_get_match_roles(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+) -> Result<Vec<(String, Vec<Wrestler>)>, DieselError> {
+    let mut groups: Vec<(String, Vec<Wrestler>)> = Vec::new();
+
+    // Participants already come in entrance order
+    for (participant, wrestler) in internal_get_match_participants(conn, match_id)? {
+        match groups.iter_mut().find(|(role, _)| *role == participant.role) {
+            Some((_, wrestlers)) => wrestlers.push(wrestler),
+            None => groups.push((participant.role, vec![wrestler])),
+        }
+    }
+    groups.sort_by_key(|(role, _)| {
+        PARTICIPANT_ROLES
+            .iter()
+            .position(|known| known == role)
+            .unwrap_or(PARTICIPANT_ROLES.len())
+    });
+
+    Ok(groups)
+}
+
+/// Gets a compact summary of every match on a show's card
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(Vec<MatchSummary>)` - One summary per match in card order
+/// * `Err(DieselError)` - Database error if query fails
+/// 
+/// # Note
+/// Participant names are joined in entrance order; title names are only filled in for title matches
+pub fn internal_get_show_card_summary(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<Vec<MatchSummary>, DieselError> {
+    use crate::schema::titles;
+
+    let show_matches = internal_get_matches_for_show(conn, show_id)?;
+    let mut summaries = Vec::with_capacity(show_matches.len());
+
+    for show_match in show_matches {
+        let title_name = match (show_match.is_title_match, show_match.title_id) {
+            (true, Some(title_id)) => titles::table
+                .filter(titles::id.eq(title_id))
+                .select(titles::name)
+                .first::<String>(conn)
+                .optional()?,
+            _ => None,
+        };
+
+        let participants = internal_get_match_participants(conn, show_match.id)?
+            .into_iter()
+            .map(|(_, wrestler)| wrestler.name)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        summaries.push(MatchSummary {
+            match_id: show_match.id,
+            match_name: show_match.match_name,
+            match_type: show_match.match_type,
+            match_stipulation: show_match.match_stipulation,
+            match_order: show_match.match_order,
+            is_title_match: show_match.is_title_match,
+            title_name,
+            participants,
+            notes: show_match.notes,
+            is_main_event: show_match.is_main_event,
+        });
+    }
+
+    Ok(summaries)
+}
+
+/// Share of a winner suggestion's strength score that comes from the overall rating
+const SUGGESTION_RATING_WEIGHT: f64 = 0.6;
+/// Overall rating assumed for competitors without any power ratings
+const SUGGESTION_DEFAULT_RATING: f64 = 5.0;
+/// Win rate assumed for competitors without any recorded matches
+const SUGGESTION_DEFAULT_WIN_RATE: f64 = 0.5;
+
+/// Suggests the likely winner of a match from ratings and records
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// * `seed` - Optional seed so ties between equally favored competitors resolve the same way every time
+/// 
+/// # Returns
+/// * `Ok(WinnerSuggestion)` - The favored competitor and their estimated chance of winning
+/// * `Err(AppError::Validation)` - If the match has no competitors
+/// * `Err(AppError::Database)` - Database error if query fails
+/// 
+/// # Note
+/// Each competitor's strength blends their overall rating (out of 10) with their
+/// win rate, and the chances are their share of the combined strength. This is
+/// purely advisory and never changes the match.
+pub fn internal_suggest_match_winner(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+    seed: Option<u64>,
+) -> Result<WinnerSuggestion, AppError> {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let strengths: Vec<(i32, f64)> = internal_get_match_participants(conn, match_id)?
+        .into_iter()
+        .filter(|(participant, _)| participant.role == COMPETITOR_ROLE)
+        .map(|(_, wrestler)| {
+            let rating = wrestler.overall_rating().unwrap_or(SUGGESTION_DEFAULT_RATING) / 10.0;
+            let win_rate = wrestler.win_rate().unwrap_or(SUGGESTION_DEFAULT_WIN_RATE);
+            let strength = SUGGESTION_RATING_WEIGHT * rating + (1.0 - SUGGESTION_RATING_WEIGHT) * win_rate;
+            (wrestler.id, strength)
+        })
+        .collect();
+
+    if strengths.is_empty() {
+        return Err(AppError::Validation(format!(
+            "Match {} has no competitors to choose from",
+            match_id
+        )));
+    }
+
+    let total: f64 = strengths.iter().map(|(_, strength)| strength).sum();
+    let best = strengths
+        .iter()
+        .map(|(_, strength)| *strength)
+        .fold(f64::MIN, f64::max);
+    let favored: Vec<i32> = strengths
+        .iter()
+        .filter(|(_, strength)| (best - strength).abs() < f64::EPSILON)
+        .map(|(wrestler_id, _)| *wrestler_id)
+        .collect();
+
+    let pick = match (favored.len(), seed) {
+        (1, _) => 0,
+        (tied, Some(seed)) => StdRng::seed_from_u64(seed).gen_range(0..tied),
+        (tied, None) => rand::thread_rng().gen_range(0..tied),
+    };
+
+    let confidence = if total > 0.0 {
+        best / total * 100.0
+    } else {
+        100.0 / strengths.len() as f64
+    };
+
+    Ok(WinnerSuggestion {
+        wrestler_id: favored[pick],
+        confidence,
+    })
+}
+
+/// Adds (or with a negative delta, removes) a match result from wrestler records
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// * `winner_id` - ID of the winning wrestler
+/// * `delta` - 1 to record the result, -1 to reverse it
+/// 
+/// # Note
+/// The winner and any competitor on the winner's team get a win, every other
+/// competitor gets a loss. Non-competitor roles (interference, managers,
+/// referees) are left untouched.
+fn apply_match_result(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+    winner_id: i32,
+    delta: i32,
+) -> Result<(), DieselError> {
+    use crate::schema::{match_participants, wrestlers};
+
+    let competitors = match_participants::table
+        .filter(match_participants::match_id.eq(match_id))
+        .filter(match_participants::role.eq(COMPETITOR_ROLE))
+        .select((match_participants::wrestler_id, match_participants::team_number))
+        .load::<(i32, Option<i32>)>(conn)?;
+
+    let winning_team = competitors
+        .iter()
+        .find(|(wrestler_id, _)| *wrestler_id == winner_id)
+        .and_then(|(_, team_number)| *team_number);
+
+    diesel::update(wrestlers::table.find(winner_id))
+        .set(wrestlers::wins.eq(wrestlers::wins + delta))
+        .execute(conn)?;
+
+    for (wrestler_id, team_number) in competitors {
+        if wrestler_id == winner_id {
+            continue;
+        }
+
+        if winning_team.is_some() && team_number == winning_team {
+            diesel::update(wrestlers::table.find(wrestler_id))
+                .set(wrestlers::wins.eq(wrestlers::wins + delta))
+                .execute(conn)?;
+        } else {
+            diesel::update(wrestlers::table.find(wrestler_id))
+                .set(wrestlers::losses.eq(wrestlers::losses + delta))
+                .execute(conn)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that a wrestler competes in a match before they can be its winner
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// * `winner_id` - ID of the proposed winner
+/// 
+/// # Returns
+/// * `Ok(())` - If the wrestler is a competitor in the match
+/// * `Err(AppError::Validation)` - If the wrestler is not in the match or only
+///   appears as a referee, manager or interference
+/// * `Err(AppError::Database)` - Database error if query fails
+fn ensure_winner_is_participant(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+    winner_id: i32,
+) -> Result<(), AppError> {
+    use crate::schema::match_participants;
+
+    let booked = match_participants::table
+        .filter(match_participants::match_id.eq(match_id))
+        .filter(match_participants::wrestler_id.eq(winner_id))
+        .filter(match_participants::role.eq(COMPETITOR_ROLE))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    if booked == 0 {
+        return Err(AppError::Validation(format!(
+            "winner_id: Wrestler {} is not a competitor in match {}",
+            winner_id, match_id
+        )));
+    }
+
+    Ok(())
+}
+
+/// Updates the winner of a match and records the result on wrestler records
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// * `winner_id` - ID of the winning wrestler
+/// 
+/// # Returns
+/// * `Ok(Match)` - The updated match with winner set
+/// * `Err(AppError::Validation)` - If the winner is not a competitor in the match
+/// * `Err(AppError::Database)` - Database error if update fails
+/// 
+/// # Note
+/// Changing an existing winner reverses the previous result first, so
+/// records are never counted twice. Only competitors are tallied.
+pub fn internal_set_match_winner(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+    winner_id: i32,
+) -> Result<Match, AppError> {
+    use crate::schema::matches;
+
+    ensure_winner_is_participant(conn, match_id, winner_id)?;
+
+    conn.transaction::<Match, AppError, _>(|conn| {
+        let previous_winner = matches::table
+            .find(match_id)
+            .select(matches::winner_id)
+            .first::<Option<i32>>(conn)?;
+
+        if let Some(previous_winner) = previous_winner {
+            apply_match_result(conn, match_id, previous_winner, -1)?;
+        }
+
+        let updated = diesel::update(matches::table)
+            .filter(matches::id.eq(match_id))
+            .set((
+                matches::winner_id.eq(winner_id),
+                matches::result_type.eq(WIN_RESULT),
+            ))
+            .returning(Match::as_returning())
+            .get_result(conn)?;
+
+        apply_match_result(conn, match_id, winner_id, 1)?;
+
+        Ok(updated)
+    })
+}
+
+/// Records how a match concluded
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// * `result_type` - "Win", "NoContest" or "Draw"
+/// * `winner_id` - ID of the winning wrestler (required for a win, not allowed otherwise)
+/// 
+/// # Returns
+/// * `Ok(Match)` - The updated match
+/// * `Err(AppError::Validation)` - If the result type is unknown or the winner doesn't fit it
+/// * `Err(AppError::NotFound)` - If the match does not exist
+/// * `Err(AppError::Database)` - Database error if update fails
+/// 
+/// # Note
+/// A win is recorded through `internal_set_match_winner`. A no-contest or draw
+/// leaves every record untouched; if the match previously had a winner, that
+/// result is reversed.
+pub fn internal_set_match_result(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+    result_type: &str,
+    winner_id: Option<i32>,
+) -> Result<Match, AppError> {
+    use crate::schema::matches;
+
+    if !MATCH_RESULT_TYPES.contains(&result_type) {
+        return Err(AppError::Validation(format!(
+            "result_type: '{}' is not one of {}",
+            result_type,
+            MATCH_RESULT_TYPES.join(", ")
+        )));
+    }
+
+    match (result_type == WIN_RESULT, winner_id) {
+        (true, Some(winner_id)) => return internal_set_match_winner(conn, match_id, winner_id),
+        (true, None) => {
+            return Err(AppError::Validation("winner_id: A win needs a winner".to_string()))
+        }
+        (false, Some(_)) => {
+            return Err(AppError::Validation(format!(
+                "winner_id: A {} result has no winner",
+                result_type
+            )))
+        }
+        (false, None) => {}
+    }
+
+    conn.transaction::<Match, AppError, _>(|conn| {
+        let previous_winner = matches::table
+            .find(match_id)
+            .select(matches::winner_id)
+            .first::<Option<i32>>(conn)
+            .optional()?
+            .ok_or_else(|| AppError::NotFound(format!("Match {} not found", match_id)))?;
+
+        if let Some(previous_winner) = previous_winner {
+            apply_match_result(conn, match_id, previous_winner, -1)?;
+        }
+
+        let updated = diesel::update(matches::table.find(match_id))
+            .set((
+                matches::winner_id.eq(None::<i32>),
+                matches::result_type.eq(result_type),
+            ))
+            .returning(Match::as_returning())
+            .get_result(conn)?;
+
+        Ok(updated)
+    })
+}
+
+/// Looks up the team number of a match's winner
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// * `winner_id` - ID of the winning wrestler
+/// 
+/// # Returns
+/// * `Ok(Option<i32>)` - The winner's team, None for singles matches or a winner without a team
+/// * `Err(DieselError)` - Database error if query fails
+fn winning_team_number(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+    winner_id: Option<i32>,
+) -> Result<Option<i32>, DieselError> {
+    use crate::schema::match_participants;
+
+    Ok(match_participants::table
+        .filter(match_participants::match_id.eq(match_id))
+        .filter(match_participants::wrestler_id.nullable().eq(winner_id))
+        .filter(match_participants::role.eq(COMPETITOR_ROLE))
+        .select(match_participants::team_number)
+        .first::<Option<i32>>(conn)
+        .optional()?
+        .flatten())
+}
+
+/// Derives a wrestler's (wins, losses) from recorded match results without saving them
+/// 
+/// Uses the same rules as `set_match_winner`: the winner and their teammates
+/// get a win, every other competitor gets a loss. Matches without a winner
+/// are ignored.
+fn derived_record(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+) -> Result<(i64, i64), DieselError> {
+    use crate::schema::{match_participants, matches};
+
+    let wins_as_winner = matches::table
+        .filter(matches::winner_id.eq(wrestler_id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    let decided_entries = match_participants::table
+        .inner_join(matches::table)
+        .filter(match_participants::wrestler_id.eq(wrestler_id))
+        .filter(match_participants::role.eq(COMPETITOR_ROLE))
+        .filter(matches::winner_id.is_not_null())
+        .filter(matches::winner_id.ne(wrestler_id))
+        .select((matches::id, match_participants::team_number, matches::winner_id))
+        .load::<(i32, Option<i32>, Option<i32>)>(conn)?;
+
+    let mut wins = wins_as_winner;
+    let mut losses = 0i64;
+
+    for (match_id, team_number, winner_id) in decided_entries {
+        let winning_team = winning_team_number(conn, match_id, winner_id)?;
+
+        if winning_team.is_some() && team_number == winning_team {
+            wins += 1;
+        } else {
+            losses += 1;
+        }
+    }
+
+    Ok((wins, losses))
+}
+
+/// Recalculates a wrestler's wins and losses from recorded match results
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler to repair
+/// 
+/// # Returns
+/// * `Ok(Wrestler)` - The wrestler with the recomputed record
+/// * `Err(DieselError)` - Database error if the wrestler doesn't exist or a query fails
+/// 
+/// # Note
+/// Uses the same rules as `set_match_winner`: the winner and their teammates
+/// get a win, every other competitor gets a loss. Matches without a winner
+/// are ignored.
+pub fn internal_recompute_wrestler_record(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+) -> Result<Wrestler, DieselError> {
+    use crate::schema::wrestlers;
+
+    conn.transaction(|conn| {
+        let (wins, losses) = derived_record(conn, wrestler_id)?;
+
+        diesel::update(wrestlers::table.find(wrestler_id))
+            .set((
+                wrestlers::wins.eq(wins as i32),
+                wrestlers::losses.eq(losses as i32),
+            ))
+            .returning(Wrestler::as_returning())
+            .get_result(conn)
+    })
+}
+
+/// Recalculates the wins and losses of every wrestler from recorded match results
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(usize)` - Number of wrestlers whose record changed
+/// * `Err(DieselError)` - Database error if any recompute fails
+pub fn internal_recompute_all_records(conn: &mut SqliteConnection) -> Result<usize, DieselError> {
+    use crate::schema::wrestlers;
+
+    conn.transaction(|conn| {
+        let records = wrestlers::table
+            .select((wrestlers::id, wrestlers::wins, wrestlers::losses))
+            .load::<(i32, i32, i32)>(conn)?;
+
+        let mut changed = 0;
+        for (wrestler_id, wins, losses) in records {
+            let updated = internal_recompute_wrestler_record(conn, wrestler_id)?;
+            if updated.wins != wins || updated.losses != losses {
+                changed += 1;
+            }
+        }
+
+        Ok(changed)
+    })
+}
+
+/// Zeroes every wrestler's wins and losses for a fresh season
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(usize)` - Number of wrestlers reset
+/// * `Err(DieselError)` - Database error if update fails
+/// 
+/// # Note
+/// Match history is left untouched, so `recompute_all_records` restores the
+/// old records if the reset was a mistake
+pub fn internal_reset_all_records(conn: &mut SqliteConnection) -> Result<usize, DieselError> {
+    use crate::schema::wrestlers;
+
+    diesel::update(wrestlers::table)
+        .set((wrestlers::wins.eq(0), wrestlers::losses.eq(0)))
+        .execute(conn)
+}
+
+/// Computes a wrestler's rolling win rate over their decided matches
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `wrestler_id` - ID of the wrestler
+/// * `window` - Number of most recent decided matches each point covers
+/// 
+/// # Returns
+/// * `Ok(Vec<(NaiveDate, f64)>)` - One point per decided match in chronological order,
+///   with the share of wins (0.0-1.0) over the window ending at that match
+/// * `Err(AppError::Validation)` - If the window is zero
+/// * `Err(AppError::Database)` - Database error if query fails
+/// 
+/// # Note
+/// Only matches with a recorded winner where the wrestler competed are counted,
+/// using the same win rules as `recompute_wrestler_record`. A match's date is
+/// its scheduled date, falling back to when it was created. Early points cover
+/// fewer matches until the window fills up.
+pub fn internal_get_win_rate_trend(
+    conn: &mut SqliteConnection,
+    wrestler_id: i32,
+    window: usize,
+) -> Result<Vec<(chrono::NaiveDate, f64)>, AppError> {
+    use crate::schema::{match_participants, matches};
+
+    if window == 0 {
+        return Err(AppError::Validation("window: Window must be at least 1".to_string()));
+    }
+
+    let decided_entries = match_participants::table
+        .inner_join(matches::table)
+        .filter(match_participants::wrestler_id.eq(wrestler_id))
+        .filter(match_participants::role.eq(COMPETITOR_ROLE))
+        .filter(matches::winner_id.is_not_null())
+        .select((
+            matches::id,
+            match_participants::team_number,
+            matches::winner_id,
+            matches::scheduled_date,
+            matches::created_at,
+        ))
+        .load::<(i32, Option<i32>, Option<i32>, Option<chrono::NaiveDate>, Option<chrono::NaiveDateTime>)>(conn)?;
+
+    let mut results = Vec::with_capacity(decided_entries.len());
+    for (match_id, team_number, winner_id, scheduled_date, created_at) in decided_entries {
+        let Some(match_date) = scheduled_date.or(created_at.map(|created| created.date())) else {
+            continue;
+        };
+
+        let won = if winner_id == Some(wrestler_id) {
+            true
+        } else {
+            let winning_team = winning_team_number(conn, match_id, winner_id)?;
+            winning_team.is_some() && team_number == winning_team
+        };
+        results.push((match_date, match_id, won));
+    }
+    results.sort_by_key(|&(match_date, match_id, _)| (match_date, match_id));
+
+    Ok(results
+        .iter()
+        .enumerate()
+        .map(|(index, &(match_date, _, _))| {
+            let recent = &results[(index + 1).saturating_sub(window)..=index];
+            let wins = recent.iter().filter(|&&(_, _, won)| won).count();
+            (match_date, wins as f64 / recent.len() as f64)
+        })
+        .collect())
+}
+
+/// Changes a participant's role in a match
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `participant_id` - ID of the match participant record
+/// * `role` - New role ("Competitor", "Interference", "Manager" or "Referee")
+/// 
+/// # Returns
+/// * `Ok(MatchParticipant)` - The updated participant
+/// * `Err(AppError::Validation)` - If the role is not recognized, the recorded winner would stop being a competitor,
+///   or a new competitor would exceed the match type's limit
+/// * `Err(AppError::NotFound)` - If the participant does not exist
+/// * `Err(AppError::Database)` - Database error if update fails
+/// 
+/// # Note
+/// On a decided match the result is reversed before the role change and
+/// re-applied after it, so records always reflect the current competitors.
+pub fn internal_set_participant_role(
+    conn: &mut SqliteConnection,
+    participant_id: i32,
+    role: &str,
+) -> Result<MatchParticipant, AppError> {
+    use crate::schema::{match_participants, matches};
+
+    if !PARTICIPANT_ROLES.contains(&role) {
+        return Err(AppError::Validation(format!(
+            "role: '{}' is not one of {}",
+            role,
+            PARTICIPANT_ROLES.join(", ")
+        )));
+    }
+
+    conn.transaction::<MatchParticipant, AppError, _>(|conn| {
+        let participant = match_participants::table
+            .find(participant_id)
+            .first::<MatchParticipant>(conn)
+            .optional()?
+            .ok_or_else(|| AppError::NotFound(format!("Participant {} not found", participant_id)))?;
+
+        let winner_id = matches::table
+            .find(participant.match_id)
+            .select(matches::winner_id)
+            .first::<Option<i32>>(conn)?;
+
+        if winner_id == Some(participant.wrestler_id) && role != COMPETITOR_ROLE {
+            return Err(AppError::Validation(
+                "role: The recorded winner must stay a competitor".to_string(),
+            ));
+        }
+
+        if role == COMPETITOR_ROLE && participant.role != COMPETITOR_ROLE {
+            let (current, max) = internal_get_match_capacity(conn, participant.match_id)?;
+            if let Some(max) = max.filter(|max| current >= *max) {
+                return Err(AppError::Validation(format!(
+                    "role: Match {} is full ({} of {} participants)",
+                    participant.match_id, current, max
+                )));
+            }
+        }
+
+        if let Some(winner_id) = winner_id {
+            apply_match_result(conn, participant.match_id, winner_id, -1)?;
+        }
+
+        let updated = diesel::update(match_participants::table.find(participant_id))
+            .set(match_participants::role.eq(role))
+            .returning(MatchParticipant::as_returning())
+            .get_result(conn)?;
+
+        if let Some(winner_id) = winner_id {
+            apply_match_result(conn, participant.match_id, winner_id, 1)?;
+        }
+
+        Ok(updated)
+    })
+}
+
+/// Replaces one wrestler in a match with another
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// * `out_wrestler_id` - Wrestler being taken out of the match
+/// * `in_wrestler_id` - Wrestler taking their place
+/// 
+/// # Returns
+/// * `Ok(MatchParticipant)` - The participant record now pointing at the incoming wrestler
+/// * `Err(AppError::NotFound)` - If the outgoing wrestler isn't in the match or the incoming wrestler doesn't exist
+/// * `Err(AppError::Validation)` - If the incoming wrestler is already in the match or is injured
+/// * `Err(AppError::Database)` - Database error if the update fails
+/// 
+/// # Note
+/// The team number, entrance order and role carry over to the incoming wrestler.
+/// On a decided match the result is reversed from everyone's record before the
+/// swap. If the outgoing wrestler was the recorded winner the winner is cleared;
+/// otherwise the result is re-applied, so the incoming wrestler takes over the
+/// outgoing wrestler's win or loss.
+pub fn internal_substitute_participant(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+    out_wrestler_id: i32,
+    in_wrestler_id: i32,
+) -> Result<MatchParticipant, AppError> {
+    use crate::schema::{match_participants, matches, wrestlers};
+
+    conn.transaction::<MatchParticipant, AppError, _>(|conn| {
+        let outgoing = match_participants::table
+            .filter(match_participants::match_id.eq(match_id))
+            .filter(match_participants::wrestler_id.eq(out_wrestler_id))
+            .first::<MatchParticipant>(conn)
+            .optional()?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "Wrestler {} is not in match {}",
+                    out_wrestler_id, match_id
+                ))
+            })?;
+
+        let incoming_status = wrestlers::table
+            .find(in_wrestler_id)
+            .select(wrestlers::status)
+            .first::<String>(conn)
+            .optional()?
+            .ok_or_else(|| AppError::NotFound(format!("Wrestler {} not found", in_wrestler_id)))?;
+        if incoming_status == INJURED_STATUS {
+            return Err(AppError::Validation(
+                "in_wrestler_id: Injured wrestlers cannot be substituted in".to_string(),
+            ));
+        }
+
+        let already_booked = match_participants::table
+            .filter(match_participants::match_id.eq(match_id))
+            .filter(match_participants::wrestler_id.eq(in_wrestler_id))
+            .count()
+            .get_result::<i64>(conn)?;
+        if already_booked > 0 {
+            return Err(AppError::Validation(
+                "in_wrestler_id: Wrestler is already in this match".to_string(),
+            ));
+        }
+
+        let winner_id = matches::table
+            .find(match_id)
+            .select(matches::winner_id)
+            .first::<Option<i32>>(conn)?;
+        if let Some(winner_id) = winner_id {
+            apply_match_result(conn, match_id, winner_id, -1)?;
+        }
+        if winner_id == Some(out_wrestler_id) {
+            diesel::update(matches::table.find(match_id))
+                .set((
+                    matches::winner_id.eq(None::<i32>),
+                    matches::result_type.eq(None::<String>),
+                ))
+                .execute(conn)?;
+        }
+
+        let substituted = diesel::update(match_participants::table.find(outgoing.id))
+            .set(match_participants::wrestler_id.eq(in_wrestler_id))
+            .returning(MatchParticipant::as_returning())
+            .get_result(conn)?;
+
+        if let Some(winner_id) = winner_id.filter(|&winner_id| winner_id != out_wrestler_id) {
+            apply_match_result(conn, match_id, winner_id, 1)?;
+        }
+
+        Ok(substituted)
+    })
+}
+
+/// Turns a match into a title match, or back into a non-title match
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// * `title_id` - Title to put on the line (None converts it to a non-title match)
+/// 
+/// # Returns
+/// * `Ok(Match)` - The updated match
+/// * `Err(AppError::NotFound)` - If the match or title does not exist
+/// * `Err(AppError::Validation)` - If a competitor is not gender-compatible with the title
+/// * `Err(AppError::Database)` - Database error if update fails
+pub fn internal_set_match_title(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+    title_id: Option<i32>,
+) -> Result<Match, AppError> {
+    use crate::schema::{matches, titles};
+
+    if let Some(title_id) = title_id {
+        let title = titles::table
+            .filter(titles::id.eq(title_id))
+            .first::<Title>(conn)
+            .optional()?
+            .ok_or_else(|| AppError::NotFound(format!("Title {} not found", title_id)))?;
+
+        // Only competitors contend for the title; officials and managers may be any gender
+        let participants = internal_get_match_participants(conn, match_id)?;
+        if let Some((_, wrestler)) = participants
+            .iter()
+            .filter(|(p, _)| p.role == COMPETITOR_ROLE)
+            .find(|(_, w)| !compatible_title_genders(&w.gender).contains(&title.gender.as_str()))
+        {
+            return Err(AppError::Validation(format!(
+                "{} ({}) cannot compete for the {} title '{}'",
+                wrestler.name, wrestler.gender, title.gender, title.name
+            )));
+        }
+    }
+
+    diesel::update(matches::table.filter(matches::id.eq(match_id)))
+        .set((
+            matches::is_title_match.eq(title_id.is_some()),
+            matches::title_id.eq(title_id),
+        ))
+        .returning(Match::as_returning())
+        .get_result(conn)
+        .map_err(AppError::from)
+}
+
+/// Maximum length of a match's storyline notes
+const MAX_MATCH_NOTES_LENGTH: usize = 2000;
+
+/// Sets or clears the storyline notes on a match
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// * `notes` - New notes (None or blank text clears them)
+/// 
+/// # Returns
+/// * `Ok(Match)` - The updated match
+/// * `Err(AppError::Validation)` - If the notes are too long
+/// * `Err(AppError::NotFound)` - If the match does not exist
+/// * `Err(AppError::Database)` - Database error if update fails
+pub fn internal_set_match_notes(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+    notes: Option<String>,
+) -> Result<Match, AppError> {
+    use crate::schema::matches;
+
+    let notes = notes
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty());
+
+    if notes.as_ref().is_some_and(|text| text.chars().count() > MAX_MATCH_NOTES_LENGTH) {
+        return Err(AppError::Validation(format!(
+            "notes: Match notes cannot exceed {} characters",
+            MAX_MATCH_NOTES_LENGTH
+        )));
+    }
+
+    diesel::update(matches::table.find(match_id))
+        .set(matches::notes.eq(notes))
+        .returning(Match::as_returning())
+        .get_result(conn)
+        .map_err(AppError::from)
+}
+
+/// Marks a match as the main event of its show
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_id` - ID of the match
+/// 
+/// # Returns
+/// * `Ok(Match)` - The updated match
+/// * `Err(AppError::NotFound)` - If the match does not exist
+/// * `Err(AppError::Database)` - Database error if update fails
+/// 
+/// # Note
+/// A card has a single main event, so the flag is cleared on the show's other matches
+pub fn internal_set_main_event(
+    conn: &mut SqliteConnection,
+    match_id: i32,
+) -> Result<Match, AppError> {
+    use crate::schema::matches;
+
+    conn.transaction::<Match, AppError, _>(|conn| {
+        let show_id = matches::table
+            .find(match_id)
+            .select(matches::show_id)
+            .first::<i32>(conn)
+            .optional()?
+            .ok_or_else(|| AppError::NotFound(format!("Match {} not found", match_id)))?;
+
+        diesel::update(matches::table)
+            .filter(matches::show_id.eq(show_id))
+            .filter(matches::id.ne(match_id))
+            .filter(matches::is_main_event.eq(true))
+            .set(matches::is_main_event.eq(false))
+            .execute(conn)?;
+
+        diesel::update(matches::table.find(match_id))
+            .set(matches::is_main_event.eq(true))
+            .returning(Match::as_returning())
+            .get_result(conn)
+            .map_err(AppError::from)
+    })
+}
+
+/// Checks a proposed match without creating it
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_data` - MatchData struct describing the proposed match
+/// * `participant_ids` - IDs of the wrestlers that would be booked
+/// 
+/// # Returns
+/// * `Ok(())` - If the match could be booked as proposed
+/// * `Err(Vec<String>)` - Every violation found, including database failures
+/// 
+/// # Note
+/// Checks show existence, stipulation, duplicate participants, participant count
+/// for the match type, active roster membership and title gender compatibility
+/// in a single pass. Duplicates are counted once towards the participant count.
+pub fn internal_validate_match(
+    conn: &mut SqliteConnection,
+    match_data: &MatchData,
+    participant_ids: &[i32],
+) -> Result<(), Vec<String>> {
+    use crate::schema::{show_rosters, titles, wrestlers};
+
+    let mut violations = Vec::new();
+
+    match internal_get_show_by_id(conn, match_data.show_id) {
+        Ok(Some(_)) => {}
+        Ok(None) => violations.push(format!("Show {} does not exist", match_data.show_id)),
+        Err(e) => violations.push(format!("Failed to load show: {}", e)),
+    }
+
+    if let Some(stipulation) = &match_data.match_stipulation {
+        if !MATCH_STIPULATIONS.contains(&stipulation.as_str()) {
+            violations.push(format!("Unknown stipulation '{}'", stipulation));
+        }
+    }
+
+    let mut unique_ids: Vec<i32> = Vec::with_capacity(participant_ids.len());
+    let mut repeated_ids: Vec<i32> = Vec::new();
+    for &wrestler_id in participant_ids {
+        if !unique_ids.contains(&wrestler_id) {
+            unique_ids.push(wrestler_id);
+        } else if !repeated_ids.contains(&wrestler_id) {
+            repeated_ids.push(wrestler_id);
+            violations.push(format!("Wrestler {} listed more than once", wrestler_id));
+        }
+    }
+    let participant_ids = unique_ids.as_slice();
+
+    match match_participant_limits(&match_data.match_type) {
+        Some((min, max)) => {
+            let count = participant_ids.len();
+            if count < min || max.is_some_and(|max| count > max) {
+                let expected = match max {
+                    Some(max) if max == min => format!("{}", min),
+                    Some(max) => format!("{}-{}", min, max),
+                    None => format!("at least {}", min),
+                };
+                violations.push(format!(
+                    "{} matches need {} participants, got {}",
+                    match_data.match_type, expected, count
+                ));
+            }
+        }
+        None => violations.push(format!("Unknown match type '{}'", match_data.match_type)),
+    }
+
+    let participants = match wrestlers::table
+        .filter(wrestlers::id.eq_any(participant_ids))
+        .select(Wrestler::as_select())
+        .load::<Wrestler>(conn)
+    {
+        Ok(participants) => participants,
+        Err(e) => {
+            violations.push(format!("Failed to load participants: {}", e));
+            Vec::new()
+        }
+    };
+
+    for wrestler_id in participant_ids {
+        if !participants.iter().any(|w| w.id == *wrestler_id) {
+            violations.push(format!("Wrestler {} does not exist", wrestler_id));
+        }
+    }
+
+    match show_rosters::table
+        .filter(show_rosters::show_id.eq(match_data.show_id))
+        .filter(show_rosters::is_active.eq(true))
+        .filter(show_rosters::wrestler_id.eq_any(participant_ids))
+        .select(show_rosters::wrestler_id)
+        .load::<i32>(conn)
+    {
+        Ok(rostered_ids) => {
+            for wrestler in participants.iter().filter(|w| !rostered_ids.contains(&w.id)) {
+                violations.push(format!("{} is not on this show's roster", wrestler.name));
+            }
+        }
+        Err(e) => violations.push(format!("Failed to load show roster: {}", e)),
+    }
+
+    if match_data.is_title_match {
+        match match_data.title_id {
+            None => violations.push("Title matches must specify a title".to_string()),
+            Some(title_id) => match titles::table
+                .filter(titles::id.eq(title_id))
+                .first::<Title>(conn)
+                .optional()
+            {
+                Ok(Some(title)) => {
+                    for wrestler in participants.iter().filter(|w| {
+                        !compatible_title_genders(&w.gender).contains(&title.gender.as_str())
+                    }) {
+                        violations.push(format!(
+                            "{} ({}) cannot compete for the {} title '{}'",
+                            wrestler.name, wrestler.gender, title.gender, title.name
+                        ));
+                    }
                 }
+                Ok(None) => violations.push(format!("Title {} does not exist", title_id)),
+                Err(e) => violations.push(format!("Failed to load title: {}", e)),
             },
-            _ => {}
         }
-        
-        match_count += 1;
     }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+/// Books a match with its participants and optional winner in one step
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `match_data` - MatchData struct containing all match details
+/// * `participants` - `(wrestler_id, team_number, entrance_order)` for each participant
+/// * `winner_id` - Optional ID of the winning wrestler
+/// 
+/// # Returns
+/// * `Ok(Match)` - The newly created match, with the winner set if one was given
+/// * `Err(AppError::Validation)` - If the match fails `validate_match` or the winner is not a participant
+/// * `Err(AppError::Database)` - Database error if creation fails
+/// 
+/// # Note
+/// Runs in a single transaction, so nothing is created when any step fails
+pub fn internal_book_complete_match(
+    conn: &mut SqliteConnection,
+    match_data: &MatchData,
+    participants: &[(i32, Option<i32>, Option<i32>)],
+    winner_id: Option<i32>,
+) -> Result<Match, AppError> {
+    conn.transaction::<Match, AppError, _>(|conn| {
+        let participant_ids: Vec<i32> = participants.iter().map(|(id, _, _)| *id).collect();
+        internal_validate_match(conn, match_data, &participant_ids)
+            .map_err(|violations| AppError::Validation(violations.join("; ")))?;
+
+        let booked = internal_create_match(conn, match_data)?;
+        for (wrestler_id, team_number, entrance_order) in participants {
+            internal_add_wrestler_to_match(conn, booked.id, *wrestler_id, *team_number, *entrance_order)?;
+        }
+
+        match winner_id {
+            Some(winner_id) => internal_set_match_winner(conn, booked.id, winner_id),
+            None => Ok(booked),
+        }
+    })
+}
+
+/// Finds wrestlers booked to compete in more than one match on a show
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(Vec<(Wrestler, Vec<i32>)>)` - Each double-booked wrestler with their match IDs
+/// * `Err(DieselError)` - Database error if query fails (NotFound for unknown shows)
+/// 
+/// # Note
+/// Only competitors count, so a wrestler may still interfere in other matches.
+/// Shows with `allow_multi_match` set never report conflicts.
+pub fn internal_get_booking_conflicts(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<Vec<(Wrestler, Vec<i32>)>, DieselError> {
+    use crate::schema::{match_participants, matches, shows, wrestlers};
+    use std::collections::BTreeMap;
+
+    let allow_multi_match = shows::table
+        .find(show_id)
+        .select(shows::allow_multi_match)
+        .first::<bool>(conn)?;
+
+    if allow_multi_match {
+        return Ok(Vec::new());
+    }
+
+    let bookings = match_participants::table
+        .inner_join(matches::table.on(match_participants::match_id.eq(matches::id)))
+        .filter(matches::show_id.eq(show_id))
+        .filter(match_participants::role.eq(COMPETITOR_ROLE))
+        .select((match_participants::wrestler_id, matches::id))
+        .order(matches::id.asc())
+        .load::<(i32, i32)>(conn)?;
+
+    let mut matches_by_wrestler: BTreeMap<i32, Vec<i32>> = BTreeMap::new();
+    for (wrestler_id, match_id) in bookings {
+        let match_ids = matches_by_wrestler.entry(wrestler_id).or_default();
+        if !match_ids.contains(&match_id) {
+            match_ids.push(match_id);
+        }
+    }
+    matches_by_wrestler.retain(|_, match_ids| match_ids.len() > 1);
+
+    let conflicted = wrestlers::table
+        .filter(wrestlers::id.eq_any(matches_by_wrestler.keys().copied().collect::<Vec<_>>()))
+        .order(wrestlers::name.asc())
+        .load::<Wrestler>(conn)?;
+
+    Ok(conflicted
+        .into_iter()
+        .map(|wrestler| {
+            let match_ids = matches_by_wrestler.remove(&wrestler.id).unwrap_or_default();
+            (wrestler, match_ids)
+        })
+        .collect())
+}
+
+/// Tauri command to create a new match for booking
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `match_data` - MatchData struct with match details
+/// 
+/// # Returns
+/// * `Ok(Match)` - The newly created match
+/// * `Err(String)` - Error message if creation fails
+#[tauri::command]
+pub fn create_match(
+    state: State<'_, DbState>,
+    match_data: MatchData,
+) -> Result<Match, String> {
+    validate_id(match_data.show_id, "show_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
     
-    info!("Test data created successfully");
-    Ok(format!("Test data created: 2 shows, 5 wrestlers, {} titles, show rosters assigned, 2 title holders, and {} matches with participants", title_count, match_count))
+    internal_create_match(&mut conn, &match_data)
+        .map_err(|e| {
+            error!("Error creating match: {}", e);
+            format!("Failed to create match: {}", e)
+        })
+}
+
+/// Tauri command to book a rematch of an existing match
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `source_match_id` - ID of the match to rematch
+/// * `show_id` - ID of the show to book the rematch on
+/// * `scheduled_date` - Optional date for the rematch
+/// 
+/// # Returns
+/// * `Ok(Match)` - The newly created match with the source's participants
+/// * `Err(AppError)` - Categorized error if the match or show is missing or creation fails
+#[tauri::command]
+pub fn create_rematch(
+    state: State<'_, DbState>,
+    source_match_id: i32,
+    show_id: i32,
+    scheduled_date: Option<chrono::NaiveDate>,
+) -> Result<Match, AppError> {
+    validate_id(source_match_id, "source_match_id")?;
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_create_rematch(&mut conn, source_match_id, show_id, scheduled_date)
+        .inspect(|rematch| {
+            info!("Rematch of match {} booked as match {}", source_match_id, rematch.id);
+        })
+        .map_err(|e| {
+            error!("Error booking rematch of match {}: {}", source_match_id, e);
+            e.with_context("Failed to book rematch")
+        })
+}
+
+/// Tauri command to create a match at a given position on a show's card
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `match_data` - MatchData struct with match details
+/// * `position` - Match order for the new match; later matches shift down
+/// 
+/// # Returns
+/// * `Ok(Match)` - The newly created match
+/// * `Err(AppError)` - Categorized error if the position is invalid or creation fails
+#[tauri::command]
+pub fn insert_match_at(
+    state: State<'_, DbState>,
+    match_data: MatchData,
+    position: i32,
+) -> Result<Match, AppError> {
+    validate_id(match_data.show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_insert_match_at(&mut conn, &match_data, position)
+        .inspect(|inserted| {
+            info!("Match {} inserted at position {} on show {}", inserted.id, position, match_data.show_id);
+        })
+        .map_err(|e| {
+            error!("Error inserting match at position {}: {}", position, e);
+            e.with_context("Failed to insert match")
+        })
 }
 
-// ===== Show Roster Operations =====
+/// Tauri command to get all matches for a show
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(Vec<Match>)` - Vector of matches for the show
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_matches_for_show(
+    state: State<'_, DbState>,
+    show_id: i32,
+) -> Result<Vec<Match>, String> {
+    validate_id(show_id, "show_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
+    
+    internal_get_matches_for_show(&mut conn, show_id)
+        .map_err(|e| {
+            error!("Error loading matches for show: {}", e);
+            format!("Failed to load matches for show: {}", e)
+        })
+}
+
+/// Tauri command to get the title matches on a show's card
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(Vec<(Match, String)>)` - Title matches with their title name
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_title_matches_for_show(
+    state: State<'_, DbState>,
+    show_id: i32,
+) -> Result<Vec<(Match, String)>, AppError> {
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_title_matches_for_show(&mut conn, show_id).map_err(|e| {
+        error!("Error loading title matches for show {}: {}", show_id, e);
+        AppError::from(e).with_context("Failed to load title matches")
+    })
+}
+
+/// Tauri command to count the matches booked on a show
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(i64)` - Number of matches on the card
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn count_matches_for_show(
+    state: State<'_, DbState>,
+    show_id: i32,
+) -> Result<i64, AppError> {
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_count_matches_for_show(&mut conn, show_id).map_err(|e| {
+        error!("Error counting matches for show {}: {}", show_id, e);
+        AppError::from(e).with_context("Failed to count matches")
+    })
+}
+
+/// Tauri command to get the match order for the next match on a show
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(i32)` - The next free match order (1 for an empty card)
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn next_match_order(
+    state: State<'_, DbState>,
+    show_id: i32,
+) -> Result<i32, AppError> {
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_next_match_order(&mut conn, show_id).map_err(|e| {
+        error!("Error computing next match order for show {}: {}", show_id, e);
+        AppError::from(e).with_context("Failed to compute next match order")
+    })
+}
+
+/// Tauri command to get a single match by ID
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `match_id` - ID of the match to retrieve
+/// 
+/// # Returns
+/// * `Ok(Option<Match>)` - The match if found, None otherwise
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_match_by_id(
+    state: State<'_, DbState>,
+    match_id: i32,
+) -> Result<Option<Match>, AppError> {
+    validate_id(match_id, "match_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_match_by_id(&mut conn, match_id).map_err(|e| {
+        error!("Error fetching match {}: {}", match_id, e);
+        AppError::from(e).with_context("Failed to fetch match")
+    })
+}
+
+/// Tauri command to get how full a match is
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `match_id` - ID of the match
+/// 
+/// # Returns
+/// * `Ok((usize, Option<usize>))` - Current competitors and the cap (None when unlimited)
+/// * `Err(AppError)` - Categorized error if the match is missing or the query fails
+#[tauri::command]
+pub fn get_match_capacity(
+    state: State<'_, DbState>,
+    match_id: i32,
+) -> Result<(usize, Option<usize>), AppError> {
+    validate_id(match_id, "match_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_match_capacity(&mut conn, match_id).map_err(|e| {
+        error!("Error fetching capacity of match {}: {}", match_id, e);
+        AppError::from(e).with_context("Failed to fetch match capacity")
+    })
+}
+
+/// Tauri command to get the wrestler who won a match
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `match_id` - ID of the match
+/// 
+/// # Returns
+/// * `Ok(Option<Wrestler>)` - The winner, None if the match has no result
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_match_winner(
+    state: State<'_, DbState>,
+    match_id: i32,
+) -> Result<Option<Wrestler>, AppError> {
+    validate_id(match_id, "match_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_match_winner(&mut conn, match_id).map_err(|e| {
+        error!("Error fetching winner of match {}: {}", match_id, e);
+        AppError::from(e).with_context("Failed to fetch match winner")
+    })
+}
+
+/// Tauri command to get matches still waiting on a result
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - Optional show to limit the results to
+/// 
+/// # Returns
+/// * `Ok(Vec<Match>)` - Matches without a winner
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_unfinished_matches(
+    state: State<'_, DbState>,
+    show_id: Option<i32>,
+) -> Result<Vec<Match>, AppError> {
+    if let Some(id) = show_id {
+        validate_id(id, "show_id")?;
+    }
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_unfinished_matches(&mut conn, show_id)
+        .map_err(|e| {
+            error!("Error loading unfinished matches: {}", e);
+            AppError::from(e).with_context("Failed to load unfinished matches")
+        })
+}
+
+/// Tauri command to list every match type used by a booked match
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - Distinct match types in alphabetical order
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_distinct_match_types(state: State<'_, DbState>) -> Result<Vec<String>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_distinct_match_types(&mut conn).map_err(|e| {
+        error!("Error loading match types: {}", e);
+        AppError::from(e).with_context("Failed to load match types")
+    })
+}
 
-/// Gets all wrestlers assigned to a specific show's roster
+/// Tauri command to get shows with matches scheduled on or after a date
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `show_id` - ID of the show
+/// * `state` - The Tauri state containing the database pool
+/// * `from` - First date that counts as upcoming
 /// 
 /// # Returns
-/// * `Ok(Vec<Wrestler>)` - Vector of wrestlers on the show's roster
-/// * `Err(DieselError)` - Database error if query fails
-/// 
-/// # Note
-/// Only returns active roster assignments
-pub fn internal_get_wrestlers_for_show(
-    conn: &mut SqliteConnection,
-    show_id: i32,
-) -> Result<Vec<Wrestler>, DieselError> {
-    use crate::schema::{wrestlers, show_rosters};
-    
-    wrestlers::table
-        .inner_join(show_rosters::table.on(wrestlers::id.eq(show_rosters::wrestler_id)))
-        .filter(show_rosters::show_id.eq(show_id))
-        .filter(show_rosters::is_active.eq(true))
-        .select(Wrestler::as_select())
-        .order(wrestlers::name.asc())
-        .load::<Wrestler>(conn)
+/// * `Ok(Vec<(Show, i64)>)` - Each show with its number of upcoming matches
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_shows_with_upcoming_matches(
+    state: State<'_, DbState>,
+    from: chrono::NaiveDate,
+) -> Result<Vec<(Show, i64)>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_shows_with_upcoming_matches(&mut conn, from).map_err(|e| {
+        error!("Error loading shows with upcoming matches: {}", e);
+        AppError::from(e).with_context("Failed to load shows with upcoming matches")
+    })
 }
 
-/// Gets all wrestlers not currently assigned to any show (internal function)
+/// Tauri command to get the most recent match a wrestler took part in
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
 /// 
 /// # Returns
-/// * `Ok(Vec<Wrestler>)` - Vector of unassigned wrestlers
-/// * `Err(DieselError)` - Database error if query fails
+/// * `Ok(Option<(Match, Show)>)` - The latest match with its show, None if never booked
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_last_match_for_wrestler(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+) -> Result<Option<(Match, Show)>, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_last_match_for_wrestler(&mut conn, wrestler_id).map_err(|e| {
+        error!("Error fetching last match for wrestler {}: {}", wrestler_id, e);
+        AppError::from(e).with_context("Failed to fetch last match")
+    })
+}
+
+/// Tauri command to get the most recently scheduled matches across all shows
 /// 
-/// # Note
-/// Uses LEFT JOIN to find wrestlers with no active show roster assignments
-pub fn internal_get_unassigned_wrestlers(
-    conn: &mut SqliteConnection,
-) -> Result<Vec<Wrestler>, DieselError> {
-    use crate::schema::{wrestlers, show_rosters};
-    
-    wrestlers::table
-        .left_join(
-            show_rosters::table.on(
-                wrestlers::id.eq(show_rosters::wrestler_id)
-                    .and(show_rosters::is_active.eq(true))
-            )
-        )
-        .filter(show_rosters::wrestler_id.is_null())
-        .select(Wrestler::as_select())
-        .order(wrestlers::name.asc())
-        .load::<Wrestler>(conn)
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `limit` - Maximum number of matches to return
+/// 
+/// # Returns
+/// * `Ok(Vec<RecentMatch>)` - Matches with show and winner names, newest first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_recent_matches(
+    state: State<'_, DbState>,
+    limit: i64,
+) -> Result<Vec<RecentMatch>, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_recent_matches(&mut conn, limit).map_err(|e| {
+        error!("Error loading recent matches: {}", e);
+        AppError::from(e).with_context("Failed to load recent matches")
+    })
 }
 
-/// Gets the current active show assignment for a wrestler
+/// Tauri command to add a wrestler to a match
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `wrestler_id` - ID of the wrestler to check
+/// * `state` - The Tauri state containing the database pool
+/// * `match_id` - ID of the match
+/// * `wrestler_id` - ID of the wrestler to add
+/// * `team_number` - Optional team assignment for tag matches
+/// * `entrance_order` - Optional entrance order
+/// * `role` - Optional role in the match (defaults to "Competitor")
 /// 
 /// # Returns
-/// * `Ok(Option<ShowRoster>)` - The active assignment if found, None otherwise
-/// * `Err(DieselError)` - Database error if query fails
-pub fn internal_get_current_show_for_wrestler(
-    conn: &mut SqliteConnection,
+/// * `Ok(MatchParticipant)` - The created participant record
+/// * `Err(String)` - Error message if addition fails
+#[tauri::command]
+pub fn add_wrestler_to_match(
+    state: State<'_, DbState>,
+    match_id: i32,
     wrestler_id: i32,
-) -> Result<Option<ShowRoster>, DieselError> {
-    use crate::schema::show_rosters;
+    team_number: Option<i32>,
+    entrance_order: Option<i32>,
+    role: Option<String>,
+) -> Result<MatchParticipant, String> {
+    validate_id(match_id, "match_id").map_err(|e| e.to_string())?;
+    validate_id(wrestler_id, "wrestler_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
     
-    show_rosters::table
-        .filter(show_rosters::wrestler_id.eq(wrestler_id))
-        .filter(show_rosters::is_active.eq(true))
-        .first::<ShowRoster>(conn)
-        .optional()
+    let role = role.as_deref().unwrap_or(COMPETITOR_ROLE);
+    internal_add_participant_with_role(&mut conn, match_id, wrestler_id, team_number, entrance_order, role)
+        .map_err(|e| {
+            error!("Error adding wrestler to match: {}", e);
+            format!("Failed to add wrestler to match: {}", e)
+        })
 }
 
-/// Assigns a wrestler to a show's roster with exclusive assignment logic
+/// Tauri command to get all participants in a match
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `show_id` - ID of the show
-/// * `wrestler_id` - ID of the wrestler to assign
+/// * `state` - The Tauri state containing the database pool
+/// * `match_id` - ID of the match
 /// 
 /// # Returns
-/// * `Ok(())` - If assignment was successful or already exists
-/// * `Err(DieselError)` - Database error if assignment fails
-/// 
-/// # Note
-/// Implements exclusive assignment logic - wrestler can only be on one show at a time
-/// Uses database transaction for atomicity
-pub fn internal_assign_wrestler_to_show(
-    conn: &mut SqliteConnection,
-    show_id: i32,
-    wrestler_id: i32,
-) -> Result<(), DieselError> {
-    use crate::schema::show_rosters;
-    use chrono::Utc;
+/// * `Ok(Vec<(MatchParticipant, Wrestler)>)` - Participants with wrestler details
+/// * `Err(String)` - Error message if query fails
+#[tauri::command]
+pub fn get_match_participants(
+    state: State<'_, DbState>,
+    match_id: i32,
+) -> Result<Vec<(MatchParticipant, Wrestler)>, String> {
+    validate_id(match_id, "match_id").map_err(|e| e.to_string())?;
+
+    let mut conn = get_connection(&state)?;
     
-    // Use transaction for atomicity
-    conn.transaction::<(), DieselError, _>(|conn| {
-        // Check if wrestler is already assigned to the target show
-        let existing_assignment = show_rosters::table
-            .filter(show_rosters::show_id.eq(show_id))
-            .filter(show_rosters::wrestler_id.eq(wrestler_id))
-            .first::<ShowRoster>(conn)
-            .optional()?;
-        
-        if let Some(assignment) = existing_assignment {
-            if assignment.is_active {
-                // Wrestler already assigned to this show and active - nothing to do
-                info!("Wrestler {} already assigned to show {} (active)", wrestler_id, show_id);
-                return Ok(());
-            } else {
-                // Reactivate the existing inactive assignment to this show
-                info!("Reactivating existing assignment for wrestler {} to show {}", wrestler_id, show_id);
-                diesel::update(show_rosters::table)
-                    .filter(show_rosters::id.eq(assignment.id))
-                    .set((
-                        show_rosters::is_active.eq(true),
-                        show_rosters::assigned_at.eq(Some(Utc::now().naive_utc())),
-                    ))
-                    .execute(conn)?;
-                return Ok(());
-            }
-        }
-        
-        // Check if wrestler is currently assigned to ANY other show
-        let current_assignment = internal_get_current_show_for_wrestler(conn, wrestler_id)?;
-        
-        if let Some(current) = current_assignment {
-            if current.show_id == show_id {
-                // Already assigned to target show (shouldn't happen due to check above, but defensive)
-                info!("Wrestler {} already assigned to show {} (active)", wrestler_id, show_id);
-                return Ok(());
-            }
-            
-            // Deactivate current assignment (transfer)
-            info!("Transferring wrestler {} from show {} to show {}", wrestler_id, current.show_id, show_id);
-            diesel::update(show_rosters::table)
-                .filter(show_rosters::id.eq(current.id))
-                .set(show_rosters::is_active.eq(false))
-                .execute(conn)?;
-        } else {
-            info!("Assigning wrestler {} to show {} (new assignment)", wrestler_id, show_id);
-        }
-        
-        // Create new assignment to target show
-        let new_assignment = NewShowRoster {
-            show_id,
-            wrestler_id,
-            assigned_at: Some(Utc::now().naive_utc()),
-            is_active: true,
-        };
-        
-        diesel::insert_into(show_rosters::table)
-            .values(&new_assignment)
-            .execute(conn)?;
-        
-        Ok(())
-    })
+    internal_get_match_participants(&mut conn, match_id)
+        .map_err(|e| {
+            error!("Error loading match participants: {}", e);
+            format!("Failed to load match participants: {}", e)
+        })
 }
 
-/// Removes a wrestler from a show's roster
+/// Tauri command to get a match's participants grouped by role
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `show_id` - ID of the show
-/// * `wrestler_id` - ID of the wrestler to remove
+/// * `state` - The Tauri state containing the database pool
+/// * `match_id` - ID of the match
 /// 
 /// # Returns
-/// * `Ok(())` - If removal was successful
-/// * `Err(DieselError)` - Database error if removal fails
-/// 
-/// # Note
-/// Sets is_active to false rather than deleting the record
-pub fn internal_remove_wrestler_from_show(
-    conn: &mut SqliteConnection,
-    show_id: i32,
-    wrestler_id: i32,
-) -> Result<(), DieselError> {
-    use crate::schema::show_rosters;
-    
-    diesel::update(show_rosters::table)
-        .filter(show_rosters::show_id.eq(show_id))
-        .filter(show_rosters::wrestler_id.eq(wrestler_id))
-        .filter(show_rosters::is_active.eq(true))
-        .set(show_rosters::is_active.eq(false))
-        .execute(conn)?;
-    
-    Ok(())
+/// * `Ok(Vec<(String, Vec<Wrestler>)>)` - Each role present with its wrestlers, competitors first
+/// * `Err(AppError)` - Categorized error if query fails
+#[tauri::command]
+pub fn get_match_roles(
+    state: State<'_, DbState>,
+    match_id: i32,
+) -> Result<Vec<(String, Vec<Wrestler>)>, AppError> {
+    validate_id(match_id, "match_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_match_roles(&mut conn, match_id).map_err(|e| {
+        error!("Error loading roles for match {}: {}", match_id, e);
+        AppError::from(e).with_context("Failed to load match roles")
+    })
 }
 
-/// Tauri command to get all wrestlers on a show's roster
+/// Tauri command to get a printable summary of a show's card
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
 /// * `show_id` - ID of the show
 /// 
 /// # Returns
-/// * `Ok(Vec<Wrestler>)` - Vector of wrestlers on the roster
-/// * `Err(String)` - Error message if query fails
+/// * `Ok(Vec<MatchSummary>)` - One summary per match in card order
+/// * `Err(AppError)` - Categorized error if query fails
 #[tauri::command]
-pub fn get_wrestlers_for_show(
+pub fn get_show_card_summary(
     state: State<'_, DbState>,
     show_id: i32,
-) -> Result<Vec<Wrestler>, String> {
-    let mut conn = get_connection(&state)?;
-    
-    internal_get_wrestlers_for_show(&mut conn, show_id)
+) -> Result<Vec<MatchSummary>, AppError> {
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_show_card_summary(&mut conn, show_id)
         .map_err(|e| {
-            error!("Error loading wrestlers for show: {}", e);
-            format!("Failed to load wrestlers for show: {}", e)
+            error!("Error loading show card summary: {}", e);
+            AppError::from(e).with_context("Failed to load show card summary")
         })
 }
 
-/// Tauri command to assign a wrestler to a show's roster
+/// Tauri command to suggest the likely winner of a match
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `match_id` - ID of the match
+/// * `seed` - Optional seed for deterministic tie-breaking
+/// 
+/// # Returns
+/// * `Ok(WinnerSuggestion)` - The favored competitor and their estimated chance of winning
+/// * `Err(AppError)` - Categorized error if the match has no competitors or the query fails
+#[tauri::command]
+pub fn suggest_match_winner(
+    state: State<'_, DbState>,
+    match_id: i32,
+    seed: Option<u64>,
+) -> Result<WinnerSuggestion, AppError> {
+    validate_id(match_id, "match_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_suggest_match_winner(&mut conn, match_id, seed).map_err(|e| {
+        error!("Error suggesting winner for match {}: {}", match_id, e);
+        e.with_context("Failed to suggest match winner")
+    })
+}
+
+/// Tauri command to set the winner of a match
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `show_id` - ID of the show
-/// * `wrestler_id` - ID of the wrestler to assign
+/// * `match_id` - ID of the match
+/// * `winner_id` - ID of the winning wrestler
 /// 
 /// # Returns
-/// * `Ok(String)` - Success message
-/// * `Err(String)` - Error message if assignment fails
+/// * `Ok(Match)` - The updated match
+/// * `Err(String)` - Error message if the winner is not in the match or the update fails
+/// 
+/// # Note
+/// Besides storing the winner, this updates wins and losses: the winner and
+/// their teammates gain a win and every other competitor a loss. Changing the
+/// winner reverses the previous result first.
 #[tauri::command]
-pub fn assign_wrestler_to_show(
+pub fn set_match_winner(
     state: State<'_, DbState>,
-    show_id: i32,
-    wrestler_id: i32,
-) -> Result<String, String> {
+    match_id: i32,
+    winner_id: i32,
+) -> Result<Match, String> {
+    validate_id(match_id, "match_id").map_err(|e| e.to_string())?;
+    validate_id(winner_id, "winner_id").map_err(|e| e.to_string())?;
+
     let mut conn = get_connection(&state)?;
     
-    internal_assign_wrestler_to_show(&mut conn, show_id, wrestler_id)
+    internal_set_match_winner(&mut conn, match_id, winner_id)
         .map_err(|e| {
-            error!("Error assigning wrestler to show: {}", e);
-            format!("Failed to assign wrestler to show: {}", e)
+            error!("Error setting match winner: {}", e);
+            format!("Failed to set match winner: {}", e)
         })
-        .map(|_| "Wrestler assigned to show successfully".to_string())
 }
 
-/// Tauri command to remove a wrestler from a show's roster
+/// Tauri command to record how a match concluded
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `show_id` - ID of the show
-/// * `wrestler_id` - ID of the wrestler to remove
+/// * `match_id` - ID of the match
+/// * `result_type` - "Win", "NoContest" or "Draw"
+/// * `winner_id` - ID of the winning wrestler, only for a win
 /// 
 /// # Returns
-/// * `Ok(String)` - Success message
-/// * `Err(String)` - Error message if removal fails
+/// * `Ok(Match)` - The updated match
+/// * `Err(AppError)` - Categorized error if the result is invalid or the update fails
+/// 
+/// # Note
+/// A win updates wins and losses like `set_match_winner`; a no-contest or draw
+/// changes no records and reverses any previously recorded winner.
 #[tauri::command]
-pub fn remove_wrestler_from_show(
+pub fn set_match_result(
     state: State<'_, DbState>,
-    show_id: i32,
-    wrestler_id: i32,
-) -> Result<String, String> {
-    let mut conn = get_connection(&state)?;
-    
-    internal_remove_wrestler_from_show(&mut conn, show_id, wrestler_id)
+    match_id: i32,
+    result_type: String,
+    winner_id: Option<i32>,
+) -> Result<Match, AppError> {
+    validate_id(match_id, "match_id")?;
+    if let Some(winner_id) = winner_id {
+        validate_id(winner_id, "winner_id")?;
+    }
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_set_match_result(&mut conn, match_id, &result_type, winner_id)
+        .inspect(|_| info!("Match {} recorded as {}", match_id, result_type))
         .map_err(|e| {
-            error!("Error removing wrestler from show: {}", e);
-            format!("Failed to remove wrestler from show: {}", e)
+            error!("Error setting match result: {}", e);
+            e.with_context("Failed to set match result")
         })
-        .map(|_| "Wrestler removed from show successfully".to_string())
 }
 
-/// Internal function to get shows that a wrestler is currently assigned to
+/// Tauri command to recalculate a wrestler's record from match results
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `wrestler_id` - ID of the wrestler
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler to repair
 /// 
 /// # Returns
-/// * `Ok(Vec<Show>)` - Vector of shows the wrestler is assigned to
-/// * `Err(DieselError)` - Database error if query fails
-/// 
-/// # Note
-/// Only returns shows with active roster assignments.
-/// Result set is typically small (1-5 shows per wrestler in most cases).
-/// Returns empty vector if wrestler has no active assignments.
-/// 
-/// # Examples
-/// ```rust,no_run
-/// # use diesel::prelude::*;
-/// # use wwe_universe_manager_lib::db::internal_get_shows_for_wrestler;
-/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
-/// # let mut conn = todo!();
-/// # let wrestler_id = 1;
-/// let shows = internal_get_shows_for_wrestler(&mut conn, wrestler_id)?;
-/// for show in shows {
-///     println!("Wrestler is assigned to: {}", show.name);
-/// }
-/// # Ok(())
-/// # }
-/// ```
-pub fn internal_get_shows_for_wrestler(
-    conn: &mut SqliteConnection,
+/// * `Ok(Wrestler)` - The wrestler with the recomputed record
+/// * `Err(AppError)` - Categorized error if the wrestler doesn't exist or the update fails
+#[tauri::command]
+pub fn recompute_wrestler_record(
+    state: State<'_, DbState>,
     wrestler_id: i32,
-) -> Result<Vec<Show>, DieselError> {
-    use crate::schema::{shows, show_rosters};
-    
-    shows::table
-        .inner_join(show_rosters::table.on(shows::id.eq(show_rosters::show_id)))
-        .filter(show_rosters::wrestler_id.eq(wrestler_id))
-        .filter(show_rosters::is_active.eq(true))
-        .select(Show::as_select())
-        .order(shows::name.asc())
-        .load::<Show>(conn)
+) -> Result<Wrestler, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_recompute_wrestler_record(&mut conn, wrestler_id)
+        .inspect(|w| info!("Recomputed record for '{}': {}-{}", w.name, w.wins, w.losses))
+        .map_err(|e| {
+            error!("Error recomputing record for wrestler {}: {}", wrestler_id, e);
+            AppError::from(e).with_context("Failed to recompute wrestler record")
+        })
 }
 
-/// Tauri command to get shows that a wrestler is currently assigned to
+/// Tauri command to recalculate every wrestler's record from match results
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `wrestler_id` - ID of the wrestler
 /// 
 /// # Returns
-/// * `Ok(Vec<Show>)` - Vector of shows the wrestler is assigned to
-/// * `Err(String)` - Error message if query fails
+/// * `Ok(usize)` - Number of wrestlers whose record changed
+/// * `Err(AppError)` - Categorized error if the update fails
 #[tauri::command]
-pub fn get_shows_for_wrestler(
-    state: State<'_, DbState>,
-    wrestler_id: i32,
-) -> Result<Vec<Show>, String> {
-    // Input validation
-    if wrestler_id <= 0 {
-        error!("Invalid wrestler ID provided: {}", wrestler_id);
-        return Err(format!("Invalid wrestler ID: {}", wrestler_id));
-    }
-    
-    let mut conn = get_connection(&state)?;
-    
-    internal_get_shows_for_wrestler(&mut conn, wrestler_id)
+pub fn recompute_all_records(state: State<'_, DbState>) -> Result<usize, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_recompute_all_records(&mut conn)
+        .inspect(|changed| info!("Recomputed all records, {} changed", changed))
         .map_err(|e| {
-            error!("Error loading shows for wrestler {}: {}", wrestler_id, e);
-            format!("Failed to load shows for wrestler {}: {}", wrestler_id, e)
+            error!("Error recomputing all records: {}", e);
+            AppError::from(e).with_context("Failed to recompute records")
         })
 }
 
-// ===== Match Booking Operations =====
-
-/// Creates a new match for a show
+/// Tauri command to zero every wrestler's wins and losses
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `match_data` - MatchData struct containing all match details
+/// * `state` - The Tauri state containing the database pool
+/// * `confirm` - Must be true, guards against accidental resets
 /// 
 /// # Returns
-/// * `Ok(Match)` - The newly created match
-/// * `Err(DieselError)` - Database error if creation fails
-/// 
-/// # Note
-/// Scheduled date should be in "YYYY-MM-DD" format
-pub fn internal_create_match(
-    conn: &mut SqliteConnection,
-    match_data: &MatchData,
-) -> Result<Match, DieselError> {
-    use crate::schema::matches;
-    use chrono::NaiveDate;
-    
-    // Parse the date string if provided
-    let scheduled_date = match_data.scheduled_date.as_ref()
-        .and_then(|date_str| NaiveDate::parse_from_str(date_str, "%Y-%m-%d").ok());
-    
-    let new_match = NewMatch {
-        show_id: match_data.show_id,
-        match_name: match_data.match_name.clone(),
-        match_type: match_data.match_type.clone(),
-        match_stipulation: match_data.match_stipulation.clone(),
-        scheduled_date,
-        match_order: match_data.match_order,
-        winner_id: None, // Will be set later when match is concluded
-        is_title_match: match_data.is_title_match,
-        title_id: match_data.title_id,
-    };
-    
-    diesel::insert_into(matches::table)
-        .values(&new_match)
-        .returning(Match::as_returning())
-        .get_result(conn)
+/// * `Ok(usize)` - Number of wrestlers reset
+/// * `Err(AppError)` - Categorized error if not confirmed or the update fails
+#[tauri::command]
+pub fn reset_all_records(state: State<'_, DbState>, confirm: bool) -> Result<usize, AppError> {
+    if !confirm {
+        return Err(AppError::Validation(
+            "confirm: Resetting all records must be confirmed".to_string(),
+        ));
+    }
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_reset_all_records(&mut conn)
+        .inspect(|reset| info!("Reset records for {} wrestlers", reset))
+        .map_err(|e| {
+            error!("Error resetting all records: {}", e);
+            AppError::from(e).with_context("Failed to reset records")
+        })
 }
 
-/// Gets all matches for a specific show
+/// Tauri command to get a wrestler's rolling win rate for a trend chart
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `show_id` - ID of the show
+/// * `state` - The Tauri state containing the database pool
+/// * `wrestler_id` - ID of the wrestler
+/// * `window` - Number of most recent decided matches each point covers
 /// 
 /// # Returns
-/// * `Ok(Vec<Match>)` - Vector of matches ordered by match order
-/// * `Err(DieselError)` - Database error if query fails
-pub fn internal_get_matches_for_show(
-    conn: &mut SqliteConnection,
-    show_id: i32,
-) -> Result<Vec<Match>, DieselError> {
-    use crate::schema::matches;
-    
-    matches::table
-        .filter(matches::show_id.eq(show_id))
-        .order(matches::match_order.asc())
-        .then_order_by(matches::id.asc())
-        .load::<Match>(conn)
+/// * `Ok(Vec<(NaiveDate, f64)>)` - Dated win rates in chronological order
+/// * `Err(AppError)` - Categorized error if the window is invalid or the query fails
+#[tauri::command]
+pub fn get_win_rate_trend(
+    state: State<'_, DbState>,
+    wrestler_id: i32,
+    window: usize,
+) -> Result<Vec<(chrono::NaiveDate, f64)>, AppError> {
+    validate_id(wrestler_id, "wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_win_rate_trend(&mut conn, wrestler_id, window).map_err(|e| {
+        error!("Error computing win rate trend for wrestler {}: {}", wrestler_id, e);
+        e.with_context("Failed to compute win rate trend")
+    })
 }
 
-/// Adds a wrestler as a participant in a match
+/// Tauri command to change a participant's role in a match
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
-/// * `match_id` - ID of the match
-/// * `wrestler_id` - ID of the wrestler to add
-/// * `team_number` - Optional team number for tag matches
-/// * `entrance_order` - Optional entrance order
+/// * `state` - The Tauri state containing the database pool
+/// * `participant_id` - ID of the match participant record
+/// * `role` - New role ("Competitor", "Interference", "Manager" or "Referee")
 /// 
 /// # Returns
-/// * `Ok(MatchParticipant)` - The newly created match participant
-/// * `Err(DieselError)` - Database error if addition fails
-pub fn internal_add_wrestler_to_match(
-    conn: &mut SqliteConnection,
-    match_id: i32,
-    wrestler_id: i32,
-    team_number: Option<i32>,
-    entrance_order: Option<i32>,
-) -> Result<MatchParticipant, DieselError> {
-    use crate::schema::match_participants;
-    
-    let new_participant = NewMatchParticipant {
-        match_id,
-        wrestler_id,
-        team_number,
-        entrance_order,
-    };
-    
-    diesel::insert_into(match_participants::table)
-        .values(&new_participant)
-        .returning(MatchParticipant::as_returning())
-        .get_result(conn)
+/// * `Ok(MatchParticipant)` - The updated participant
+/// * `Err(AppError)` - Categorized error if validation or the update fails
+#[tauri::command]
+pub fn set_participant_role(
+    state: State<'_, DbState>,
+    participant_id: i32,
+    role: String,
+) -> Result<MatchParticipant, AppError> {
+    validate_id(participant_id, "participant_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_set_participant_role(&mut conn, participant_id, &role)
+        .map_err(|e| {
+            error!("Error setting participant role: {}", e);
+            e.with_context("Failed to set participant role")
+        })
 }
 
-/// Gets all participants for a specific match with wrestler details
+/// Tauri command to swap one wrestler in a match for another
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
+/// * `state` - The Tauri state containing the database pool
 /// * `match_id` - ID of the match
+/// * `out_wrestler_id` - Wrestler being taken out of the match
+/// * `in_wrestler_id` - Wrestler taking their place
 /// 
 /// # Returns
-/// * `Ok(Vec<(MatchParticipant, Wrestler)>)` - Vector of participants with wrestler data
-/// * `Err(DieselError)` - Database error if query fails
-pub fn internal_get_match_participants(
-    conn: &mut SqliteConnection,
+/// * `Ok(MatchParticipant)` - The participant record now pointing at the incoming wrestler
+/// * `Err(AppError)` - Categorized error if validation or the update fails
+#[tauri::command]
+pub fn substitute_participant(
+    state: State<'_, DbState>,
     match_id: i32,
-) -> Result<Vec<(MatchParticipant, Wrestler)>, DieselError> {
-    use crate::schema::{match_participants, wrestlers};
-    
-    match_participants::table
-        .inner_join(wrestlers::table.on(match_participants::wrestler_id.eq(wrestlers::id)))
-        .filter(match_participants::match_id.eq(match_id))
-        .order(match_participants::entrance_order.asc())
-        .then_order_by(match_participants::id.asc())
-        .select((MatchParticipant::as_select(), Wrestler::as_select()))
-        .load::<(MatchParticipant, Wrestler)>(conn)
+    out_wrestler_id: i32,
+    in_wrestler_id: i32,
+) -> Result<MatchParticipant, AppError> {
+    validate_id(match_id, "match_id")?;
+    validate_id(out_wrestler_id, "out_wrestler_id")?;
+    validate_id(in_wrestler_id, "in_wrestler_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_substitute_participant(&mut conn, match_id, out_wrestler_id, in_wrestler_id)
+        .inspect(|_| {
+            info!(
+                "Substituted wrestler {} for {} in match {}",
+                in_wrestler_id, out_wrestler_id, match_id
+            );
+        })
+        .map_err(|e| {
+            error!("Error substituting match participant: {}", e);
+            e.with_context("Failed to substitute participant")
+        })
 }
 
-/// Updates the winner of a match
+/// Tauri command to set or clear the title at stake in a match
 /// 
 /// # Arguments
-/// * `conn` - Mutable reference to the database connection
+/// * `state` - The Tauri state containing the database pool
 /// * `match_id` - ID of the match
-/// * `winner_id` - ID of the winning wrestler
+/// * `title_id` - Title to put on the line (None for a non-title match)
 /// 
 /// # Returns
-/// * `Ok(Match)` - The updated match with winner set
-/// * `Err(DieselError)` - Database error if update fails
-pub fn internal_set_match_winner(
-    conn: &mut SqliteConnection,
+/// * `Ok(Match)` - The updated match
+/// * `Err(AppError)` - Categorized error if validation or the update fails
+#[tauri::command]
+pub fn set_match_title(
+    state: State<'_, DbState>,
     match_id: i32,
-    winner_id: i32,
-) -> Result<Match, DieselError> {
-    use crate::schema::matches;
-    
-    diesel::update(matches::table)
-        .filter(matches::id.eq(match_id))
-        .set(matches::winner_id.eq(winner_id))
-        .returning(Match::as_returning())
-        .get_result(conn)
+    title_id: Option<i32>,
+) -> Result<Match, AppError> {
+    validate_id(match_id, "match_id")?;
+    if let Some(id) = title_id {
+        validate_id(id, "title_id")?;
+    }
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_set_match_title(&mut conn, match_id, title_id)
+        .map_err(|e| {
+            error!("Error setting match title: {}", e);
+            e.with_context("Failed to set match title")
+        })
 }
 
-/// Tauri command to create a new match for booking
+/// Tauri command to set or clear the storyline notes on a match
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `match_data` - MatchData struct with match details
+/// * `match_id` - ID of the match
+/// * `notes` - New notes (None or blank text clears them)
 /// 
 /// # Returns
-/// * `Ok(Match)` - The newly created match
-/// * `Err(String)` - Error message if creation fails
+/// * `Ok(Match)` - The updated match
+/// * `Err(AppError)` - Categorized error if validation or the update fails
 #[tauri::command]
-pub fn create_match(
+pub fn set_match_notes(
     state: State<'_, DbState>,
-    match_data: MatchData,
-) -> Result<Match, String> {
-    let mut conn = get_connection(&state)?;
-    
-    internal_create_match(&mut conn, &match_data)
+    match_id: i32,
+    notes: Option<String>,
+) -> Result<Match, AppError> {
+    validate_id(match_id, "match_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_set_match_notes(&mut conn, match_id, notes)
         .map_err(|e| {
-            error!("Error creating match: {}", e);
-            format!("Failed to create match: {}", e)
+            error!("Error setting match notes: {}", e);
+            e.with_context("Failed to set match notes")
         })
 }
 
-/// Tauri command to get all matches for a show
+/// Tauri command to mark a match as the main event of its show
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `show_id` - ID of the show
+/// * `match_id` - ID of the match
 /// 
 /// # Returns
-/// * `Ok(Vec<Match>)` - Vector of matches for the show
-/// * `Err(String)` - Error message if query fails
+/// * `Ok(Match)` - The updated match
+/// * `Err(AppError)` - Categorized error if the match is missing or the update fails
 #[tauri::command]
-pub fn get_matches_for_show(
-    state: State<'_, DbState>,
-    show_id: i32,
-) -> Result<Vec<Match>, String> {
-    let mut conn = get_connection(&state)?;
-    
-    internal_get_matches_for_show(&mut conn, show_id)
+pub fn set_main_event(state: State<'_, DbState>, match_id: i32) -> Result<Match, AppError> {
+    validate_id(match_id, "match_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_set_main_event(&mut conn, match_id)
+        .inspect(|main_event| {
+            info!("Match {} set as main event of show {}", main_event.id, main_event.show_id);
+        })
         .map_err(|e| {
-            error!("Error loading matches for show: {}", e);
-            format!("Failed to load matches for show: {}", e)
+            error!("Error setting main event: {}", e);
+            e.with_context("Failed to set main event")
         })
 }
 
-/// Tauri command to add a wrestler to a match
+/// Tauri command to check a proposed match before booking it
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `match_id` - ID of the match
-/// * `wrestler_id` - ID of the wrestler to add
-/// * `team_number` - Optional team assignment for tag matches
-/// * `entrance_order` - Optional entrance order
+/// * `match_data` - MatchData struct describing the proposed match
+/// * `participant_ids` - IDs of the wrestlers that would be booked
 /// 
 /// # Returns
-/// * `Ok(MatchParticipant)` - The created participant record
-/// * `Err(String)` - Error message if addition fails
+/// * `Ok(())` - If the match could be booked as proposed
+/// * `Err(Vec<String>)` - Every problem with the proposed match
 #[tauri::command]
-pub fn add_wrestler_to_match(
+pub fn validate_match(
     state: State<'_, DbState>,
-    match_id: i32,
-    wrestler_id: i32,
-    team_number: Option<i32>,
-    entrance_order: Option<i32>,
-) -> Result<MatchParticipant, String> {
-    let mut conn = get_connection(&state)?;
-    
-    internal_add_wrestler_to_match(&mut conn, match_id, wrestler_id, team_number, entrance_order)
-        .map_err(|e| {
-            error!("Error adding wrestler to match: {}", e);
-            format!("Failed to add wrestler to match: {}", e)
+    match_data: MatchData,
+    participant_ids: Vec<i32>,
+) -> Result<(), Vec<String>> {
+    let mut conn = get_connection(&state).map_err(|e| vec![e])?;
+
+    internal_validate_match(&mut conn, &match_data, &participant_ids)
+        .inspect_err(|violations| {
+            info!("Proposed match has {} violation(s)", violations.len());
         })
 }
 
-/// Tauri command to get all participants in a match
+/// Tauri command to book a match with its participants and optional winner
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `match_id` - ID of the match
+/// * `match_data` - MatchData struct containing all match details
+/// * `participants` - `(wrestler_id, team_number, entrance_order)` for each participant
+/// * `winner_id` - Optional ID of the winning wrestler
 /// 
 /// # Returns
-/// * `Ok(Vec<(MatchParticipant, Wrestler)>)` - Participants with wrestler details
-/// * `Err(String)` - Error message if query fails
+/// * `Ok(Match)` - The newly created match
+/// * `Err(AppError)` - Categorized error if validation or creation fails; nothing is booked
 #[tauri::command]
-pub fn get_match_participants(
+pub fn book_complete_match(
     state: State<'_, DbState>,
-    match_id: i32,
-) -> Result<Vec<(MatchParticipant, Wrestler)>, String> {
-    let mut conn = get_connection(&state)?;
-    
-    internal_get_match_participants(&mut conn, match_id)
+    match_data: MatchData,
+    participants: Vec<(i32, Option<i32>, Option<i32>)>,
+    winner_id: Option<i32>,
+) -> Result<Match, AppError> {
+    validate_id(match_data.show_id, "show_id")?;
+    if let Some(id) = winner_id {
+        validate_id(id, "winner_id")?;
+    }
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_book_complete_match(&mut conn, &match_data, &participants, winner_id)
+        .inspect(|booked| {
+            info!("Match {} booked with {} participant(s)", booked.id, participants.len());
+        })
         .map_err(|e| {
-            error!("Error loading match participants: {}", e);
-            format!("Failed to load match participants: {}", e)
+            error!("Error booking complete match for show {}: {}", match_data.show_id, e);
+            e.with_context("Failed to book match")
         })
 }
 
-/// Tauri command to set the winner of a match
+/// Tauri command to list wrestlers double-booked on a show's card
 /// 
 /// # Arguments
 /// * `state` - The Tauri state containing the database pool
-/// * `match_id` - ID of the match
-/// * `winner_id` - ID of the winning wrestler
+/// * `show_id` - ID of the show
 /// 
 /// # Returns
-/// * `Ok(Match)` - The updated match
-/// * `Err(String)` - Error message if update fails
+/// * `Ok(Vec<(Wrestler, Vec<i32>)>)` - Each double-booked wrestler with their match IDs
+/// * `Err(AppError)` - Categorized error if query fails
 #[tauri::command]
-pub fn set_match_winner(
+pub fn get_booking_conflicts(
     state: State<'_, DbState>,
-    match_id: i32,
-    winner_id: i32,
-) -> Result<Match, String> {
-    let mut conn = get_connection(&state)?;
-    
-    internal_set_match_winner(&mut conn, match_id, winner_id)
-        .map_err(|e| {
-            error!("Error setting match winner: {}", e);
-            format!("Failed to set match winner: {}", e)
-        })
+    show_id: i32,
+) -> Result<Vec<(Wrestler, Vec<i32>)>, AppError> {
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_get_booking_conflicts(&mut conn, show_id).map_err(|e| {
+        error!("Error checking booking conflicts for show {}: {}", show_id, e);
+        AppError::from(e).with_context("Failed to check booking conflicts")
+    })
 }
 
 /// Vacates a title by ending the current title reign
@@ -2524,7 +9760,7 @@ pub fn set_match_winner(
 /// * `Err(DieselError)` - Database error if update fails
 /// 
 /// # Note
-/// Validates string lengths to prevent database abuse
+/// Validates string lengths with `validate_event_fields` to prevent database abuse
 pub fn internal_vacate_title(
     conn: &mut SqliteConnection,
     title_id: i32,
@@ -2536,39 +9772,13 @@ pub fn internal_vacate_title(
     use diesel::result::{DatabaseErrorKind, Error as DieselError};
     
     // Input validation to prevent abuse
-    const MAX_STRING_LENGTH: usize = 255;
-    
-    if let Some(name) = event_name {
-        if name.len() > MAX_STRING_LENGTH {
-            return Err(DieselError::DatabaseError(
-                DatabaseErrorKind::Unknown,
-                Box::new("Event name too long".to_string())
-            ));
-        }
-    }
-    
-    if let Some(location) = event_location {
-        if location.len() > MAX_STRING_LENGTH {
-            return Err(DieselError::DatabaseError(
-                DatabaseErrorKind::Unknown,
-                Box::new("Event location too long".to_string())
-            ));
-        }
-    }
-    
-    if let Some(method) = change_method {
-        if method.len() > MAX_STRING_LENGTH {
-            return Err(DieselError::DatabaseError(
-                DatabaseErrorKind::Unknown,
-                Box::new("Change method too long".to_string())
-            ));
-        }
-    }
+    validate_event_fields(event_name, event_location, change_method)
+        .map_err(|e| DieselError::DatabaseError(DatabaseErrorKind::Unknown, Box::new(e.to_string())))?;
     
     let now = Utc::now().naive_utc();
     
     // End current title reigns for this title
-    diesel::update(title_holders::table)
+    let vacated = diesel::update(title_holders::table)
         .filter(title_holders::title_id.eq(title_id))
         .filter(title_holders::held_until.is_null())
         .set((
@@ -2578,6 +9788,10 @@ pub fn internal_vacate_title(
             title_holders::change_method.eq(change_method.map(|s| s.to_string())),
         ))
         .execute(conn)?;
+
+    if vacated > 0 {
+        internal_log_operation(conn, "title_vacated", "title", Some(title_id), change_method);
+    }
     
     Ok(())
 }
@@ -2602,6 +9816,8 @@ pub fn vacate_title(
     event_location: Option<String>,
     change_method: Option<String>,
 ) -> Result<String, String> {
+    validate_id(title_id, "title_id").map_err(|e| e.to_string())?;
+
     let mut conn = get_connection(&state)?;
     
     internal_vacate_title(
@@ -2618,3 +9834,373 @@ pub fn vacate_title(
     
     Ok("Title vacated successfully".to_string())
 }
+
+// ===== Reports =====
+
+/// Number of title changes listed in the universe report
+const REPORT_RECENT_CHANGES_LIMIT: i64 = 10;
+
+/// Gets the most recent title changes across all titles
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `limit` - Maximum number of changes to return
+/// 
+/// # Returns
+/// * `Ok(Vec<(TitleHolder, String, String)>)` - Reigns with title name and wrestler name, newest first
+/// * `Err(DieselError)` - Database error if query fails
+pub fn internal_get_recent_title_changes(
+    conn: &mut SqliteConnection,
+    limit: i64,
+) -> Result<Vec<(TitleHolder, String, String)>, DieselError> {
+    use crate::schema::{title_holders, titles, wrestlers};
+
+    title_holders::table
+        .inner_join(titles::table.on(title_holders::title_id.eq(titles::id)))
+        .inner_join(wrestlers::table.on(title_holders::wrestler_id.eq(wrestlers::id)))
+        .select((TitleHolder::as_select(), titles::name, wrestlers::name))
+        .order(title_holders::held_since.desc())
+        .then_order_by(title_holders::id.desc())
+        .limit(limit)
+        .load::<(TitleHolder, String, String)>(conn)
+}
+
+/// Builds the promotions and shows section of the universe report
+fn report_shows_section(conn: &mut SqliteConnection) -> Result<String, DieselError> {
+    use crate::schema::promotions;
+
+    let all_promotions = promotions::table
+        .order(promotions::name.asc())
+        .load::<Promotion>(conn)?;
+    let all_shows = internal_get_shows(conn)?;
+
+    let mut section = String::from("## Promotions & Shows\n\n");
+    for promotion in &all_promotions {
+        section.push_str(&format!("### {}\n\n", promotion.name));
+        let promotion_shows: Vec<&Show> = all_shows
+            .iter()
+            .filter(|show| show.promotion_id == Some(promotion.id))
+            .collect();
+        if promotion_shows.is_empty() {
+            section.push_str("- No shows\n");
+        }
+        for show in promotion_shows {
+            section.push_str(&format!("- {}\n", show.name));
+        }
+        section.push('\n');
+    }
+
+    let independent_shows: Vec<&Show> = all_shows
+        .iter()
+        .filter(|show| show.promotion_id.is_none())
+        .collect();
+    if !independent_shows.is_empty() {
+        if !all_promotions.is_empty() {
+            section.push_str("### Independent Shows\n\n");
+        }
+        for show in independent_shows {
+            section.push_str(&format!("- {}\n", show.name));
+        }
+        section.push('\n');
+    }
+
+    if all_shows.is_empty() && all_promotions.is_empty() {
+        section.push_str("No shows yet.\n\n");
+    }
+
+    Ok(section)
+}
+
+/// Builds the current champions section of the universe report
+fn report_champions_section(conn: &mut SqliteConnection) -> Result<String, DieselError> {
+    let mut section = String::from("## Current Champions\n\n");
+
+    let all_titles = internal_get_titles(conn)?;
+    if all_titles.is_empty() {
+        section.push_str("No titles yet.\n");
+    }
+    for title in all_titles {
+        let holders = if title.current_holders.is_empty() {
+            "Vacant".to_string()
+        } else {
+            title
+                .current_holders
+                .iter()
+                .map(|holder| holder.wrestler_name.as_str())
+                .collect::<Vec<_>>()
+                .join(" & ")
+        };
+        section.push_str(&format!("- **{}**: {}\n", title.title.name, holders));
+    }
+    section.push('\n');
+
+    Ok(section)
+}
+
+/// Builds the roster sizes section of the universe report
+fn report_roster_section(conn: &mut SqliteConnection) -> Result<String, DieselError> {
+    let mut section = String::from("## Roster Sizes\n\n");
+
+    for show in internal_get_shows(conn)? {
+        let roster_size = internal_get_wrestlers_for_show(conn, show.id)?.len();
+        section.push_str(&format!("- {}: {} wrestlers\n", show.name, roster_size));
+    }
+    let free_agents = internal_get_unassigned_wrestlers(conn)?.len();
+    section.push_str(&format!("- Free agents: {} wrestlers\n\n", free_agents));
+
+    Ok(section)
+}
+
+/// Builds the recent title changes section of the universe report
+fn report_title_changes_section(conn: &mut SqliteConnection) -> Result<String, DieselError> {
+    let mut section = String::from("## Recent Title Changes\n\n");
+
+    let changes = internal_get_recent_title_changes(conn, REPORT_RECENT_CHANGES_LIMIT)?;
+    if changes.is_empty() {
+        section.push_str("No title changes yet.\n");
+    }
+    for (holder, title_name, wrestler_name) in changes {
+        section.push_str(&format!(
+            "- {}: {} won the {}",
+            holder.held_since.format("%Y-%m-%d"),
+            wrestler_name,
+            title_name
+        ));
+        if let Some(event_name) = &holder.event_name {
+            section.push_str(&format!(" at {}", event_name));
+        }
+        if let Some(change_method) = &holder.change_method {
+            section.push_str(&format!(" ({})", change_method));
+        }
+        section.push('\n');
+    }
+
+    Ok(section)
+}
+
+/// Generates a markdown summary of the whole universe
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(String)` - Markdown report with shows, champions, roster sizes and recent title changes
+/// * `Err(DieselError)` - Database error if any section fails to load
+pub fn internal_generate_report(conn: &mut SqliteConnection) -> Result<String, DieselError> {
+    let mut report = format!(
+        "# Universe Report\n\n_Generated {}_\n\n",
+        Utc::now().format("%Y-%m-%d %H:%M UTC")
+    );
+
+    report.push_str(&report_shows_section(conn)?);
+    report.push_str(&report_champions_section(conn)?);
+    report.push_str(&report_roster_section(conn)?);
+    report.push_str(&report_title_changes_section(conn)?);
+
+    Ok(report)
+}
+
+/// Tauri command to generate a markdown summary of the universe
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(String)` - Markdown report the frontend can display or save
+/// * `Err(AppError)` - Categorized error if the report cannot be built
+#[tauri::command]
+pub fn generate_universe_report(state: State<'_, DbState>) -> Result<String, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_generate_report(&mut conn)
+        .inspect(|_| {
+            info!("Universe report generated");
+        })
+        .map_err(|e| {
+            error!("Error generating universe report: {}", e);
+            AppError::from(e).with_context("Failed to generate report")
+        })
+}
+
+/// Generates a shareable markdown card for a single show
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(String)` - Markdown with the show header and every match in card order
+/// * `Err(DieselError)` - NotFound if the show does not exist, or a database error
+/// 
+/// # Note
+/// Built from the same card query as `get_show_card_summary`; each match lists
+/// its competitors, stipulation, title at stake and the winner once decided.
+/// Managers, referees and other non-competing roles are left off the card.
+pub fn internal_export_show_card_markdown(
+    conn: &mut SqliteConnection,
+    show_id: i32,
+) -> Result<String, DieselError> {
+    use crate::schema::shows;
+
+    let show = shows::table.find(show_id).first::<Show>(conn)?;
+    let summaries = internal_get_show_card_summary(conn, show_id)?;
+
+    let mut card = format!("# {}\n\n", show.name);
+    if !show.description.trim().is_empty() {
+        card.push_str(&format!("_{}_\n\n", show.description.trim()));
+    }
+    card.push_str("## Card\n\n");
+
+    if summaries.is_empty() {
+        card.push_str("No matches booked yet.\n");
+    }
+    for (position, summary) in summaries.iter().enumerate() {
+        let heading = summary.match_name.as_deref().unwrap_or(&summary.match_type);
+        card.push_str(&format!("### {}. {}", position + 1, heading));
+        if summary.is_main_event {
+            card.push_str(" (Main Event)");
+        }
+        card.push_str("\n\n");
+
+        card.push_str(&format!("- **Type:** {}\n", summary.match_type));
+        if let Some(stipulation) = &summary.match_stipulation {
+            card.push_str(&format!("- **Stipulation:** {}\n", stipulation));
+        }
+        if let Some(title_name) = &summary.title_name {
+            card.push_str(&format!("- **Title:** {}\n", title_name));
+        }
+        let competitors = internal_get_match_participants(conn, summary.match_id)?
+            .into_iter()
+            .filter(|(participant, _)| participant.role == COMPETITOR_ROLE)
+            .map(|(_, wrestler)| wrestler.name)
+            .collect::<Vec<_>>();
+        let participants = if competitors.is_empty() {
+            "TBD".to_string()
+        } else {
+            competitors.join(", ")
+        };
+        card.push_str(&format!("- **Participants:** {}\n", participants));
+        if let Some(winner) = internal_get_match_winner(conn, summary.match_id)? {
+            card.push_str(&format!("- **Result:** {} wins\n", winner.name));
+        }
+        card.push('\n');
+    }
+
+    Ok(card)
+}
+
+/// Tauri command to export a single show's card as markdown
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// * `show_id` - ID of the show
+/// 
+/// # Returns
+/// * `Ok(String)` - Markdown card the frontend can display or share
+/// * `Err(AppError)` - Categorized error if the show is missing or the card cannot be built
+#[tauri::command]
+pub fn export_show_card_markdown(state: State<'_, DbState>, show_id: i32) -> Result<String, AppError> {
+    validate_id(show_id, "show_id")?;
+
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_export_show_card_markdown(&mut conn, show_id)
+        .inspect(|_| {
+            info!("Show card exported for show {}", show_id);
+        })
+        .map_err(|e| {
+            error!("Error exporting card for show {}: {}", show_id, e);
+            AppError::from(e).with_context("Failed to export show card")
+        })
+}
+
+/// Number of offending ids kept per category of the universe health check
+const VALIDATION_SAMPLE_SIZE: usize = 10;
+
+/// Runs every consistency check and groups the problems by category
+/// 
+/// # Arguments
+/// * `conn` - Mutable reference to the database connection
+/// 
+/// # Returns
+/// * `Ok(UniverseValidation)` - Count and sample ids for each category
+/// * `Err(DieselError)` - Database error if any check fails
+/// 
+/// # Note
+/// Read-only: drifted records are detected by deriving each record the same
+/// way `recompute_wrestler_record` does, without saving the result.
+pub fn internal_validate_universe(
+    conn: &mut SqliteConnection,
+) -> Result<UniverseValidation, DieselError> {
+    use crate::schema::{shows, title_holders, titles, wrestlers};
+
+    let orphaned_holders = title_holders::table
+        .left_join(titles::table.on(title_holders::title_id.eq(titles::id)))
+        .left_join(wrestlers::table.on(title_holders::wrestler_id.eq(wrestlers::id)))
+        .filter(titles::id.is_null().or(wrestlers::id.is_null()))
+        .select(title_holders::id)
+        .load::<i32>(conn)?;
+
+    let double_reigns = internal_get_titles_with_duplicate_reigns(conn)?;
+
+    let benchwarmers = internal_get_benchwarmers(conn)?
+        .into_iter()
+        .map(|wrestler| wrestler.id)
+        .collect();
+
+    let mut conflicted_wrestlers = Vec::new();
+    for show_id in shows::table.select(shows::id).load::<i32>(conn)? {
+        conflicted_wrestlers.extend(
+            internal_get_booking_conflicts(conn, show_id)?
+                .into_iter()
+                .map(|(wrestler, _)| wrestler.id),
+        );
+    }
+
+    let mut drifted = Vec::new();
+    let records = wrestlers::table
+        .select((wrestlers::id, wrestlers::wins, wrestlers::losses))
+        .load::<(i32, i32, i32)>(conn)?;
+    for (wrestler_id, wins, losses) in records {
+        if derived_record(conn, wrestler_id)? != (wins as i64, losses as i64) {
+            drifted.push(wrestler_id);
+        }
+    }
+
+    Ok(UniverseValidation {
+        orphaned_title_holders: ConsistencyIssue::from_ids(orphaned_holders, VALIDATION_SAMPLE_SIZE),
+        double_reigns: ConsistencyIssue::from_ids(double_reigns, VALIDATION_SAMPLE_SIZE),
+        benchwarmers: ConsistencyIssue::from_ids(benchwarmers, VALIDATION_SAMPLE_SIZE),
+        booking_conflicts: ConsistencyIssue::from_ids(conflicted_wrestlers, VALIDATION_SAMPLE_SIZE),
+        drifted_records: ConsistencyIssue::from_ids(drifted, VALIDATION_SAMPLE_SIZE),
+    })
+}
+
+/// Tauri command to run the universe health check
+/// 
+/// # Arguments
+/// * `state` - The Tauri state containing the database pool
+/// 
+/// # Returns
+/// * `Ok(UniverseValidation)` - Count and sample ids for each category
+/// * `Err(AppError)` - Categorized error if any check fails
+#[tauri::command]
+pub fn validate_universe(state: State<'_, DbState>) -> Result<UniverseValidation, AppError> {
+    let mut conn = get_connection(&state).map_err(AppError::Database)?;
+
+    internal_validate_universe(&mut conn)
+        .inspect(|report| {
+            info!(
+                "Universe check: {} orphaned reigns, {} double reigns, {} benchwarmers, {} booking conflicts, {} drifted records",
+                report.orphaned_title_holders.count,
+                report.double_reigns.count,
+                report.benchwarmers.count,
+                report.booking_conflicts.count,
+                report.drifted_records.count
+            )
+        })
+        .map_err(|e| {
+            error!("Error validating universe: {}", e);
+            AppError::from(e).with_context("Failed to validate universe")
+        })
+}
@@ -0,0 +1,59 @@
+//! Application error type shared by Tauri commands
+//!
+//! Commands returning `AppError` serialize to `{ "kind": ..., "message": ... }`
+//! so the frontend can branch on the error category instead of parsing strings.
+
+use diesel::result::Error as DieselError;
+use serde::Serialize;
+use std::fmt;
+
+/// Categorized error returned by Tauri commands
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", content = "message")]
+pub enum AppError {
+    /// The requested record does not exist
+    NotFound(String),
+    /// Input failed validation before reaching the database
+    Validation(String),
+    /// Database or connection pool failure
+    Database(String),
+    /// The caller is not allowed to perform the operation
+    Unauthorized(String),
+}
+
+impl AppError {
+    /// Prefixes the error message with context while keeping the error kind
+    ///
+    /// # Arguments
+    /// * `context` - Description of the failed operation (e.g., "Failed to create title")
+    pub fn with_context(self, context: &str) -> Self {
+        match self {
+            AppError::NotFound(msg) => AppError::NotFound(format!("{}: {}", context, msg)),
+            AppError::Validation(msg) => AppError::Validation(format!("{}: {}", context, msg)),
+            AppError::Database(msg) => AppError::Database(format!("{}: {}", context, msg)),
+            AppError::Unauthorized(msg) => AppError::Unauthorized(format!("{}: {}", context, msg)),
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AppError::NotFound(msg)
+            | AppError::Validation(msg)
+            | AppError::Database(msg)
+            | AppError::Unauthorized(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<DieselError> for AppError {
+    fn from(e: DieselError) -> Self {
+        match e {
+            DieselError::NotFound => AppError::NotFound(e.to_string()),
+            _ => AppError::Database(e.to_string()),
+        }
+    }
+}
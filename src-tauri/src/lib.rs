@@ -5,6 +5,7 @@
 
 pub mod auth;
 pub mod db;
+pub mod error;
 pub mod models;
 pub mod schema;
 pub mod types;
@@ -34,38 +35,142 @@ pub fn run() {
             // Database operations
             db::get_shows,
             db::create_show,
+            db::get_show_by_id,
+            db::update_show,
+            db::set_show_allow_multi_match,
+            db::update_show_metrics,
+            db::get_average_show_rating,
+            db::create_promotion,
+            db::get_promotion_by_id,
+            db::assign_show_to_promotion,
+            db::get_promotion_overview,
+            db::get_champions_for_promotion,
             db::get_wrestlers,
             db::get_unassigned_wrestlers,
+            db::get_free_agents,
+            db::get_benchwarmers,
             db::get_wrestler_by_id,
+            db::count_wrestlers_by_gender,
+            db::count_wrestlers_by_decade,
             db::update_wrestler_power_ratings,
             db::update_wrestler_basic_stats,
             db::update_wrestler_name,
             db::update_wrestler_real_name,
             db::update_wrestler_biography,
+            db::update_wrestler,
             db::create_user,
+            db::get_setting,
+            db::set_setting,
+            db::get_audit_log,
             db::create_wrestler,
             db::create_user_wrestler,
+            db::create_wrestler_with_moves,
+            db::clone_wrestler,
             db::delete_wrestler,
+            db::get_wrestler_deletion_impact,
+            db::set_wrestler_status,
+            db::set_wrestler_home_promotion,
+            db::get_wrestlers_by_home_promotion,
+            db::get_inactive_wrestlers,
+            db::get_never_champions,
+            db::get_rating_averages,
+            db::get_experience_extremes,
+            db::get_wrestlers_above_rating,
+            db::get_wrestlers_by_height_range,
+            db::compare_wrestlers,
+            db::get_busiest_wrestlers,
+            db::get_frequent_opponents,
+            db::get_signature_moves_for_wrestler,
+            db::set_signature_move_active,
+            db::get_signature_moves_by_type,
             db::create_belt,
+            db::create_title_set,
             db::get_titles,
+            db::get_champions_flat,
+            db::search_titles,
+            db::reorder_titles,
+            db::move_all_titles,
+            db::get_champion_for_title,
+            db::get_holder_at_match,
+            db::get_titles_grouped,
             db::get_titles_for_show,
+            db::get_titles_for_division,
             db::get_titles_for_wrestler,
             db::get_unassigned_titles,
+            db::get_misaligned_champions,
+            db::get_current_titles_for_wrestler,
+            db::get_prestige_leaderboard,
+            db::get_dominant_champions,
+            db::get_title_history_for_wrestler,
+            db::get_wrestler_title_timeline,
+            db::get_wrestler_accolades,
+            db::get_title_statistics,
+            db::get_undefended_titles,
+            db::get_inaugural_champion,
+            db::get_title_timeline,
+            db::get_vacancy_history,
+            db::get_average_reigns,
+            db::get_change_method_stats,
+            db::count_titles_by_division,
+            db::count_active_reigns,
+            db::get_distinct_divisions,
             db::update_title_holder,
+            db::process_ppv_results,
+            db::undo_last_title_change,
+            db::repair_duplicate_reigns,
             db::vacate_title,
             db::delete_title,
             db::create_test_data,
             // Show roster operations
             db::get_wrestlers_for_show,
+            db::get_show_roster_detailed,
+            db::get_roster_balance,
+            db::get_wrestler_rank_in_show,
             db::assign_wrestler_to_show,
             db::remove_wrestler_from_show,
+            db::restore_roster_assignment,
+            db::get_roster_mode,
+            db::set_roster_mode,
             db::get_shows_for_wrestler,
             // Match booking operations
             db::create_match,
+            db::create_rematch,
+            db::insert_match_at,
             db::get_matches_for_show,
+            db::get_title_matches_for_show,
+            db::count_matches_for_show,
+            db::next_match_order,
+            db::get_match_by_id,
+            db::get_match_capacity,
+            db::get_match_winner,
+            db::get_unfinished_matches,
+            db::get_distinct_match_types,
+            db::get_shows_with_upcoming_matches,
+            db::get_last_match_for_wrestler,
+            db::get_recent_matches,
             db::add_wrestler_to_match,
             db::get_match_participants,
+            db::get_match_roles,
+            db::get_show_card_summary,
+            db::suggest_match_winner,
             db::set_match_winner,
+            db::set_match_result,
+            db::recompute_wrestler_record,
+            db::recompute_all_records,
+            db::reset_all_records,
+            db::get_win_rate_trend,
+            db::set_participant_role,
+            db::substitute_participant,
+            db::set_match_title,
+            db::set_match_notes,
+            db::set_main_event,
+            db::validate_match,
+            db::book_complete_match,
+            db::get_booking_conflicts,
+            // Report operations
+            db::generate_universe_report,
+            db::export_show_card_markdown,
+            db::validate_universe,
             // Authentication operations
             auth::verify_credentials,
             auth::register_user,
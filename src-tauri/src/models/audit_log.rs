@@ -0,0 +1,31 @@
+//! Audit log models and data structures
+//!
+//! Audit entries are a best-effort history of key mutations for the Command Center.
+
+use crate::schema::audit_log;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A single recorded operation
+#[derive(Debug, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = audit_log)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct OperationLog {
+    pub id: i32,
+    pub timestamp: NaiveDateTime,
+    pub operation: String,
+    pub entity: String,
+    pub entity_id: Option<i32>,
+    pub details: Option<String>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = audit_log)]
+pub struct NewOperationLog {
+    pub timestamp: NaiveDateTime,
+    pub operation: String,
+    pub entity: String,
+    pub entity_id: Option<i32>,
+    pub details: Option<String>,
+}
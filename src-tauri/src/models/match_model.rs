@@ -9,6 +9,12 @@ use chrono::{NaiveDate, NaiveDateTime};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 
+/// Ways a match can conclude; only a win changes wrestler records
+pub const MATCH_RESULT_TYPES: &[&str] = &["Win", "NoContest", "Draw"];
+
+/// Result type of a match decided by a winner
+pub const WIN_RESULT: &str = "Win";
+
 /// Main match model representing a wrestling match
 /// 
 /// Matches are wrestling contests that take place on shows. They can be
@@ -45,6 +51,12 @@ pub struct Match {
     pub created_at: Option<NaiveDateTime>,
     /// Timestamp when the match was last updated
     pub updated_at: Option<NaiveDateTime>,
+    /// Booker's storyline notes
+    pub notes: Option<String>,
+    /// Whether this match headlines its show (at most one per show)
+    pub is_main_event: bool,
+    /// How the match concluded ("Win", "NoContest" or "Draw"; None if it hasn't concluded)
+    pub result_type: Option<String>,
 }
 
 /// Model for creating a new match
@@ -69,7 +81,7 @@ pub struct NewMatch {
 /// 
 /// Used by the frontend when creating a new match.
 /// The scheduled_date string should be in "YYYY-MM-DD" format.
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct MatchData {
     pub show_id: i32,
     pub match_name: Option<String>,
@@ -79,4 +91,46 @@ pub struct MatchData {
     pub match_order: Option<i32>,
     pub is_title_match: bool,
     pub title_id: Option<i32>,
-}
\ No newline at end of file
+}
+/// Compact, read-only view of a match for printable show cards
+/// 
+/// Participant names are pre-joined so the card can be rendered without
+/// additional lookups. `title_name` is only set for title matches.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MatchSummary {
+    pub match_id: i32,
+    pub match_name: Option<String>,
+    pub match_type: String,
+    pub match_stipulation: Option<String>,
+    pub match_order: Option<i32>,
+    pub is_title_match: bool,
+    pub title_name: Option<String>,
+    pub participants: String,
+    pub notes: Option<String>,
+    pub is_main_event: bool,
+}
+
+/// Entry in the activity feed of recently scheduled matches across all shows
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecentMatch {
+    pub match_id: i32,
+    pub show_id: i32,
+    pub show_name: String,
+    pub match_name: Option<String>,
+    pub match_type: String,
+    pub scheduled_date: Option<NaiveDate>,
+    /// Name of the winner (None if the match hasn't concluded)
+    pub winner_name: Option<String>,
+    pub is_main_event: bool,
+}
+
+/// Advisory pick for the likely winner of a match
+/// 
+/// Produced by `suggest_match_winner`; nothing is recorded on the match.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WinnerSuggestion {
+    /// The favored wrestler
+    pub wrestler_id: i32,
+    /// The favored wrestler's estimated chance of winning, as a percentage
+    pub confidence: f64,
+}
@@ -3,6 +3,12 @@ use crate::schema::match_participants;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 
+/// Roles a wrestler can have in a match; only competitors count toward the record
+pub const PARTICIPANT_ROLES: &[&str] = &["Competitor", "Interference", "Manager", "Referee"];
+
+/// Default role for newly added participants
+pub const COMPETITOR_ROLE: &str = "Competitor";
+
 #[derive(Debug, Queryable, Selectable, Identifiable, Associations, Serialize, Deserialize)]
 #[diesel(table_name = match_participants)]
 #[diesel(belongs_to(Match, foreign_key = match_id))]
@@ -14,6 +20,7 @@ pub struct MatchParticipant {
     pub wrestler_id: i32,
     pub team_number: Option<i32>,
     pub entrance_order: Option<i32>,
+    pub role: String, // "Competitor", "Interference", "Manager" or "Referee"
 }
 
 #[derive(Insertable)]
@@ -23,6 +30,7 @@ pub struct NewMatchParticipant {
     pub wrestler_id: i32,
     pub team_number: Option<i32>,
     pub entrance_order: Option<i32>,
+    pub role: String,
 }
 
 #[derive(Deserialize)]
@@ -1,19 +1,27 @@
+mod audit_log;
 mod match_model;
 mod match_participant;
+mod promotion;
+mod setting;
 mod show;
 mod show_roster;
 mod signature_move;
 mod title;
 mod title_holder;
 mod user;
+mod validation;
 mod wrestler;
 
-pub use match_model::{Match, NewMatch, MatchData};
-pub use match_participant::{MatchParticipant, NewMatchParticipant, MatchParticipantData};
+pub use audit_log::{NewOperationLog, OperationLog};
+pub use match_model::{Match, NewMatch, MatchData, MatchSummary, RecentMatch, WinnerSuggestion, MATCH_RESULT_TYPES, WIN_RESULT};
+pub use match_participant::{MatchParticipant, NewMatchParticipant, MatchParticipantData, COMPETITOR_ROLE, PARTICIPANT_ROLES};
+pub use promotion::{NewPromotion, Promotion, PromotionOverview};
+pub use setting::{Setting, ROSTER_MODE_EXCLUSIVE, ROSTER_MODE_KEY, ROSTER_MODE_MULTI, ROSTER_MODES};
 pub use show::{NewShow, Show, ShowData};
-pub use show_roster::{ShowRoster, NewShowRoster, ShowRosterData};
+pub use show_roster::{RankPosition, RosterBalance, RosterMember, ShowRoster, NewShowRoster, ShowRosterData};
 pub use signature_move::{MoveType, NewSignatureMove, SignatureMove, SignatureMoveData};
 pub use title::{NewTitle, Title, TitleData};
-pub use title_holder::{NewTitleHolder, TitleHolder, TitleHolderData, TitleWithHolders, TitleHolderInfo};
+pub use title_holder::{CareerReign, ChampionEntry, NewTitleHolder, PastReign, TitleHolder, TitleHolderData, TitleStats, TimelineSegment, TitleWithHolders, TitleHolderInfo, VacancyEvent};
 pub use user::{NewUser, User, UserData};
-pub use wrestler::{NewWrestler, NewEnhancedWrestler, Wrestler, WrestlerData, EnhancedWrestlerData};
+pub use validation::{ConsistencyIssue, UniverseValidation};
+pub use wrestler::{Accolades, Comparison, DeletionImpact, ExperienceExtremes, FreeAgent, NewWrestler, RatingAverages, RatingDeltas, ACTIVE_STATUS, INJURED_STATUS, RATING_FIELDS, RETIRED_STATUS, parse_height_inches, WRESTLER_STATUSES, NewEnhancedWrestler, Wrestler, WrestlerData, EnhancedWrestlerData};
@@ -0,0 +1,47 @@
+//! Promotion models and data structures
+//!
+//! This module contains all promotion-related database models and data transfer objects.
+//! Promotions are the companies that own shows (e.g., WWE owning RAW and SmackDown).
+
+use crate::models::Show;
+use crate::schema::promotions;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Main promotion model representing a wrestling company
+#[derive(Debug, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = promotions)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct Promotion {
+    /// Unique identifier
+    pub id: i32,
+    /// Promotion name (e.g., "WWE")
+    pub name: String,
+    /// Optional description of the promotion
+    pub description: Option<String>,
+    /// Timestamp when the promotion was created
+    pub created_at: Option<NaiveDateTime>,
+    /// Timestamp when the promotion was last updated
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+/// Model for creating a new promotion
+#[derive(Insertable)]
+#[diesel(table_name = promotions)]
+pub struct NewPromotion {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Landing page summary of a promotion and everything it runs
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PromotionOverview {
+    pub promotion: Promotion,
+    /// Shows belonging to the promotion, ordered by ID
+    pub shows: Vec<Show>,
+    /// Active titles assigned to the promotion's shows
+    pub title_count: i64,
+    /// Distinct wrestlers on the active rosters of the promotion's shows
+    pub total_roster: i64,
+}
@@ -0,0 +1,28 @@
+//! Setting models and data structures
+//!
+//! Settings are persisted app preferences stored as key/value text pairs.
+
+use crate::schema::settings;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Setting key holding the roster assignment mode
+pub const ROSTER_MODE_KEY: &str = "roster_mode";
+
+/// Roster mode where assigning a wrestler transfers them off their current show
+pub const ROSTER_MODE_EXCLUSIVE: &str = "exclusive";
+
+/// Roster mode where a wrestler can be on several shows at once
+pub const ROSTER_MODE_MULTI: &str = "multi";
+
+/// Roster modes accepted by `set_roster_mode`
+pub const ROSTER_MODES: &[&str] = &[ROSTER_MODE_EXCLUSIVE, ROSTER_MODE_MULTI];
+
+/// A single persisted setting
+#[derive(Debug, Queryable, Selectable, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = settings)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct Setting {
+    pub key: String,
+    pub value: String,
+}
@@ -28,6 +28,18 @@ pub struct Show {
     pub created_at: Option<NaiveDateTime>,
     /// Timestamp when the show was last updated
     pub updated_at: Option<NaiveDateTime>,
+    /// Arena or venue where the show is held
+    pub venue: Option<String>,
+    /// Day of the week the show airs (e.g., "Monday")
+    pub show_day: Option<String>,
+    /// ID of the promotion the show belongs to (None if unassigned)
+    pub promotion_id: Option<i32>,
+    /// Whether a wrestler may compete in more than one match on the card
+    pub allow_multi_match: bool,
+    /// Crowd size, if recorded
+    pub attendance: Option<i32>,
+    /// Rating of the show from 0.0 to 5.0, if recorded
+    pub show_rating: Option<f64>,
 }
 
 /// Model for creating a new show
@@ -38,13 +50,18 @@ pub struct Show {
 pub struct NewShow {
     pub name: String,
     pub description: String,
+    pub venue: Option<String>,
+    pub show_day: Option<String>,
 }
 
-/// Data transfer object for show creation via API
+/// Data transfer object for show creation and updates via API
 /// 
-/// Used by the frontend when creating a new show
+/// Used by the frontend when creating or editing a show. Venue and show day
+/// are optional and may be omitted when creating a show.
 #[derive(Deserialize)]
 pub struct ShowData {
     pub name: String,
     pub description: String,
+    pub venue: Option<String>,
+    pub show_day: Option<String>,
 }
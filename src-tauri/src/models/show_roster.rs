@@ -30,4 +30,29 @@ pub struct NewShowRoster {
 pub struct ShowRosterData {
     pub show_id: i32,
     pub wrestler_id: i32,
-}
\ No newline at end of file
+}
+
+// Roster entry with the record and title status shown in the roster view
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RosterMember {
+    pub wrestler: Wrestler,
+    pub wins: i32,
+    pub losses: i32,
+    pub is_champion: bool,
+}
+
+// Make-up of a show's active roster for booking balance
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RosterBalance {
+    pub show_id: i32,
+    pub total: i64,
+    pub gender_split: Vec<(String, i64)>,
+}
+
+// Where a wrestler stands on their show's roster by win rate
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RankPosition {
+    pub position: i32,
+    pub total: i32,
+    pub win_rate: Option<f64>,
+}
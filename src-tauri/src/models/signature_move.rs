@@ -14,6 +14,7 @@ pub struct SignatureMove {
     pub move_type: String, // "primary" or "secondary"
     pub created_at: Option<NaiveDateTime>,
     pub updated_at: Option<NaiveDateTime>,
+    pub is_active: bool, // false once the move has been retired
 }
 
 #[derive(Insertable)]
@@ -46,6 +47,19 @@ impl From<String> for MoveType {
     }
 }
 
+impl std::str::FromStr for MoveType {
+    type Err = String;
+
+    /// Strict parse that rejects unknown move types instead of defaulting
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "primary" => Ok(MoveType::Primary),
+            "secondary" => Ok(MoveType::Secondary),
+            _ => Err(format!("Invalid move type '{}': expected 'primary' or 'secondary'", s)),
+        }
+    }
+}
+
 impl From<MoveType> for String {
     fn from(move_type: MoveType) -> Self {
         match move_type {
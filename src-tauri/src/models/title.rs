@@ -48,6 +48,8 @@ pub struct Title {
     pub created_at: Option<NaiveDateTime>,
     /// Timestamp when the title was last updated
     pub updated_at: Option<NaiveDateTime>,
+    /// Custom position within the prestige tier (lower comes first)
+    pub display_order: i32,
 }
 
 /// Model for creating a new championship title
@@ -66,6 +68,7 @@ pub struct NewTitle {
     pub show_id: Option<i32>,
     pub is_active: bool,
     pub is_user_created: Option<bool>,
+    pub display_order: i32,
 }
 
 /// Data transfer object for title creation via API
@@ -58,4 +58,62 @@ pub struct TitleHolderInfo {
     pub holder: TitleHolder,
     pub wrestler_name: String,
     pub wrestler_gender: String,
-}
\ No newline at end of file
+}
+
+// Single reign from a wrestler's championship history
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PastReign {
+    pub title_id: i32,
+    pub title_name: String,
+    pub held_since: NaiveDateTime,
+    pub held_until: Option<NaiveDateTime>,
+    pub days: i32,
+    pub is_current: bool,
+}
+
+// One reign on a wrestler's career championship timeline, in chronological order
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CareerReign {
+    pub title_id: i32,
+    pub title_name: String,
+    pub held_since: NaiveDateTime,
+    pub held_until: Option<NaiveDateTime>,
+    pub days: i32,
+}
+
+// Headline reign statistics for a single title
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TitleStats {
+    pub total_reigns: i32,
+    pub distinct_champions: i32,
+    pub longest_reign_days: Option<i32>,
+    pub current_reign_days: Option<i32>,
+    pub average_reign_days: Option<f64>,
+    pub most_defenses_in_a_reign: Option<i32>,
+    pub most_defended_champion: Option<String>,
+}
+
+// One reign on a title's timeline, in chronological order
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimelineSegment {
+    pub wrestler_id: i32,
+    pub wrestler_name: String,
+    pub held_since: NaiveDateTime,
+    pub held_until: Option<NaiveDateTime>,
+    pub days: i32,
+}
+
+// A point where a title was left without a champion
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VacancyEvent {
+    pub when: NaiveDateTime,
+    pub reason: Option<String>,
+}
+
+// One champion on the flat ticker list; tag team titles produce one entry per holder
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChampionEntry {
+    pub title_name: String,
+    pub wrestler_name: String,
+    pub days_held: i32,
+}
@@ -0,0 +1,44 @@
+//! Universe consistency check models
+//!
+//! The health check groups integrity problems into categories so the Command
+//! Center can render one section per category.
+
+use serde::{Deserialize, Serialize};
+
+/// Offending records found by a single consistency check
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConsistencyIssue {
+    /// Total number of offending records
+    pub count: usize,
+    /// Up to a handful of offending ids, in ascending order
+    pub sample_ids: Vec<i32>,
+}
+
+impl ConsistencyIssue {
+    /// Builds an issue from every offending id, keeping only a sample of them
+    pub fn from_ids(mut ids: Vec<i32>, sample_size: usize) -> Self {
+        ids.sort_unstable();
+        ids.dedup();
+        let count = ids.len();
+        ids.truncate(sample_size);
+        ConsistencyIssue {
+            count,
+            sample_ids: ids,
+        }
+    }
+}
+
+/// Result of running every consistency check against the universe
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UniverseValidation {
+    /// Title reigns whose title or wrestler no longer exists (title holder ids)
+    pub orphaned_title_holders: ConsistencyIssue,
+    /// Singles titles with more than one active reign (title ids)
+    pub double_reigns: ConsistencyIssue,
+    /// Rostered wrestlers never booked in a match (wrestler ids)
+    pub benchwarmers: ConsistencyIssue,
+    /// Wrestlers booked more than once on a single-match show (wrestler ids)
+    pub booking_conflicts: ConsistencyIssue,
+    /// Wrestlers whose stored record disagrees with their match results (wrestler ids)
+    pub drifted_records: ConsistencyIssue,
+}
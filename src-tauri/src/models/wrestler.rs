@@ -60,6 +60,174 @@ pub struct Wrestler {
     pub created_at: Option<NaiveDateTime>,
     /// Timestamp when the wrestler was last updated
     pub updated_at: Option<NaiveDateTime>,
+    /// Availability status ("Active", "Injured" or "Retired")
+    pub status: String,
+    /// Nominal home brand, independent of show roster assignments
+    pub home_promotion_id: Option<i32>,
+}
+
+/// Power rating fields that can be filtered on
+pub const RATING_FIELDS: &[&str] = &["strength", "speed", "agility", "stamina", "charisma", "technique"];
+
+/// Valid wrestler availability statuses
+pub const WRESTLER_STATUSES: &[&str] = &["Active", "Injured", "Retired"];
+
+/// Status of a wrestler who is available for booking
+pub const ACTIVE_STATUS: &str = "Active";
+
+/// Status of a wrestler who cannot currently compete
+pub const INJURED_STATUS: &str = "Injured";
+
+/// Status of a wrestler who has left active competition for good
+pub const RETIRED_STATUS: &str = "Retired";
+
+const CM_PER_INCH: f64 = 2.54;
+
+/// Parses a free-form height into whole inches
+/// 
+/// Accepts feet and inches (`6'5"`, `6' 5`, `6ft 5in`, `6 feet`) and metric
+/// heights (`196 cm`). Returns None for blank, bare or out-of-range values,
+/// since a number without a unit could be either system.
+pub fn parse_height_inches(height: &str) -> Option<i32> {
+    let height = height.trim().to_lowercase();
+
+    if let Some(cm) = height.strip_suffix("cm") {
+        let cm: f64 = cm.trim().parse().ok()?;
+        return (cm > 0.0 && cm.is_finite()).then(|| (cm / CM_PER_INCH).round() as i32);
+    }
+
+    // Reduce every feet/inches notation to `<feet>'<inches>`
+    let mut normalized = height.replace(['\u{2019}', '\u{2032}'], "'");
+    for (unit, replacement) in [("feet", "'"), ("foot", "'"), ("ft", "'"), ("inches", ""), ("inch", ""), ("in", "")] {
+        normalized = normalized.replace(unit, replacement);
+    }
+    let normalized = normalized.replace(['"', '\u{201D}', '\u{2033}'], "").replace("''", "");
+
+    let (feet, inches) = normalized.split_once('\'')?;
+    let feet: i32 = feet.trim().parse().ok()?;
+    let inches: i32 = match inches.trim() {
+        "" => 0,
+        inches => inches.parse().ok()?,
+    };
+
+    if feet <= 0 || !(0..12).contains(&inches) {
+        return None;
+    }
+
+    feet.checked_mul(12)?.checked_add(inches)
+}
+
+impl Wrestler {
+    /// Share of recorded matches won, or None if the wrestler has no record
+    pub fn win_rate(&self) -> Option<f64> {
+        let total = self.wins + self.losses;
+        (total > 0).then(|| self.wins as f64 / total as f64)
+    }
+
+    /// Average of the power ratings that have been set, or None if none are
+    pub fn overall_rating(&self) -> Option<f64> {
+        let ratings: Vec<i32> = [
+            self.strength,
+            self.speed,
+            self.agility,
+            self.stamina,
+            self.charisma,
+            self.technique,
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        (!ratings.is_empty()).then(|| ratings.iter().sum::<i32>() as f64 / ratings.len() as f64)
+    }
+}
+
+/// Unassigned wrestler enriched with the stats needed to draft them
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FreeAgent {
+    pub wrestler: Wrestler,
+    /// Share of recorded matches won (None without any matches)
+    pub win_rate: Option<f64>,
+    /// Average power rating (None if the wrestler is unrated)
+    pub overall_rating: Option<f64>,
+    /// Names of the titles the wrestler currently holds
+    pub current_titles: Vec<String>,
+}
+
+/// Records removed along with a wrestler, shown before a hard delete
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeletionImpact {
+    /// Matches the wrestler is booked in
+    pub match_count: i64,
+    /// Title reigns the wrestler currently holds
+    pub active_reigns: i64,
+    /// Show roster rows for the wrestler, active or not
+    pub roster_assignments: i64,
+    pub signature_moves: i64,
+}
+
+/// Career accomplishments shown on a wrestler's profile
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Accolades {
+    /// Number of title reigns, including a current one
+    pub total_reigns: i32,
+    /// Distinct names of every title the wrestler has held, alphabetically
+    pub titles_held_names: Vec<String>,
+    pub match_wins: i32,
+    pub match_losses: i32,
+    /// Share of recorded matches won (None without any matches)
+    pub win_rate: Option<f64>,
+    /// Length of the longest reign in days, counting a current reign up to now
+    pub longest_reign_days: Option<i32>,
+}
+
+/// Roster-wide average of each power rating
+/// 
+/// Each field is None when no wrestler has that rating set
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RatingAverages {
+    pub strength: Option<f64>,
+    pub speed: Option<f64>,
+    pub agility: Option<f64>,
+    pub stamina: Option<f64>,
+    pub charisma: Option<f64>,
+    pub technique: Option<f64>,
+}
+
+/// Difference in each power rating between two wrestlers (first minus second)
+/// 
+/// A field is None when either wrestler does not have that rating set
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RatingDeltas {
+    pub strength: Option<i32>,
+    pub speed: Option<i32>,
+    pub agility: Option<i32>,
+    pub stamina: Option<i32>,
+    pub charisma: Option<i32>,
+    pub technique: Option<i32>,
+}
+
+/// Side-by-side comparison of two wrestlers' attributes and records
+/// 
+/// Deltas are always `wrestler_a` minus `wrestler_b`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Comparison {
+    pub wrestler_a: Wrestler,
+    pub wrestler_b: Wrestler,
+    pub rating_deltas: RatingDeltas,
+    pub wins_delta: i32,
+    pub losses_delta: i32,
+    /// None when either wrestler has no recorded matches
+    pub win_rate_delta: Option<f64>,
+}
+
+/// Active wrestlers with the earliest and latest debut years
+/// 
+/// Both fields are None when no active wrestler has a debut year
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExperienceExtremes {
+    pub most_experienced: Option<Wrestler>,
+    pub least_experienced: Option<Wrestler>,
 }
 
 /// Model for creating a new wrestler with basic information
@@ -1,5 +1,16 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    audit_log (id) {
+        id -> Integer,
+        timestamp -> Timestamp,
+        operation -> Text,
+        entity -> Text,
+        entity_id -> Nullable<Integer>,
+        details -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     match_participants (id) {
         id -> Integer,
@@ -7,6 +18,7 @@ diesel::table! {
         wrestler_id -> Integer,
         team_number -> Nullable<Integer>,
         entrance_order -> Nullable<Integer>,
+        role -> Text,
     }
 }
 
@@ -24,6 +36,26 @@ diesel::table! {
         title_id -> Nullable<Integer>,
         created_at -> Nullable<Timestamp>,
         updated_at -> Nullable<Timestamp>,
+        notes -> Nullable<Text>,
+        is_main_event -> Bool,
+        result_type -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    promotions (id) {
+        id -> Integer,
+        name -> Text,
+        description -> Nullable<Text>,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    settings (key) {
+        key -> Text,
+        value -> Text,
     }
 }
 
@@ -44,6 +76,12 @@ diesel::table! {
         description -> Text,
         created_at -> Nullable<Timestamp>,
         updated_at -> Nullable<Timestamp>,
+        venue -> Nullable<Text>,
+        show_day -> Nullable<Text>,
+        promotion_id -> Nullable<Integer>,
+        allow_multi_match -> Bool,
+        attendance -> Nullable<Integer>,
+        show_rating -> Nullable<Double>,
     }
 }
 
@@ -55,6 +93,7 @@ diesel::table! {
         move_type -> Text,
         created_at -> Nullable<Timestamp>,
         updated_at -> Nullable<Timestamp>,
+        is_active -> Bool,
     }
 }
 
@@ -87,6 +126,7 @@ diesel::table! {
         is_user_created -> Nullable<Bool>,
         created_at -> Nullable<Timestamp>,
         updated_at -> Nullable<Timestamp>,
+        display_order -> Integer,
     }
 }
 
@@ -122,6 +162,8 @@ diesel::table! {
         is_user_created -> Nullable<Bool>,
         created_at -> Nullable<Timestamp>,
         updated_at -> Nullable<Timestamp>,
+        status -> Text,
+        home_promotion_id -> Nullable<Integer>,
     }
 }
 
@@ -131,16 +173,21 @@ diesel::joinable!(matches -> shows (show_id));
 diesel::joinable!(matches -> titles (title_id));
 diesel::joinable!(matches -> wrestlers (winner_id));
 diesel::joinable!(show_rosters -> shows (show_id));
+diesel::joinable!(shows -> promotions (promotion_id));
 diesel::joinable!(show_rosters -> wrestlers (wrestler_id));
 diesel::joinable!(signature_moves -> wrestlers (wrestler_id));
+diesel::joinable!(wrestlers -> promotions (home_promotion_id));
 diesel::joinable!(title_holders -> titles (title_id));
 diesel::joinable!(title_holders -> wrestlers (wrestler_id));
 diesel::joinable!(titles -> shows (show_id));
 diesel::joinable!(titles -> wrestlers (current_holder_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    audit_log,
     match_participants,
     matches,
+    promotions,
+    settings,
     show_rosters,
     shows,
     signature_moves,
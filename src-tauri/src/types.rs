@@ -1,3 +1,4 @@
+use crate::error::AppError;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -20,10 +21,20 @@ impl fmt::Display for Gender {
 
 impl From<String> for Gender {
     fn from(s: String) -> Self {
-        match s.to_lowercase().as_str() {
-            "male" | "m" => Gender::Male,
-            "female" | "f" => Gender::Female,
-            _ => Gender::Other,
+        s.parse().unwrap_or(Gender::Other)
+    }
+}
+
+impl std::str::FromStr for Gender {
+    type Err = String;
+
+    /// Strict parse that rejects unknown genders instead of defaulting to Other
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "male" | "m" => Ok(Gender::Male),
+            "female" | "f" => Ok(Gender::Female),
+            "other" => Ok(Gender::Other),
+            _ => Err(format!("gender: '{}' is not one of Male, Female, Other", s.trim())),
         }
     }
 }
@@ -39,3 +50,53 @@ impl From<Gender> for String {
         gender.to_string()
     }
 }
+
+/// Canonicalizes a wrestler's gender before it is stored or used for filtering
+///
+/// # Arguments
+/// * `gender` - Gender as entered (case-insensitive, surrounding whitespace ignored)
+///
+/// # Returns
+/// * `Ok(String)` - "Male", "Female" or "Other"
+/// * `Err(AppError::Validation)` - If the gender is empty or not recognised
+///
+/// # Note
+/// "M" and "F" are accepted as shorthand for "Male" and "Female"
+pub fn normalize_wrestler_gender(gender: &str) -> Result<String, AppError> {
+    if gender.trim().is_empty() {
+        return Err(AppError::Validation("gender: Gender cannot be empty".to_string()));
+    }
+
+    gender
+        .parse::<Gender>()
+        .map(String::from)
+        .map_err(AppError::Validation)
+}
+
+/// Canonicalizes a title's gender division before it is stored or used for filtering
+///
+/// # Arguments
+/// * `gender` - Gender as entered (case-insensitive, surrounding whitespace ignored)
+///
+/// # Returns
+/// * `Ok(String)` - "Male", "Female" or "Mixed"
+/// * `Err(AppError::Validation)` - If the gender is empty or not recognised
+///
+/// # Note
+/// Titles have no "Other" division; wrestlers of any gender may hold Mixed titles
+pub fn normalize_title_gender(gender: &str) -> Result<String, AppError> {
+    let canonical = match gender.trim().to_lowercase().as_str() {
+        "male" | "m" => "Male",
+        "female" | "f" => "Female",
+        "mixed" => "Mixed",
+        "" => return Err(AppError::Validation("gender: Gender cannot be empty".to_string())),
+        _ => {
+            return Err(AppError::Validation(format!(
+                "gender: '{}' is not one of Male, Female, Mixed",
+                gender.trim()
+            )))
+        }
+    };
+
+    Ok(canonical.to_string())
+}
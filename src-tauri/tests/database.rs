@@ -0,0 +1,119 @@
+use diesel::prelude::*;
+use serial_test::serial;
+use std::fs;
+
+use wwe_universe_manager_lib::db::create_pool;
+
+const POOL_TEST_DB: &str = "./test_pool_database.db";
+
+fn remove_pool_test_database() {
+    for suffix in ["", "-wal", "-shm"] {
+        fs::remove_file(format!("{}{}", POOL_TEST_DB, suffix)).ok();
+    }
+}
+
+#[test]
+#[serial]
+fn test_pool_enforces_foreign_keys() {
+    remove_pool_test_database();
+
+    let pool = create_pool(POOL_TEST_DB).expect("Failed to create pool");
+    let mut conn = pool.get().expect("Failed to get connection");
+
+    diesel::sql_query("CREATE TABLE parents (id INTEGER PRIMARY KEY)")
+        .execute(&mut conn)
+        .expect("Failed to create parents table");
+    diesel::sql_query(
+        "CREATE TABLE children (
+            id INTEGER PRIMARY KEY,
+            parent_id INTEGER NOT NULL,
+            FOREIGN KEY (parent_id) REFERENCES parents (id) ON DELETE CASCADE
+        )",
+    )
+    .execute(&mut conn)
+    .expect("Failed to create children table");
+
+    // Inserting a child for a missing parent must be rejected
+    let orphan = diesel::sql_query("INSERT INTO children (id, parent_id) VALUES (1, 999)")
+        .execute(&mut conn);
+    assert!(orphan.is_err(), "Foreign key violation should be rejected");
+
+    // Deleting a parent must cascade to its children
+    diesel::sql_query("INSERT INTO parents (id) VALUES (1)")
+        .execute(&mut conn)
+        .expect("Failed to insert parent");
+    diesel::sql_query("INSERT INTO children (id, parent_id) VALUES (1, 1)")
+        .execute(&mut conn)
+        .expect("Failed to insert child");
+    diesel::sql_query("DELETE FROM parents WHERE id = 1")
+        .execute(&mut conn)
+        .expect("Failed to delete parent");
+
+    let remaining = diesel::sql_query("DELETE FROM children")
+        .execute(&mut conn)
+        .expect("Failed to clear children");
+    assert_eq!(remaining, 0, "Child rows should have been removed by the cascade");
+
+    drop(conn);
+    drop(pool);
+    remove_pool_test_database();
+}
+
+#[derive(QueryableByName)]
+struct JournalMode {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    journal_mode: String,
+}
+
+#[derive(QueryableByName)]
+struct RowCount {
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    count: i64,
+}
+
+fn count_entries(conn: &mut SqliteConnection) -> i64 {
+    diesel::sql_query("SELECT COUNT(*) AS count FROM entries")
+        .get_result::<RowCount>(conn)
+        .expect("Failed to count entries")
+        .count
+}
+
+#[test]
+#[serial]
+fn test_pool_readers_not_blocked_by_writer() {
+    remove_pool_test_database();
+
+    let pool = create_pool(POOL_TEST_DB).expect("Failed to create pool");
+    let mut writer = pool.get().expect("Failed to get writer connection");
+    let mut reader1 = pool.get().expect("Failed to get first reader connection");
+    let mut reader2 = pool.get().expect("Failed to get second reader connection");
+
+    let mode = diesel::sql_query("PRAGMA journal_mode")
+        .get_result::<JournalMode>(&mut writer)
+        .expect("Failed to read journal mode");
+    assert_eq!(mode.journal_mode.to_lowercase(), "wal");
+
+    diesel::sql_query("CREATE TABLE entries (id INTEGER PRIMARY KEY, label TEXT NOT NULL)")
+        .execute(&mut writer)
+        .expect("Failed to create entries table");
+
+    writer
+        .transaction::<(), diesel::result::Error, _>(|writer| {
+            diesel::sql_query("INSERT INTO entries (label) VALUES ('pending')").execute(writer)?;
+
+            // Both readers can still read the last committed state mid-write
+            assert_eq!(count_entries(&mut reader1), 0);
+            assert_eq!(count_entries(&mut reader2), 0);
+            Ok(())
+        })
+        .expect("Write transaction failed");
+
+    assert_eq!(count_entries(&mut reader1), 1);
+    assert_eq!(count_entries(&mut reader2), 1);
+
+    drop(writer);
+    drop(reader1);
+    drop(reader2);
+    drop(pool);
+    remove_pool_test_database();
+}
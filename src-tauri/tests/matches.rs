@@ -0,0 +1,1786 @@
+use serial_test::serial;
+
+use wwe_universe_manager_lib::db::{
+    internal_add_wrestler_to_match, internal_assign_wrestler_to_show, internal_create_belt,
+    internal_create_match, internal_create_rematch, internal_set_main_event, internal_get_matches_for_show, internal_get_win_rate_trend, internal_get_distinct_match_types, internal_get_title_matches_for_show, internal_create_show, internal_create_wrestler,
+    internal_get_booking_conflicts, internal_get_last_match_for_wrestler, internal_get_wrestler_by_id, internal_set_show_allow_multi_match, internal_set_match_title, internal_set_match_winner,
+    internal_get_match_by_id, internal_get_match_participants, internal_get_recent_matches, internal_get_match_winner,
+    internal_get_show_card_summary, internal_next_match_order, internal_set_match_notes,
+    internal_suggest_match_winner, internal_update_wrestler_power_ratings, internal_recompute_all_records,
+    internal_recompute_wrestler_record, internal_set_wrestler_status, internal_substitute_participant,
+    internal_set_participant_role, internal_update_wrestler_basic_stats, internal_validate_match,
+    internal_book_complete_match, internal_reset_all_records, internal_get_match_roles, internal_get_match_capacity, internal_add_participant_with_role, internal_set_match_result,
+    internal_insert_match_at, validate_id, internal_count_matches_for_show,
+};
+use wwe_universe_manager_lib::error::AppError;
+use wwe_universe_manager_lib::models::MatchData;
+
+mod test_helpers;
+use test_helpers::*;
+
+fn singles_match_data(show_id: i32, match_name: &str) -> MatchData {
+    MatchData {
+        show_id,
+        match_name: Some(match_name.to_string()),
+        match_type: "Singles".to_string(),
+        match_stipulation: None,
+        scheduled_date: None,
+        match_order: Some(1),
+        is_title_match: false,
+        title_id: None,
+    }
+}
+
+#[test]
+#[serial]
+fn test_set_match_title_and_clear_it() {
+    let test_data = TestData::new();
+    let show_name = "Title Toggle Show";
+    let title_name = "Title Toggle Championship";
+    let wrestler1_name = "Title Toggle One";
+    let wrestler2_name = "Title Toggle Two";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(wrestler1_name);
+    test_data.cleanup_wrestlers(wrestler2_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Title toggle testing")
+        .expect("Failed to create show");
+    let wrestler1 = internal_create_wrestler(&mut conn, wrestler1_name, "Male", 0, 0)
+        .expect("Failed to create wrestler 1");
+    let wrestler2 = internal_create_wrestler(&mut conn, wrestler2_name, "Male", 0, 0)
+        .expect("Failed to create wrestler 2");
+    let title = internal_create_belt(
+        &mut conn,
+        title_name,
+        "Singles",
+        "United States",
+        "Male",
+        Some(show.id),
+        None,
+        false, // is_user_created
+    )
+    .expect("Failed to create title");
+
+    let created = internal_create_match(&mut conn, &singles_match_data(show.id, "Toggle Match"))
+        .expect("Failed to create match");
+    internal_add_wrestler_to_match(&mut conn, created.id, wrestler1.id, None, Some(1))
+        .expect("Failed to add wrestler 1");
+    internal_add_wrestler_to_match(&mut conn, created.id, wrestler2.id, None, Some(2))
+        .expect("Failed to add wrestler 2");
+
+    let title_match = internal_set_match_title(&mut conn, created.id, Some(title.id))
+        .expect("Failed to set match title");
+    assert!(title_match.is_title_match);
+    assert_eq!(title_match.title_id, Some(title.id));
+
+    let non_title_match = internal_set_match_title(&mut conn, created.id, None)
+        .expect("Failed to clear match title");
+    assert!(!non_title_match.is_title_match);
+    assert_eq!(non_title_match.title_id, None);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(wrestler1_name);
+    test_data.cleanup_wrestlers(wrestler2_name);
+}
+
+#[test]
+#[serial]
+fn test_set_match_title_rejects_incompatible_gender() {
+    let test_data = TestData::new();
+    let show_name = "Gender Check Show";
+    let title_name = "Gender Check Women's Championship";
+    let wrestler_name = "Gender Check Wrestler";
+    let contender_name = "Gender Check Contender";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+    test_data.cleanup_wrestlers(contender_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Gender compatibility testing")
+        .expect("Failed to create show");
+    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let title = internal_create_belt(
+        &mut conn,
+        title_name,
+        "Singles",
+        "Women's World",
+        "Female",
+        Some(show.id),
+        None,
+        false, // is_user_created
+    )
+    .expect("Failed to create title");
+
+    let created = internal_create_match(&mut conn, &singles_match_data(show.id, "Mismatch"))
+        .expect("Failed to create match");
+    internal_add_wrestler_to_match(&mut conn, created.id, wrestler.id, None, Some(1))
+        .expect("Failed to add wrestler");
+
+    let result = internal_set_match_title(&mut conn, created.id, Some(title.id));
+    assert!(matches!(result, Err(AppError::Validation(_))));
+
+    // A referee is not contending for the title, so their gender is not checked
+    let contender = internal_create_wrestler(&mut conn, contender_name, "Female", 0, 0)
+        .expect("Failed to create contender");
+    let refereed = internal_create_match(&mut conn, &singles_match_data(show.id, "Refereed"))
+        .expect("Failed to create match");
+    internal_add_wrestler_to_match(&mut conn, refereed.id, contender.id, None, Some(1))
+        .expect("Failed to add contender");
+    internal_add_participant_with_role(&mut conn, refereed.id, wrestler.id, None, None, "Referee")
+        .expect("Failed to add referee");
+
+    let title_match = internal_set_match_title(&mut conn, refereed.id, Some(title.id))
+        .expect("Failed to set title with a referee of another gender");
+    assert_eq!(title_match.title_id, Some(title.id));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+    test_data.cleanup_wrestlers(contender_name);
+}
+
+#[test]
+#[serial]
+fn test_validate_match_reports_every_violation() {
+    let test_data = TestData::new();
+    let show_name = "Validation Show";
+    let title_name = "Validation Women's Championship";
+    let rostered_name = "Validation Rostered";
+    let outsider_name = "Validation Outsider";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(rostered_name);
+    test_data.cleanup_wrestlers(outsider_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Dry-run validation testing")
+        .expect("Failed to create show");
+    let rostered = internal_create_wrestler(&mut conn, rostered_name, "Female", 0, 0)
+        .expect("Failed to create rostered wrestler");
+    let outsider = internal_create_wrestler(&mut conn, outsider_name, "Male", 0, 0)
+        .expect("Failed to create outsider");
+    internal_assign_wrestler_to_show(&mut conn, show.id, rostered.id)
+        .expect("Failed to assign wrestler to show");
+    let title = internal_create_belt(
+        &mut conn,
+        title_name,
+        "Singles",
+        "Women's World",
+        "Female",
+        Some(show.id),
+        None,
+        false, // is_user_created
+    )
+    .expect("Failed to create title");
+
+    let mut proposed = singles_match_data(show.id, "Validation Match");
+    proposed.match_stipulation = Some("Standard".to_string());
+
+    // A singles match with one participant only fails the participant count
+    assert_eq!(internal_validate_match(&mut conn, &proposed, &[rostered.id]).unwrap_err().len(), 1);
+
+    // Bad stipulation, off-roster outsider and a gender mismatch for the title
+    proposed.match_stipulation = Some("Pillow Fight".to_string());
+    proposed.is_title_match = true;
+    proposed.title_id = Some(title.id);
+    let violations = internal_validate_match(&mut conn, &proposed, &[rostered.id, outsider.id])
+        .expect_err("Match should be rejected");
+    assert_eq!(violations.len(), 3);
+    assert!(violations.iter().any(|v| v.contains("Pillow Fight")));
+    assert!(violations.iter().any(|v| v.contains("roster")));
+    assert!(violations.iter().any(|v| v.contains(outsider_name)));
+
+    // Fixing every problem makes the match valid
+    internal_assign_wrestler_to_show(&mut conn, show.id, outsider.id)
+        .expect("Failed to assign outsider to show");
+    proposed.match_stipulation = None;
+    proposed.is_title_match = false;
+    proposed.title_id = None;
+    assert!(internal_validate_match(&mut conn, &proposed, &[rostered.id, outsider.id]).is_ok());
+
+    // Listing the same wrestler twice doesn't fill a singles match
+    let violations = internal_validate_match(&mut conn, &proposed, &[rostered.id, rostered.id])
+        .expect_err("Match should be rejected");
+    assert_eq!(violations.len(), 2);
+    assert!(violations.contains(&format!("Wrestler {} listed more than once", rostered.id)));
+    assert!(violations.iter().any(|v| v.contains("got 1")));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(rostered_name);
+    test_data.cleanup_wrestlers(outsider_name);
+}
+
+#[test]
+#[serial]
+fn test_interference_does_not_take_a_loss() {
+    let test_data = TestData::new();
+    let show_name = "Interference Show";
+    let winner_name = "Interference Winner";
+    let loser_name = "Interference Loser";
+    let interferer_name = "Interference Runner";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(winner_name);
+    test_data.cleanup_wrestlers(loser_name);
+    test_data.cleanup_wrestlers(interferer_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Interference testing")
+        .expect("Failed to create show");
+    let winner = internal_create_wrestler(&mut conn, winner_name, "Male", 0, 0)
+        .expect("Failed to create winner");
+    let loser = internal_create_wrestler(&mut conn, loser_name, "Male", 0, 0)
+        .expect("Failed to create loser");
+    let interferer = internal_create_wrestler(&mut conn, interferer_name, "Male", 0, 0)
+        .expect("Failed to create interferer");
+
+    let created = internal_create_match(&mut conn, &singles_match_data(show.id, "Interference Match"))
+        .expect("Failed to create match");
+    internal_add_wrestler_to_match(&mut conn, created.id, winner.id, None, Some(1))
+        .expect("Failed to add winner");
+    internal_add_wrestler_to_match(&mut conn, created.id, loser.id, None, Some(2))
+        .expect("Failed to add loser");
+    let run_in = internal_add_participant_with_role(&mut conn, created.id, interferer.id, None, Some(3), "Interference")
+        .expect("Failed to add interferer");
+    assert_eq!(run_in.role, "Interference");
+
+    assert!(matches!(
+        internal_set_participant_role(&mut conn, run_in.id, "Spectator"),
+        Err(AppError::Validation(_))
+    ));
+
+    internal_set_match_winner(&mut conn, created.id, winner.id)
+        .expect("Failed to set match winner");
+    // Setting the same winner again must not double count
+    internal_set_match_winner(&mut conn, created.id, winner.id)
+        .expect("Failed to reset match winner");
+
+    let winner = internal_get_wrestler_by_id(&mut conn, winner.id)
+        .expect("Failed to fetch winner")
+        .expect("Winner should exist");
+    let loser = internal_get_wrestler_by_id(&mut conn, loser.id)
+        .expect("Failed to fetch loser")
+        .expect("Loser should exist");
+    let interferer = internal_get_wrestler_by_id(&mut conn, interferer.id)
+        .expect("Failed to fetch interferer")
+        .expect("Interferer should exist");
+    assert_eq!((winner.wins, winner.losses), (1, 0));
+    assert_eq!((loser.wins, loser.losses), (0, 1));
+    assert_eq!((interferer.wins, interferer.losses), (0, 0));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(winner_name);
+    test_data.cleanup_wrestlers(loser_name);
+    test_data.cleanup_wrestlers(interferer_name);
+}
+
+#[test]
+#[serial]
+fn test_booking_conflicts_respect_multi_match_flag() {
+    let test_data = TestData::new();
+    let show_name = "Conflict Show";
+    let busy_name = "Conflict Busy Wrestler";
+    let opponent_names = ["Conflict Opponent One", "Conflict Opponent Two"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(busy_name);
+    for opponent_name in opponent_names {
+        test_data.cleanup_wrestlers(opponent_name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Conflict testing")
+        .expect("Failed to create show");
+    let busy = internal_create_wrestler(&mut conn, busy_name, "Male", 0, 0)
+        .expect("Failed to create busy wrestler");
+
+    let mut match_ids = Vec::new();
+    for opponent_name in opponent_names {
+        let opponent = internal_create_wrestler(&mut conn, opponent_name, "Male", 0, 0)
+            .expect("Failed to create opponent");
+        let booked = internal_create_match(&mut conn, &singles_match_data(show.id, opponent_name))
+            .expect("Failed to create match");
+        internal_add_wrestler_to_match(&mut conn, booked.id, busy.id, None, Some(1))
+            .expect("Failed to add busy wrestler");
+        internal_add_wrestler_to_match(&mut conn, booked.id, opponent.id, None, Some(2))
+            .expect("Failed to add opponent");
+        match_ids.push(booked.id);
+    }
+
+    let conflicts = internal_get_booking_conflicts(&mut conn, show.id)
+        .expect("Failed to check booking conflicts");
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].0.id, busy.id);
+    assert_eq!(conflicts[0].1, match_ids);
+
+    internal_set_show_allow_multi_match(&mut conn, show.id, true)
+        .expect("Failed to allow multi-match bookings");
+    let conflicts = internal_get_booking_conflicts(&mut conn, show.id)
+        .expect("Failed to check booking conflicts");
+    assert!(conflicts.is_empty());
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(busy_name);
+    for opponent_name in opponent_names {
+        test_data.cleanup_wrestlers(opponent_name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_get_last_match_for_wrestler() {
+    let test_data = TestData::new();
+    let show_name = "Last Match Show";
+    let wrestler_name = "Last Match Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Last match testing")
+        .expect("Failed to create show");
+    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+
+    let never_booked = internal_get_last_match_for_wrestler(&mut conn, wrestler.id)
+        .expect("Failed to fetch last match");
+    assert!(never_booked.is_none());
+
+    for (match_name, date) in [("Later Match", "2025-03-10"), ("Earlier Match", "2025-03-03")] {
+        let mut data = singles_match_data(show.id, match_name);
+        data.scheduled_date = Some(date.to_string());
+        let booked = internal_create_match(&mut conn, &data).expect("Failed to create match");
+        internal_add_wrestler_to_match(&mut conn, booked.id, wrestler.id, None, Some(1))
+            .expect("Failed to add wrestler");
+    }
+
+    let (last_match, last_show) = internal_get_last_match_for_wrestler(&mut conn, wrestler.id)
+        .expect("Failed to fetch last match")
+        .expect("Wrestler should have a last match");
+    assert_eq!(last_match.match_name.as_deref(), Some("Later Match"));
+    assert_eq!(last_show.id, show.id);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+}
+
+#[test]
+#[serial]
+fn test_recompute_records_repairs_corrupted_totals() {
+    let test_data = TestData::new();
+    let show_name = "Recompute Show";
+    let winner_name = "Recompute Winner";
+    let loser_name = "Recompute Loser";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(winner_name);
+    test_data.cleanup_wrestlers(loser_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Record repair testing")
+        .expect("Failed to create show");
+    let winner = internal_create_wrestler(&mut conn, winner_name, "Male", 0, 0)
+        .expect("Failed to create winner");
+    let loser = internal_create_wrestler(&mut conn, loser_name, "Male", 0, 0)
+        .expect("Failed to create loser");
+
+    for match_name in ["Recompute Match 1", "Recompute Match 2"] {
+        let created = internal_create_match(&mut conn, &singles_match_data(show.id, match_name))
+            .expect("Failed to create match");
+        internal_add_wrestler_to_match(&mut conn, created.id, winner.id, None, Some(1))
+            .expect("Failed to add winner");
+        internal_add_wrestler_to_match(&mut conn, created.id, loser.id, None, Some(2))
+            .expect("Failed to add loser");
+        internal_set_match_winner(&mut conn, created.id, winner.id)
+            .expect("Failed to set match winner");
+    }
+
+    // A match without a result must not count either way
+    let pending = internal_create_match(&mut conn, &singles_match_data(show.id, "Recompute Pending"))
+        .expect("Failed to create match");
+    internal_add_wrestler_to_match(&mut conn, pending.id, winner.id, None, Some(1))
+        .expect("Failed to add winner");
+    internal_add_wrestler_to_match(&mut conn, pending.id, loser.id, None, Some(2))
+        .expect("Failed to add loser");
+
+    // Corrupt both records by hand
+    internal_update_wrestler_basic_stats(&mut conn, winner.id, None, None, None, 40, 7)
+        .expect("Failed to corrupt winner record");
+    internal_update_wrestler_basic_stats(&mut conn, loser.id, None, None, None, 0, 0)
+        .expect("Failed to corrupt loser record");
+
+    let repaired = internal_recompute_wrestler_record(&mut conn, winner.id)
+        .expect("Failed to recompute winner record");
+    assert_eq!((repaired.wins, repaired.losses), (2, 0));
+
+    let changed = internal_recompute_all_records(&mut conn)
+        .expect("Failed to recompute all records");
+    assert_eq!(changed, 1);
+
+    let loser = internal_get_wrestler_by_id(&mut conn, loser.id)
+        .expect("Failed to fetch loser")
+        .expect("Loser should exist");
+    assert_eq!((loser.wins, loser.losses), (0, 2));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(winner_name);
+    test_data.cleanup_wrestlers(loser_name);
+}
+
+#[test]
+#[serial]
+fn test_substitute_participant_keeps_team_and_order() {
+    let test_data = TestData::new();
+    let show_name = "Substitution Show";
+    let wrestler_names = ["Sub Original", "Sub Opponent", "Sub Replacement", "Sub Injured"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Substitution testing")
+        .expect("Failed to create show");
+    let [original, opponent, replacement, injured] = wrestler_names.map(|name| {
+        internal_create_wrestler(&mut conn, name, "Male", 0, 0).expect("Failed to create wrestler")
+    });
+    internal_set_wrestler_status(&mut conn, injured.id, "Injured")
+        .expect("Failed to injure wrestler");
+
+    let created = internal_create_match(&mut conn, &singles_match_data(show.id, "Substitution Match"))
+        .expect("Failed to create match");
+    internal_add_wrestler_to_match(&mut conn, created.id, original.id, Some(1), Some(2))
+        .expect("Failed to add original");
+    internal_add_wrestler_to_match(&mut conn, created.id, opponent.id, Some(2), Some(1))
+        .expect("Failed to add opponent");
+    internal_set_match_winner(&mut conn, created.id, original.id)
+        .expect("Failed to set match winner");
+
+    assert!(matches!(
+        internal_substitute_participant(&mut conn, created.id, original.id, injured.id),
+        Err(AppError::Validation(_))
+    ));
+    assert!(matches!(
+        internal_substitute_participant(&mut conn, created.id, original.id, opponent.id),
+        Err(AppError::Validation(_))
+    ));
+    assert!(matches!(
+        internal_substitute_participant(&mut conn, created.id, replacement.id, original.id),
+        Err(AppError::NotFound(_))
+    ));
+
+    let substituted = internal_substitute_participant(&mut conn, created.id, original.id, replacement.id)
+        .expect("Failed to substitute participant");
+    assert_eq!(substituted.wrestler_id, replacement.id);
+    assert_eq!(substituted.team_number, Some(1));
+    assert_eq!(substituted.entrance_order, Some(2));
+
+    let participants = internal_get_match_participants(&mut conn, created.id)
+        .expect("Failed to load participants");
+    assert_eq!(participants.len(), 2);
+    assert!(participants.iter().all(|(_, wrestler)| wrestler.id != original.id));
+
+    // The outgoing wrestler's win is reversed and the result cleared
+    let updated = internal_get_match_by_id(&mut conn, created.id)
+        .expect("Failed to fetch match")
+        .expect("Match should exist");
+    assert_eq!(updated.winner_id, None);
+    let original = internal_get_wrestler_by_id(&mut conn, original.id)
+        .expect("Failed to fetch original")
+        .expect("Original should exist");
+    let opponent = internal_get_wrestler_by_id(&mut conn, opponent.id)
+        .expect("Failed to fetch opponent")
+        .expect("Opponent should exist");
+    assert_eq!((original.wins, original.losses), (0, 0));
+    assert_eq!((opponent.wins, opponent.losses), (0, 0));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_substitute_losing_side_moves_the_loss() {
+    let test_data = TestData::new();
+    let show_name = "Substitution Loss Show";
+    let wrestler_names = ["Sub Loss Winner", "Sub Loss Loser", "Sub Loss Replacement"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Substitution testing")
+        .expect("Failed to create show");
+    let [winner, loser, replacement] = wrestler_names.map(|name| {
+        internal_create_wrestler(&mut conn, name, "Male", 0, 0).expect("Failed to create wrestler")
+    });
+
+    let created = internal_create_match(&mut conn, &singles_match_data(show.id, "Substitution Loss Match"))
+        .expect("Failed to create match");
+    internal_add_wrestler_to_match(&mut conn, created.id, winner.id, None, Some(1))
+        .expect("Failed to add winner");
+    internal_add_wrestler_to_match(&mut conn, created.id, loser.id, None, Some(2))
+        .expect("Failed to add loser");
+    internal_set_match_winner(&mut conn, created.id, winner.id)
+        .expect("Failed to set match winner");
+
+    internal_substitute_participant(&mut conn, created.id, loser.id, replacement.id)
+        .expect("Failed to substitute participant");
+
+    // The result stands, and the loss moves to the incoming wrestler
+    let updated = internal_get_match_by_id(&mut conn, created.id)
+        .expect("Failed to fetch match")
+        .expect("Match should exist");
+    assert_eq!(updated.winner_id, Some(winner.id));
+    let record = |conn: &mut _, id| {
+        let wrestler = internal_get_wrestler_by_id(conn, id)
+            .expect("Failed to fetch wrestler")
+            .expect("Wrestler should exist");
+        (wrestler.wins, wrestler.losses)
+    };
+    assert_eq!(record(&mut conn, winner.id), (1, 0));
+    assert_eq!(record(&mut conn, loser.id), (0, 0));
+    assert_eq!(record(&mut conn, replacement.id), (0, 1));
+
+    // A later winner change reverses the result against the incoming wrestler
+    internal_set_match_winner(&mut conn, created.id, replacement.id)
+        .expect("Failed to change match winner");
+    assert_eq!(record(&mut conn, winner.id), (0, 1));
+    assert_eq!(record(&mut conn, loser.id), (0, 0));
+    assert_eq!(record(&mut conn, replacement.id), (1, 0));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_get_match_winner() {
+    let test_data = TestData::new();
+    let show_name = "Winner Lookup Show";
+    let winner_name = "Winner Lookup Victor";
+    let loser_name = "Winner Lookup Opponent";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(winner_name);
+    test_data.cleanup_wrestlers(loser_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Winner lookup testing")
+        .expect("Failed to create show");
+    let winner = internal_create_wrestler(&mut conn, winner_name, "Female", 0, 0)
+        .expect("Failed to create winner");
+    let loser = internal_create_wrestler(&mut conn, loser_name, "Female", 0, 0)
+        .expect("Failed to create loser");
+
+    let decided = internal_create_match(&mut conn, &singles_match_data(show.id, "Winner Lookup Decided"))
+        .expect("Failed to create match");
+    let undecided = internal_create_match(&mut conn, &singles_match_data(show.id, "Winner Lookup Pending"))
+        .expect("Failed to create match");
+    for created in [&decided, &undecided] {
+        internal_add_wrestler_to_match(&mut conn, created.id, winner.id, None, Some(1))
+            .expect("Failed to add winner");
+        internal_add_wrestler_to_match(&mut conn, created.id, loser.id, None, Some(2))
+            .expect("Failed to add loser");
+    }
+    internal_set_match_winner(&mut conn, decided.id, winner.id)
+        .expect("Failed to set match winner");
+
+    let found = internal_get_match_winner(&mut conn, decided.id)
+        .expect("Failed to fetch match winner")
+        .expect("Decided match should have a winner");
+    assert_eq!(found.id, winner.id);
+    assert_eq!(found.name, winner_name);
+
+    let pending = internal_get_match_winner(&mut conn, undecided.id)
+        .expect("Failed to fetch match winner");
+    assert!(pending.is_none());
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(winner_name);
+    test_data.cleanup_wrestlers(loser_name);
+}
+
+#[test]
+#[serial]
+fn test_set_and_clear_match_notes() {
+    let test_data = TestData::new();
+    let show_name = "Notes Show";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Match notes testing")
+        .expect("Failed to create show");
+    let created = internal_create_match(&mut conn, &singles_match_data(show.id, "Notes Match"))
+        .expect("Failed to create match");
+    assert_eq!(created.notes, None);
+
+    let updated = internal_set_match_notes(&mut conn, created.id, Some("  Heel turn after the bell  ".to_string()))
+        .expect("Failed to set match notes");
+    assert_eq!(updated.notes.as_deref(), Some("Heel turn after the bell"));
+
+    let card = internal_get_show_card_summary(&mut conn, show.id)
+        .expect("Failed to load show card");
+    assert_eq!(card[0].notes.as_deref(), Some("Heel turn after the bell"));
+
+    assert!(matches!(
+        internal_set_match_notes(&mut conn, created.id, Some("x".repeat(5000))),
+        Err(AppError::Validation(_))
+    ));
+
+    let cleared = internal_set_match_notes(&mut conn, created.id, None)
+        .expect("Failed to clear match notes");
+    assert_eq!(cleared.notes, None);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+}
+
+#[test]
+#[serial]
+fn test_set_match_winner_rejects_non_participant() {
+    let test_data = TestData::new();
+    let show_name = "Winner Guard Show";
+    let wrestler_names = ["Guard Competitor One", "Guard Competitor Two", "Guard Outsider"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Winner guard testing")
+        .expect("Failed to create show");
+    let [first, second, outsider] = wrestler_names.map(|name| {
+        internal_create_wrestler(&mut conn, name, "Male", 0, 0).expect("Failed to create wrestler")
+    });
+
+    let created = internal_create_match(&mut conn, &singles_match_data(show.id, "Winner Guard Match"))
+        .expect("Failed to create match");
+    internal_add_wrestler_to_match(&mut conn, created.id, first.id, None, Some(1))
+        .expect("Failed to add first competitor");
+    internal_add_wrestler_to_match(&mut conn, created.id, second.id, None, Some(2))
+        .expect("Failed to add second competitor");
+
+    assert!(matches!(
+        internal_set_match_winner(&mut conn, created.id, outsider.id),
+        Err(AppError::Validation(_))
+    ));
+
+    // Nothing was recorded for anyone
+    let unchanged = internal_get_match_by_id(&mut conn, created.id)
+        .expect("Failed to fetch match")
+        .expect("Match should exist");
+    assert_eq!(unchanged.winner_id, None);
+    let outsider = internal_get_wrestler_by_id(&mut conn, outsider.id)
+        .expect("Failed to fetch outsider")
+        .expect("Outsider should exist");
+    assert_eq!((outsider.wins, outsider.losses), (0, 0));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_set_match_winner_rejects_referee() {
+    let test_data = TestData::new();
+    let show_name = "Referee Winner Show";
+    let wrestler_names = ["Referee Guard One", "Referee Guard Two", "Referee Guard Official"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Referee winner testing")
+        .expect("Failed to create show");
+    let [first, second, referee] = wrestler_names.map(|name| {
+        internal_create_wrestler(&mut conn, name, "Male", 0, 0).expect("Failed to create wrestler")
+    });
+
+    let created = internal_create_match(&mut conn, &singles_match_data(show.id, "Referee Winner Match"))
+        .expect("Failed to create match");
+    let official = internal_add_wrestler_to_match(&mut conn, created.id, referee.id, None, None)
+        .expect("Failed to add referee");
+    internal_set_participant_role(&mut conn, official.id, "Referee")
+        .expect("Failed to set referee role");
+    internal_add_wrestler_to_match(&mut conn, created.id, first.id, None, Some(1))
+        .expect("Failed to add first competitor");
+    internal_add_wrestler_to_match(&mut conn, created.id, second.id, None, Some(2))
+        .expect("Failed to add second competitor");
+
+    assert!(matches!(
+        internal_set_match_winner(&mut conn, created.id, referee.id),
+        Err(AppError::Validation(_))
+    ));
+
+    // No result was recorded for the referee or the competitors
+    let unchanged = internal_get_match_by_id(&mut conn, created.id)
+        .expect("Failed to fetch match")
+        .expect("Match should exist");
+    assert_eq!(unchanged.winner_id, None);
+    for wrestler in [&first, &second, &referee] {
+        let reloaded = internal_get_wrestler_by_id(&mut conn, wrestler.id)
+            .expect("Failed to fetch wrestler")
+            .expect("Wrestler should exist");
+        assert_eq!((reloaded.wins, reloaded.losses), (0, 0));
+    }
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_next_match_order() {
+    let test_data = TestData::new();
+    let show_name = "Match Order Show";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Match order testing")
+        .expect("Failed to create show");
+    assert_eq!(internal_next_match_order(&mut conn, show.id).expect("Failed to get next order"), 1);
+
+    // Orders 1 and 4 leave a gap; the next slot still goes after the last match
+    for (match_name, order) in [("Order Opener", 1), ("Order Main Event", 4)] {
+        let mut data = singles_match_data(show.id, match_name);
+        data.match_order = Some(order);
+        internal_create_match(&mut conn, &data).expect("Failed to create match");
+    }
+    assert_eq!(internal_next_match_order(&mut conn, show.id).expect("Failed to get next order"), 5);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+}
+
+#[test]
+#[serial]
+fn test_suggest_match_winner_favors_stronger_wrestler() {
+    let test_data = TestData::new();
+    let show_name = "Suggestion Show";
+    let favorite_name = "Suggestion Favorite";
+    let underdog_name = "Suggestion Underdog";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(favorite_name);
+    test_data.cleanup_wrestlers(underdog_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Winner suggestion testing")
+        .expect("Failed to create show");
+    let favorite = internal_create_wrestler(&mut conn, favorite_name, "Male", 40, 5)
+        .expect("Failed to create favorite");
+    let underdog = internal_create_wrestler(&mut conn, underdog_name, "Male", 3, 30)
+        .expect("Failed to create underdog");
+    internal_update_wrestler_power_ratings(&mut conn, favorite.id, Some(10), Some(9), Some(9), Some(10), Some(9), Some(10))
+        .expect("Failed to rate favorite");
+    internal_update_wrestler_power_ratings(&mut conn, underdog.id, Some(2), Some(3), Some(2), Some(1), Some(2), Some(3))
+        .expect("Failed to rate underdog");
+
+    let created = internal_create_match(&mut conn, &singles_match_data(show.id, "Suggestion Match"))
+        .expect("Failed to create match");
+    internal_add_wrestler_to_match(&mut conn, created.id, underdog.id, None, Some(1))
+        .expect("Failed to add underdog");
+    internal_add_wrestler_to_match(&mut conn, created.id, favorite.id, None, Some(2))
+        .expect("Failed to add favorite");
+
+    let suggestion = internal_suggest_match_winner(&mut conn, created.id, Some(7))
+        .expect("Failed to suggest winner");
+    assert_eq!(suggestion.wrestler_id, favorite.id);
+    assert!(suggestion.confidence > 75.0 && suggestion.confidence < 100.0);
+
+    // Purely advisory: no result is recorded
+    let unchanged = internal_get_match_by_id(&mut conn, created.id)
+        .expect("Failed to fetch match")
+        .expect("Match should exist");
+    assert_eq!(unchanged.winner_id, None);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(favorite_name);
+    test_data.cleanup_wrestlers(underdog_name);
+}
+
+#[test]
+#[serial]
+fn test_recent_matches_newest_first_with_unscheduled_last() {
+    let test_data = TestData::new();
+    let show_name = "Recent Matches Show";
+    let winner_name = "Recent Matches Winner";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(winner_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Recent matches testing")
+        .expect("Failed to create show");
+    let winner = internal_create_wrestler(&mut conn, winner_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+
+    let mut unscheduled = singles_match_data(show.id, "Recent Unscheduled");
+    unscheduled.scheduled_date = None;
+    let unscheduled = internal_create_match(&mut conn, &unscheduled).expect("Failed to create match");
+    let mut older = singles_match_data(show.id, "Recent Older");
+    older.scheduled_date = Some("2025-01-06".to_string());
+    let older = internal_create_match(&mut conn, &older).expect("Failed to create match");
+    let mut newer = singles_match_data(show.id, "Recent Newer");
+    newer.scheduled_date = Some("2025-02-03".to_string());
+    let newer = internal_create_match(&mut conn, &newer).expect("Failed to create match");
+
+    internal_add_wrestler_to_match(&mut conn, newer.id, winner.id, None, Some(1))
+        .expect("Failed to add wrestler to match");
+    internal_set_match_winner(&mut conn, newer.id, winner.id).expect("Failed to set winner");
+
+    let recent = internal_get_recent_matches(&mut conn, 10).expect("Failed to load recent matches");
+    let order: Vec<i32> = recent.iter().map(|m| m.match_id).collect();
+    assert_eq!(order, vec![newer.id, older.id, unscheduled.id]);
+    assert_eq!(recent[0].show_name, show_name);
+    assert_eq!(recent[0].winner_name.as_deref(), Some(winner_name));
+    assert_eq!(recent[1].winner_name, None);
+
+    let limited = internal_get_recent_matches(&mut conn, 2).expect("Failed to load recent matches");
+    assert_eq!(limited.len(), 2);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(winner_name);
+}
+
+#[test]
+#[serial]
+fn test_create_rematch_copies_participants_without_result() {
+    let test_data = TestData::new();
+    let first_show_name = "Rematch Original Show";
+    let second_show_name = "Rematch Return Show";
+    let wrestler_names = ["Rematch Team A One", "Rematch Team A Two", "Rematch Team B One", "Rematch Manager"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(first_show_name);
+    test_data.cleanup_shows(second_show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let first_show = internal_create_show(&mut conn, first_show_name, "Original match")
+        .expect("Failed to create show");
+    let second_show = internal_create_show(&mut conn, second_show_name, "Rematch")
+        .expect("Failed to create show");
+
+    let mut source_data = singles_match_data(first_show.id, "Grudge Match");
+    source_data.match_type = "Tag Team".to_string();
+    source_data.match_stipulation = Some("No Disqualification".to_string());
+    let source = internal_create_match(&mut conn, &source_data).expect("Failed to create match");
+
+    let mut wrestler_ids = Vec::new();
+    for (index, name) in wrestler_names.iter().enumerate() {
+        let wrestler = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create wrestler");
+        let team = if index < 2 { Some(1) } else { Some(2) };
+        let participant = internal_add_wrestler_to_match(&mut conn, source.id, wrestler.id, team, Some(index as i32 + 1))
+            .expect("Failed to add wrestler to match");
+        if *name == "Rematch Manager" {
+            internal_set_participant_role(&mut conn, participant.id, "Manager")
+                .expect("Failed to set participant role");
+        }
+        wrestler_ids.push(wrestler.id);
+    }
+    internal_set_match_winner(&mut conn, source.id, wrestler_ids[0]).expect("Failed to set winner");
+
+    let rematch_date = chrono::NaiveDate::from_ymd_opt(2025, 3, 10).expect("Invalid date");
+    let rematch = internal_create_rematch(&mut conn, source.id, second_show.id, Some(rematch_date))
+        .expect("Failed to book rematch");
+    assert_eq!(rematch.show_id, second_show.id);
+    assert_eq!(rematch.match_type, "Tag Team");
+    assert_eq!(rematch.match_stipulation, Some("No Disqualification".to_string()));
+    assert_eq!(rematch.scheduled_date, Some(rematch_date));
+    assert_eq!(rematch.match_order, Some(1));
+    assert_eq!(rematch.winner_id, None);
+
+    let original = internal_get_match_participants(&mut conn, source.id).expect("Failed to load participants");
+    let copied = internal_get_match_participants(&mut conn, rematch.id).expect("Failed to load participants");
+    assert_eq!(copied.len(), original.len());
+    for (copy, source_participant) in copied.iter().zip(original.iter()) {
+        assert_eq!(copy.0.wrestler_id, source_participant.0.wrestler_id);
+        assert_eq!(copy.0.team_number, source_participant.0.team_number);
+        assert_eq!(copy.0.entrance_order, source_participant.0.entrance_order);
+        assert_eq!(copy.0.role, source_participant.0.role);
+    }
+
+    let missing = internal_create_rematch(&mut conn, 99999, second_show.id, None);
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+
+    // Cleanup
+    test_data.cleanup_shows(first_show_name);
+    test_data.cleanup_shows(second_show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_only_one_main_event_per_show() {
+    let test_data = TestData::new();
+    let show_name = "Main Event Show";
+    let other_show_name = "Main Event Other Show";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_shows(other_show_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Main event testing")
+        .expect("Failed to create show");
+    let other_show = internal_create_show(&mut conn, other_show_name, "Main event testing")
+        .expect("Failed to create show");
+    let opener = internal_create_match(&mut conn, &singles_match_data(show.id, "Opener"))
+        .expect("Failed to create match");
+    let closer = internal_create_match(&mut conn, &singles_match_data(show.id, "Closer"))
+        .expect("Failed to create match");
+    let other = internal_create_match(&mut conn, &singles_match_data(other_show.id, "Other Headliner"))
+        .expect("Failed to create match");
+    assert!(!opener.is_main_event);
+
+    internal_set_main_event(&mut conn, other.id).expect("Failed to set main event");
+    internal_set_main_event(&mut conn, opener.id).expect("Failed to set main event");
+    let closer = internal_set_main_event(&mut conn, closer.id).expect("Failed to set main event");
+    assert!(closer.is_main_event);
+
+    let card = internal_get_matches_for_show(&mut conn, show.id).expect("Failed to load matches");
+    let main_events: Vec<i32> = card.iter().filter(|m| m.is_main_event).map(|m| m.id).collect();
+    assert_eq!(main_events, vec![closer.id]);
+
+    // Other shows keep their own main event
+    let other = internal_get_match_by_id(&mut conn, other.id)
+        .expect("Failed to load match")
+        .expect("Match should exist");
+    assert!(other.is_main_event);
+
+    let missing = internal_set_main_event(&mut conn, 99999);
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_shows(other_show_name);
+}
+
+#[test]
+#[serial]
+fn test_win_rate_trend_rolls_over_decided_matches() {
+    let test_data = TestData::new();
+    let show_name = "Trend Show";
+    let wrestler_name = "Trend Wrestler";
+    let opponent_name = "Trend Opponent";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+    test_data.cleanup_wrestlers(opponent_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Trend testing").expect("Failed to create show");
+    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let opponent = internal_create_wrestler(&mut conn, opponent_name, "Male", 0, 0)
+        .expect("Failed to create opponent");
+
+    // Booked out of order to check the chronological sort; the undecided match is skipped
+    let bookings = [
+        ("2025-01-20", Some(opponent.id)),
+        ("2025-01-06", Some(wrestler.id)),
+        ("2025-01-13", Some(wrestler.id)),
+        ("2025-01-27", Some(wrestler.id)),
+        ("2025-02-03", None),
+    ];
+    for (index, (scheduled_date, winner_id)) in bookings.iter().enumerate() {
+        let mut data = singles_match_data(show.id, &format!("Trend Match {}", index));
+        data.scheduled_date = Some(scheduled_date.to_string());
+        let booked = internal_create_match(&mut conn, &data).expect("Failed to create match");
+        internal_add_wrestler_to_match(&mut conn, booked.id, wrestler.id, None, Some(1))
+            .expect("Failed to add wrestler");
+        internal_add_wrestler_to_match(&mut conn, booked.id, opponent.id, None, Some(2))
+            .expect("Failed to add opponent");
+        if let Some(winner_id) = winner_id {
+            internal_set_match_winner(&mut conn, booked.id, *winner_id).expect("Failed to set winner");
+        }
+    }
+
+    // Results in date order: W, W, L, W
+    let trend = internal_get_win_rate_trend(&mut conn, wrestler.id, 2).expect("Failed to compute trend");
+    let dates: Vec<String> = trend.iter().map(|(date, _)| date.format("%Y-%m-%d").to_string()).collect();
+    let rates: Vec<f64> = trend.iter().map(|(_, rate)| *rate).collect();
+    assert_eq!(dates, vec!["2025-01-06", "2025-01-13", "2025-01-20", "2025-01-27"]);
+    assert_eq!(rates, vec![1.0, 1.0, 0.5, 0.5]);
+
+    let full = internal_get_win_rate_trend(&mut conn, wrestler.id, 10).expect("Failed to compute trend");
+    assert_eq!(full.last().map(|(_, rate)| *rate), Some(0.75));
+
+    let invalid = internal_get_win_rate_trend(&mut conn, wrestler.id, 0);
+    assert!(matches!(invalid, Err(AppError::Validation(_))));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+    test_data.cleanup_wrestlers(opponent_name);
+}
+
+#[test]
+#[serial]
+fn test_distinct_match_types_are_sorted_and_unique() {
+    let test_data = TestData::new();
+    let show_name = "Distinct Match Types Show";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Match type testing").expect("Failed to create show");
+    for (index, match_type) in ["Triple Threat", "Singles", "Singles", "Battle Royal"].iter().enumerate() {
+        let mut data = singles_match_data(show.id, &format!("Distinct Type Match {}", index));
+        data.match_type = match_type.to_string();
+        internal_create_match(&mut conn, &data).expect("Failed to create match");
+    }
+
+    let match_types = internal_get_distinct_match_types(&mut conn).expect("Failed to load match types");
+    assert_eq!(match_types, vec!["Battle Royal", "Singles", "Triple Threat"]);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+}
+
+#[test]
+#[serial]
+fn test_title_matches_for_show_skip_orphaned_titles() {
+    let test_data = TestData::new();
+    let show_name = "Title Matches Show";
+    let title_name = "Title Matches Championship";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Title match testing").expect("Failed to create show");
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", Some(show.id), None, false)
+        .expect("Failed to create title");
+
+    internal_create_match(&mut conn, &singles_match_data(show.id, "Non-Title Opener"))
+        .expect("Failed to create match");
+    let mut title_data = singles_match_data(show.id, "Championship Main Event");
+    title_data.match_order = Some(3);
+    title_data.is_title_match = true;
+    title_data.title_id = Some(title.id);
+    let title_match = internal_create_match(&mut conn, &title_data).expect("Failed to create match");
+    let mut orphaned_data = singles_match_data(show.id, "Orphaned Title Match");
+    orphaned_data.match_order = Some(2);
+    orphaned_data.is_title_match = true;
+    orphaned_data.title_id = Some(99999);
+    internal_create_match(&mut conn, &orphaned_data).expect("Failed to create match");
+
+    let title_matches = internal_get_title_matches_for_show(&mut conn, show.id)
+        .expect("Failed to load title matches");
+    assert_eq!(title_matches.len(), 1);
+    assert_eq!(title_matches[0].0.id, title_match.id);
+    assert_eq!(title_matches[0].1, title_name);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+}
+
+#[test]
+#[serial]
+fn test_book_complete_match_is_all_or_nothing() {
+    let test_data = TestData::new();
+    let show_name = "Complete Booking Show";
+    let wrestler1_name = "Complete Booking One";
+    let wrestler2_name = "Complete Booking Two";
+    let outsider_name = "Complete Booking Outsider";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in [wrestler1_name, wrestler2_name, outsider_name] {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Complete booking testing")
+        .expect("Failed to create show");
+    let wrestler1 = internal_create_wrestler(&mut conn, wrestler1_name, "Male", 0, 0)
+        .expect("Failed to create wrestler 1");
+    let wrestler2 = internal_create_wrestler(&mut conn, wrestler2_name, "Male", 0, 0)
+        .expect("Failed to create wrestler 2");
+    let outsider = internal_create_wrestler(&mut conn, outsider_name, "Male", 0, 0)
+        .expect("Failed to create outsider");
+    for wrestler_id in [wrestler1.id, wrestler2.id] {
+        internal_assign_wrestler_to_show(&mut conn, show.id, wrestler_id)
+            .expect("Failed to assign wrestler to show");
+    }
+
+    // An off-roster participant rejects the whole booking
+    let invalid = internal_book_complete_match(
+        &mut conn,
+        &singles_match_data(show.id, "Complete Booking Invalid"),
+        &[(wrestler1.id, None, Some(1)), (outsider.id, None, Some(2))],
+        Some(wrestler1.id),
+    );
+    assert!(matches!(invalid, Err(AppError::Validation(_))));
+
+    // A winner outside the match rolls back the match and participants
+    let wrong_winner = internal_book_complete_match(
+        &mut conn,
+        &singles_match_data(show.id, "Complete Booking Wrong Winner"),
+        &[(wrestler1.id, None, Some(1)), (wrestler2.id, None, Some(2))],
+        Some(outsider.id),
+    );
+    assert!(matches!(wrong_winner, Err(AppError::Validation(_))));
+    assert!(internal_get_matches_for_show(&mut conn, show.id)
+        .expect("Failed to get matches")
+        .is_empty());
+
+    // A repeated participant is rejected before anything is created
+    let repeated = internal_book_complete_match(
+        &mut conn,
+        &singles_match_data(show.id, "Complete Booking Repeated"),
+        &[(wrestler1.id, None, Some(1)), (wrestler1.id, None, Some(2))],
+        None,
+    );
+    assert!(matches!(repeated, Err(AppError::Validation(_))));
+    assert!(internal_get_matches_for_show(&mut conn, show.id)
+        .expect("Failed to get matches")
+        .is_empty());
+
+    let booked = internal_book_complete_match(
+        &mut conn,
+        &singles_match_data(show.id, "Complete Booking Valid"),
+        &[(wrestler1.id, None, Some(1)), (wrestler2.id, None, Some(2))],
+        Some(wrestler2.id),
+    )
+    .expect("Failed to book complete match");
+    assert_eq!(booked.winner_id, Some(wrestler2.id));
+    assert_eq!(
+        internal_get_match_participants(&mut conn, booked.id)
+            .expect("Failed to get participants")
+            .len(),
+        2
+    );
+    let winner = internal_get_wrestler_by_id(&mut conn, wrestler2.id)
+        .expect("Failed to get wrestler")
+        .expect("Wrestler should exist");
+    assert_eq!(winner.wins, 1);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in [wrestler1_name, wrestler2_name, outsider_name] {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_reset_all_records_keeps_match_history() {
+    let test_data = TestData::new();
+    let show_name = "Fresh Season Show";
+    let wrestler1_name = "Fresh Season One";
+    let wrestler2_name = "Fresh Season Two";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(wrestler1_name);
+    test_data.cleanup_wrestlers(wrestler2_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Season reset testing")
+        .expect("Failed to create show");
+    let wrestler1 = internal_create_wrestler(&mut conn, wrestler1_name, "Male", 0, 0)
+        .expect("Failed to create wrestler 1");
+    let wrestler2 = internal_create_wrestler(&mut conn, wrestler2_name, "Male", 0, 0)
+        .expect("Failed to create wrestler 2");
+    let booked = internal_create_match(&mut conn, &singles_match_data(show.id, "Fresh Season Match"))
+        .expect("Failed to create match");
+    internal_add_wrestler_to_match(&mut conn, booked.id, wrestler1.id, None, Some(1))
+        .expect("Failed to add wrestler 1");
+    internal_add_wrestler_to_match(&mut conn, booked.id, wrestler2.id, None, Some(2))
+        .expect("Failed to add wrestler 2");
+    internal_set_match_winner(&mut conn, booked.id, wrestler1.id).expect("Failed to set winner");
+
+    let reset = internal_reset_all_records(&mut conn).expect("Failed to reset records");
+    assert!(reset >= 2);
+
+    for wrestler_id in [wrestler1.id, wrestler2.id] {
+        let wrestler = internal_get_wrestler_by_id(&mut conn, wrestler_id)
+            .expect("Failed to get wrestler")
+            .expect("Wrestler should exist");
+        assert_eq!((wrestler.wins, wrestler.losses), (0, 0));
+    }
+
+    let kept = internal_get_match_by_id(&mut conn, booked.id)
+        .expect("Failed to get match")
+        .expect("Match should still exist");
+    assert_eq!(kept.winner_id, Some(wrestler1.id));
+    assert_eq!(
+        internal_get_match_participants(&mut conn, booked.id)
+            .expect("Failed to get participants")
+            .len(),
+        2
+    );
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(wrestler1_name);
+    test_data.cleanup_wrestlers(wrestler2_name);
+}
+
+#[test]
+#[serial]
+fn test_match_roles_grouped_with_competitors_first() {
+    let test_data = TestData::new();
+    let show_name = "Roles Show";
+    let names = ["Roles Manager", "Roles Second", "Roles First", "Roles Referee"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Role grouping testing")
+        .expect("Failed to create show");
+    let booked = internal_create_match(&mut conn, &singles_match_data(show.id, "Roles Match"))
+        .expect("Failed to create match");
+
+    // Managers and referees are added before the competitors on purpose
+    let mut wrestler_ids = Vec::new();
+    for (name, entrance_order) in names.iter().zip([1, 3, 2, 4]) {
+        let wrestler = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create wrestler");
+        let role = match *name {
+            "Roles Manager" => "Manager",
+            "Roles Referee" => "Referee",
+            _ => "Competitor",
+        };
+        internal_add_participant_with_role(&mut conn, booked.id, wrestler.id, None, Some(entrance_order), role)
+            .expect("Failed to add wrestler to match");
+        wrestler_ids.push(wrestler.id);
+    }
+
+    let roles = internal_get_match_roles(&mut conn, booked.id).expect("Failed to load match roles");
+    let grouped: Vec<(&str, Vec<i32>)> = roles
+        .iter()
+        .map(|(role, wrestlers)| (role.as_str(), wrestlers.iter().map(|w| w.id).collect()))
+        .collect();
+    assert_eq!(
+        grouped,
+        vec![
+            ("Competitor", vec![wrestler_ids[2], wrestler_ids[1]]),
+            ("Manager", vec![wrestler_ids[0]]),
+            ("Referee", vec![wrestler_ids[3]]),
+        ]
+    );
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_triple_threat_rejects_fourth_participant() {
+    let test_data = TestData::new();
+    let show_name = "Capacity Triple Threat Show";
+    let names = ["Capacity One", "Capacity Two", "Capacity Three", "Capacity Four"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Capacity testing")
+        .expect("Failed to create show");
+    let mut match_data = singles_match_data(show.id, "Capacity Triple Threat");
+    match_data.match_type = "Triple Threat".to_string();
+    let booked = internal_create_match(&mut conn, &match_data).expect("Failed to create match");
+
+    for (index, name) in names.iter().enumerate() {
+        let wrestler = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create wrestler");
+        let added = internal_add_wrestler_to_match(&mut conn, booked.id, wrestler.id, None, Some(index as i32 + 1));
+        if index < 3 {
+            added.expect("Failed to add wrestler to match");
+        } else {
+            assert!(matches!(added, Err(AppError::Validation(_))));
+        }
+    }
+
+    assert_eq!(
+        internal_get_match_capacity(&mut conn, booked.id).expect("Failed to fetch capacity"),
+        (3, Some(3))
+    );
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_referee_can_join_full_singles_match() {
+    let test_data = TestData::new();
+    let show_name = "Capacity Referee Show";
+    let names = ["Capacity Referee One", "Capacity Referee Two", "Capacity Referee Official"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Capacity testing")
+        .expect("Failed to create show");
+    let booked = internal_create_match(&mut conn, &singles_match_data(show.id, "Capacity Referee Match"))
+        .expect("Failed to create match");
+    let [first, second, official] = names.map(|name| {
+        internal_create_wrestler(&mut conn, name, "Male", 0, 0).expect("Failed to create wrestler")
+    });
+
+    internal_add_wrestler_to_match(&mut conn, booked.id, first.id, None, Some(1))
+        .expect("Failed to add first competitor");
+    internal_add_wrestler_to_match(&mut conn, booked.id, second.id, None, Some(2))
+        .expect("Failed to add second competitor");
+
+    // Full for competitors, but a referee doesn't take a spot
+    assert!(matches!(
+        internal_add_wrestler_to_match(&mut conn, booked.id, official.id, None, None),
+        Err(AppError::Validation(_))
+    ));
+    let referee = internal_add_participant_with_role(&mut conn, booked.id, official.id, None, None, "Referee")
+        .expect("Failed to add referee");
+    assert_eq!(referee.role, "Referee");
+    assert_eq!(
+        internal_get_match_capacity(&mut conn, booked.id).expect("Failed to fetch capacity"),
+        (2, Some(2))
+    );
+
+    assert!(matches!(
+        internal_add_participant_with_role(&mut conn, booked.id, official.id, None, None, "Spectator"),
+        Err(AppError::Validation(_))
+    ));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_promoting_to_competitor_respects_capacity() {
+    let test_data = TestData::new();
+    let show_name = "Promotion Capacity Show";
+    let names = ["Promotion One", "Promotion Two", "Promotion Three", "Promotion Manager"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Role promotion capacity testing")
+        .expect("Failed to create show");
+    let mut data = singles_match_data(show.id, "Promotion Capacity Match");
+    data.match_type = "Triple Threat".to_string();
+    let booked = internal_create_match(&mut conn, &data).expect("Failed to create match");
+
+    let [one, two, three, manager] = names.map(|name| {
+        internal_create_wrestler(&mut conn, name, "Male", 0, 0).expect("Failed to create wrestler")
+    });
+    for wrestler in [&one, &two, &three] {
+        internal_add_wrestler_to_match(&mut conn, booked.id, wrestler.id, None, None)
+            .expect("Failed to add competitor");
+    }
+    let manager_entry = internal_add_participant_with_role(&mut conn, booked.id, manager.id, None, None, "Manager")
+        .expect("Failed to add manager");
+
+    // The match already has three competitors, so the manager can't become a fourth
+    assert!(matches!(
+        internal_set_participant_role(&mut conn, manager_entry.id, "Competitor"),
+        Err(AppError::Validation(_))
+    ));
+    assert_eq!(
+        internal_get_match_capacity(&mut conn, booked.id).expect("Failed to load capacity"),
+        (3, Some(3))
+    );
+
+    // Switching between non-competing roles is unaffected by the cap
+    let referee = internal_set_participant_role(&mut conn, manager_entry.id, "Referee")
+        .expect("Failed to change role");
+    assert_eq!(referee.role, "Referee");
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_battle_royal_has_no_participant_cap() {
+    let test_data = TestData::new();
+    let show_name = "Capacity Battle Royal Show";
+    let names: Vec<String> = (1..=8).map(|n| format!("Battle Royal Entrant {}", n)).collect();
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in &names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Capacity testing")
+        .expect("Failed to create show");
+    let mut match_data = singles_match_data(show.id, "Capacity Battle Royal");
+    match_data.match_type = "Battle Royal".to_string();
+    let booked = internal_create_match(&mut conn, &match_data).expect("Failed to create match");
+
+    for (index, name) in names.iter().enumerate() {
+        let wrestler = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create wrestler");
+        internal_add_wrestler_to_match(&mut conn, booked.id, wrestler.id, None, Some(index as i32 + 1))
+            .expect("Failed to add wrestler to battle royal");
+    }
+
+    assert_eq!(
+        internal_get_match_capacity(&mut conn, booked.id).expect("Failed to fetch capacity"),
+        (8, None)
+    );
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in &names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_insert_match_at_shifts_later_matches() {
+    let test_data = TestData::new();
+    let show_name = "Insert Position Show";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Insert testing")
+        .expect("Failed to create show");
+
+    let mut booked = Vec::new();
+    for (order, name) in ["Insert Opener", "Insert Midcard", "Insert Main Event"].iter().enumerate() {
+        let mut data = singles_match_data(show.id, name);
+        data.match_order = Some(order as i32 + 1);
+        booked.push(internal_create_match(&mut conn, &data).expect("Failed to create match").id);
+    }
+
+    let inserted = internal_insert_match_at(&mut conn, &singles_match_data(show.id, "Insert Surprise"), 2)
+        .expect("Failed to insert match");
+    assert_eq!(inserted.match_order, Some(2));
+
+    let card: Vec<(i32, Option<i32>)> = internal_get_matches_for_show(&mut conn, show.id)
+        .expect("Failed to load matches")
+        .iter()
+        .map(|m| (m.id, m.match_order))
+        .collect();
+    assert_eq!(
+        card,
+        vec![
+            (booked[0], Some(1)),
+            (inserted.id, Some(2)),
+            (booked[1], Some(3)),
+            (booked[2], Some(4)),
+        ]
+    );
+
+    let invalid = internal_insert_match_at(&mut conn, &singles_match_data(show.id, "Insert Invalid"), 0);
+    assert!(matches!(invalid, Err(AppError::Validation(_))));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+}
+
+#[test]
+fn test_validate_id_rejects_zero_and_negative_ids() {
+    assert!(validate_id(1, "match_id").is_ok());
+
+    let err = validate_id(0, "match_id").unwrap_err();
+    assert!(matches!(err, AppError::Validation(_)));
+    assert_eq!(err.to_string(), "match_id: Invalid id 0");
+
+    assert!(matches!(validate_id(-5, "wrestler_id"), Err(AppError::Validation(_))));
+}
+
+#[test]
+#[serial]
+fn test_count_matches_for_show() {
+    let test_data = TestData::new();
+    let show_names = ["Count Busy Show", "Count Empty Show"];
+
+    // Cleanup any existing test data
+    for name in show_names {
+        test_data.cleanup_shows(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let busy = internal_create_show(&mut conn, show_names[0], "Count testing")
+        .expect("Failed to create show");
+    let empty = internal_create_show(&mut conn, show_names[1], "Count testing")
+        .expect("Failed to create show");
+
+    for name in ["Count Opener", "Count Main Event"] {
+        internal_create_match(&mut conn, &singles_match_data(busy.id, name))
+            .expect("Failed to create match");
+    }
+
+    assert_eq!(internal_count_matches_for_show(&mut conn, busy.id).expect("Failed to count matches"), 2);
+    assert_eq!(internal_count_matches_for_show(&mut conn, empty.id).expect("Failed to count matches"), 0);
+
+    // Cleanup
+    for name in show_names {
+        test_data.cleanup_shows(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_role_change_on_decided_match_retallies_result() {
+    let test_data = TestData::new();
+    let show_name = "Role Retally Show";
+    let wrestler_names = ["Retally Winner", "Retally Loser", "Retally Run-In"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Role retally testing")
+        .expect("Failed to create show");
+    let [winner, loser, run_in] = wrestler_names.map(|name| {
+        internal_create_wrestler(&mut conn, name, "Male", 0, 0).expect("Failed to create wrestler")
+    });
+
+    let mut data = singles_match_data(show.id, "Role Retally Match");
+    data.match_type = "Triple Threat".to_string();
+    let created = internal_create_match(&mut conn, &data).expect("Failed to create match");
+    let winner_entry = internal_add_wrestler_to_match(&mut conn, created.id, winner.id, None, Some(1))
+        .expect("Failed to add winner");
+    internal_add_wrestler_to_match(&mut conn, created.id, loser.id, None, Some(2))
+        .expect("Failed to add loser");
+    let run_in_entry = internal_add_wrestler_to_match(&mut conn, created.id, run_in.id, None, Some(3))
+        .expect("Failed to add run-in");
+    internal_set_match_winner(&mut conn, created.id, winner.id)
+        .expect("Failed to set match winner");
+
+    let record = |conn: &mut _, id| {
+        let wrestler = internal_get_wrestler_by_id(conn, id)
+            .expect("Failed to fetch wrestler")
+            .expect("Wrestler should exist");
+        (wrestler.wins, wrestler.losses)
+    };
+
+    // Demoting a loser takes their loss away
+    internal_set_participant_role(&mut conn, run_in_entry.id, "Interference")
+        .expect("Failed to change role");
+    assert_eq!(record(&mut conn, winner.id), (1, 0));
+    assert_eq!(record(&mut conn, loser.id), (0, 1));
+    assert_eq!(record(&mut conn, run_in.id), (0, 0));
+
+    // The recorded winner can't be demoted
+    assert!(matches!(
+        internal_set_participant_role(&mut conn, winner_entry.id, "Manager"),
+        Err(AppError::Validation(_))
+    ));
+
+    // A later winner change leaves the interferer alone
+    internal_set_match_winner(&mut conn, created.id, loser.id)
+        .expect("Failed to change match winner");
+    assert_eq!(record(&mut conn, winner.id), (0, 1));
+    assert_eq!(record(&mut conn, loser.id), (1, 0));
+    assert_eq!(record(&mut conn, run_in.id), (0, 0));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_no_contest_and_draw_leave_records_untouched() {
+    let test_data = TestData::new();
+    let show_name = "No Contest Show";
+    let wrestler_names = ["No Contest First", "No Contest Second"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "No contest testing")
+        .expect("Failed to create show");
+    let [first, second] = wrestler_names.map(|name| {
+        internal_create_wrestler(&mut conn, name, "Male", 0, 0).expect("Failed to create wrestler")
+    });
+    let booked = internal_create_match(&mut conn, &singles_match_data(show.id, "No Contest Match"))
+        .expect("Failed to create match");
+    for wrestler in [&first, &second] {
+        internal_add_wrestler_to_match(&mut conn, booked.id, wrestler.id, None, None)
+            .expect("Failed to add wrestler to match");
+    }
+    assert_eq!(booked.result_type, None);
+
+    let record = |conn: &mut _, id| {
+        let wrestler = internal_get_wrestler_by_id(conn, id)
+            .expect("Failed to fetch wrestler")
+            .expect("Wrestler should exist");
+        (wrestler.wins, wrestler.losses)
+    };
+
+    // A no-contest is stored as concluded but changes no records
+    let no_contest = internal_set_match_result(&mut conn, booked.id, "NoContest", None)
+        .expect("Failed to record no contest");
+    assert_eq!(no_contest.result_type.as_deref(), Some("NoContest"));
+    assert_eq!(no_contest.winner_id, None);
+    assert_eq!(record(&mut conn, first.id), (0, 0));
+    assert_eq!(record(&mut conn, second.id), (0, 0));
+
+    // A win counts, and turning it into a draw afterwards reverses it
+    let won = internal_set_match_result(&mut conn, booked.id, "Win", Some(first.id))
+        .expect("Failed to record win");
+    assert_eq!(won.result_type.as_deref(), Some("Win"));
+    assert_eq!(record(&mut conn, first.id), (1, 0));
+    assert_eq!(record(&mut conn, second.id), (0, 1));
+
+    let draw = internal_set_match_result(&mut conn, booked.id, "Draw", None)
+        .expect("Failed to record draw");
+    assert_eq!(draw.result_type.as_deref(), Some("Draw"));
+    assert_eq!(draw.winner_id, None);
+    assert_eq!(record(&mut conn, first.id), (0, 0));
+    assert_eq!(record(&mut conn, second.id), (0, 0));
+
+    // Winners only go with wins, and unknown result types are rejected
+    assert!(matches!(
+        internal_set_match_result(&mut conn, booked.id, "Draw", Some(first.id)),
+        Err(AppError::Validation(_))
+    ));
+    assert!(matches!(
+        internal_set_match_result(&mut conn, booked.id, "Win", None),
+        Err(AppError::Validation(_))
+    ));
+    assert!(matches!(
+        internal_set_match_result(&mut conn, booked.id, "Forfeit", None),
+        Err(AppError::Validation(_))
+    ));
+    assert!(matches!(
+        internal_set_match_result(&mut conn, 99999, "NoContest", None),
+        Err(AppError::NotFound(_))
+    ));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
@@ -0,0 +1,222 @@
+use serial_test::serial;
+
+use wwe_universe_manager_lib::db::{
+    internal_assign_show_to_promotion, internal_assign_wrestler_to_show, internal_create_belt,
+    internal_create_promotion, internal_create_show, internal_get_promotion_by_id, internal_create_wrestler,
+    internal_get_promotion_overview, internal_get_wrestlers_by_home_promotion,
+    internal_set_wrestler_home_promotion, internal_get_champions_for_promotion, internal_update_title_holder,
+};
+use wwe_universe_manager_lib::error::AppError;
+
+mod test_helpers;
+use test_helpers::*;
+
+#[test]
+#[serial]
+fn test_promotion_overview_counts_shows_titles_and_roster() {
+    let test_data = TestData::new();
+    let promotion_name = "Overview Promotion";
+    let show_names = ["Overview Monday", "Overview Friday"];
+    let title_name = "Overview Championship";
+    let wrestler_names = ["Overview Raw Wrestler", "Overview Friday Wrestler"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_promotions(promotion_name);
+    for show_name in show_names {
+        test_data.cleanup_shows(show_name);
+    }
+    test_data.cleanup_titles(title_name);
+    for wrestler_name in wrestler_names {
+        test_data.cleanup_wrestlers(wrestler_name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let promotion = internal_create_promotion(&mut conn, promotion_name, Some("Overview testing"))
+        .expect("Failed to create promotion");
+
+    // A promotion without shows still has an overview
+    let empty = internal_get_promotion_overview(&mut conn, promotion.id)
+        .expect("Failed to load empty overview");
+    assert!(empty.shows.is_empty());
+    assert_eq!(empty.title_count, 0);
+    assert_eq!(empty.total_roster, 0);
+
+    let monday = internal_create_show(&mut conn, show_names[0], "Monday show")
+        .expect("Failed to create Monday show");
+    let friday = internal_create_show(&mut conn, show_names[1], "Friday show")
+        .expect("Failed to create Friday show");
+    internal_assign_show_to_promotion(&mut conn, monday.id, Some(promotion.id))
+        .expect("Failed to assign Monday show");
+    internal_assign_show_to_promotion(&mut conn, friday.id, Some(promotion.id))
+        .expect("Failed to assign Friday show");
+
+    internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", Some(monday.id), None, false)
+        .expect("Failed to create title");
+
+    for (show_id, wrestler_name) in [monday.id, friday.id].into_iter().zip(wrestler_names) {
+        let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Male", 0, 0)
+            .expect("Failed to create wrestler");
+        internal_assign_wrestler_to_show(&mut conn, show_id, wrestler.id)
+            .expect("Failed to assign wrestler to show");
+    }
+
+    let overview = internal_get_promotion_overview(&mut conn, promotion.id)
+        .expect("Failed to load overview");
+    assert_eq!(overview.promotion.name, promotion_name);
+    assert_eq!(overview.shows.len(), 2);
+    assert_eq!(overview.title_count, 1);
+    assert_eq!(overview.total_roster, 2);
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    for show_name in show_names {
+        test_data.cleanup_shows(show_name);
+    }
+    for wrestler_name in wrestler_names {
+        test_data.cleanup_wrestlers(wrestler_name);
+    }
+    test_data.cleanup_promotions(promotion_name);
+}
+
+#[test]
+#[serial]
+fn test_wrestler_home_promotion() {
+    let test_data = TestData::new();
+    let promotion_name = "Home Brand Promotion";
+    let wrestler_name = "Home Brand Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_promotions(promotion_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+
+    let mut conn = test_data.get_connection();
+
+    let promotion = internal_create_promotion(&mut conn, promotion_name, None)
+        .expect("Failed to create promotion");
+    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Female", 0, 0)
+        .expect("Failed to create wrestler");
+    assert_eq!(wrestler.home_promotion_id, None);
+
+    assert!(matches!(
+        internal_set_wrestler_home_promotion(&mut conn, wrestler.id, Some(promotion.id + 1000)),
+        Err(AppError::NotFound(_))
+    ));
+
+    let updated = internal_set_wrestler_home_promotion(&mut conn, wrestler.id, Some(promotion.id))
+        .expect("Failed to set home promotion");
+    assert_eq!(updated.home_promotion_id, Some(promotion.id));
+
+    let home_roster = internal_get_wrestlers_by_home_promotion(&mut conn, promotion.id)
+        .expect("Failed to load wrestlers by home promotion");
+    assert_eq!(home_roster.len(), 1);
+    assert_eq!(home_roster[0].name, wrestler_name);
+
+    let cleared = internal_set_wrestler_home_promotion(&mut conn, wrestler.id, None)
+        .expect("Failed to clear home promotion");
+    assert_eq!(cleared.home_promotion_id, None);
+
+    // Cleanup
+    test_data.cleanup_promotions(promotion_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+}
+
+#[test]
+#[serial]
+fn test_champions_for_promotion_with_cross_brand_flag() {
+    let test_data = TestData::new();
+    let promotion_name = "Champions Promotion";
+    let show_name = "Champions Weekly";
+    let other_show_name = "Champions Rival Show";
+    let title_names = ["Champions Brand Title", "Champions Vacant Title", "Champions Open Title", "Champions Rival Title"];
+    let wrestler_names = ["Champions Brand Holder", "Champions Open Holder", "Champions Rival Holder"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_promotions(promotion_name);
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_shows(other_show_name);
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let promotion = internal_create_promotion(&mut conn, promotion_name, None)
+        .expect("Failed to create promotion");
+    let show = internal_create_show(&mut conn, show_name, "Promotion show")
+        .expect("Failed to create show");
+    let other_show = internal_create_show(&mut conn, other_show_name, "Show outside the promotion")
+        .expect("Failed to create show");
+    internal_assign_show_to_promotion(&mut conn, show.id, Some(promotion.id))
+        .expect("Failed to assign show to promotion");
+
+    let brand_title = internal_create_belt(&mut conn, title_names[0], "Singles", "World", "Male", Some(show.id), None, false)
+        .expect("Failed to create title");
+    internal_create_belt(&mut conn, title_names[1], "Singles", "Hardcore", "Male", Some(show.id), None, false)
+        .expect("Failed to create title");
+    let open_title = internal_create_belt(&mut conn, title_names[2], "Singles", "Speed", "Male", None, None, false)
+        .expect("Failed to create title");
+    let rival_title = internal_create_belt(&mut conn, title_names[3], "Singles", "World", "Male", Some(other_show.id), None, false)
+        .expect("Failed to create title");
+
+    for (title_id, name) in [(brand_title.id, wrestler_names[0]), (open_title.id, wrestler_names[1]), (rival_title.id, wrestler_names[2])] {
+        let wrestler = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create wrestler");
+        internal_update_title_holder(&mut conn, title_id, wrestler.id, None, None, None)
+            .expect("Failed to crown champion");
+    }
+
+    // The vacant title and the other show's title are never listed
+    let brand_only = internal_get_champions_for_promotion(&mut conn, promotion.id, false)
+        .expect("Failed to load champions");
+    assert_eq!(brand_only.len(), 1);
+    assert_eq!(brand_only[0].0.id, brand_title.id);
+    assert_eq!(brand_only[0].1[0].wrestler_name, wrestler_names[0]);
+
+    let with_cross_brand = internal_get_champions_for_promotion(&mut conn, promotion.id, true)
+        .expect("Failed to load champions");
+    let title_ids: Vec<i32> = with_cross_brand.iter().map(|(title, _)| title.id).collect();
+    assert_eq!(title_ids, vec![brand_title.id, open_title.id]);
+
+    assert!(internal_get_champions_for_promotion(&mut conn, 99999, false).is_err());
+
+    // Cleanup
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_shows(other_show_name);
+    test_data.cleanup_promotions(promotion_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_get_promotion_by_id() {
+    let test_data = TestData::new();
+    let promotion_name = "Lookup Promotion";
+
+    // Cleanup any existing test data
+    test_data.cleanup_promotions(promotion_name);
+
+    let mut conn = test_data.get_connection();
+    let promotion = internal_create_promotion(&mut conn, promotion_name, Some("Lookup testing"))
+        .expect("Failed to create promotion");
+
+    let found = internal_get_promotion_by_id(&mut conn, promotion.id)
+        .expect("Failed to fetch promotion")
+        .expect("Promotion should exist");
+    assert_eq!(found.name, promotion_name);
+    assert_eq!(found.description.as_deref(), Some("Lookup testing"));
+
+    let missing = internal_get_promotion_by_id(&mut conn, promotion.id + 1000)
+        .expect("Failed to fetch promotion");
+    assert!(missing.is_none());
+
+    // Cleanup
+    test_data.cleanup_promotions(promotion_name);
+}
@@ -0,0 +1,231 @@
+use diesel::prelude::*;
+use serial_test::serial;
+
+use wwe_universe_manager_lib::db::{
+    internal_assign_wrestler_to_show, internal_create_belt, internal_create_show,
+    internal_create_wrestler, internal_generate_report, internal_update_title_holder,
+    internal_add_wrestler_to_match, internal_create_match, internal_export_show_card_markdown,
+    internal_set_match_winner, internal_validate_universe, internal_add_participant_with_role,
+};
+use wwe_universe_manager_lib::models::{MatchData, NewTitleHolder};
+use wwe_universe_manager_lib::schema::title_holders;
+
+mod test_helpers;
+use test_helpers::*;
+
+#[test]
+#[serial]
+fn test_report_lists_shows_champions_and_title_changes() {
+    let test_data = TestData::new();
+    let show_name = "Report Show";
+    let title_name = "Report Championship";
+    let champ_name = "Report Champion";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(champ_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Report testing")
+        .expect("Failed to create show");
+    let champ = internal_create_wrestler(&mut conn, champ_name, "Male", 0, 0)
+        .expect("Failed to create champion");
+    internal_assign_wrestler_to_show(&mut conn, show.id, champ.id)
+        .expect("Failed to assign champion to show");
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", Some(show.id), None, false)
+        .expect("Failed to create title");
+    internal_update_title_holder(&mut conn, title.id, champ.id, Some("Report Night"), None, Some("Pinfall"))
+        .expect("Failed to crown champion");
+
+    let report = internal_generate_report(&mut conn).expect("Failed to generate report");
+
+    assert!(report.starts_with("# Universe Report"));
+    assert!(report.contains(&format!("- {}", show_name)));
+    assert!(report.contains(&format!("- **{}**: {}", title_name, champ_name)));
+    assert!(report.contains(&format!("- {}: 1 wrestlers", show_name)));
+    assert!(report.contains(&format!("{} won the {} at Report Night (Pinfall)", champ_name, title_name)));
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(champ_name);
+}
+
+#[test]
+#[serial]
+fn test_show_card_markdown_lists_matches_and_participants() {
+    let test_data = TestData::new();
+    let show_name = "Card Export Show";
+    let title_name = "Card Export Championship";
+    let wrestler_names = ["Card Export Champion", "Card Export Challenger"];
+    let referee_name = "Card Export Referee";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+    test_data.cleanup_wrestlers(referee_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Shareable card testing")
+        .expect("Failed to create show");
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", Some(show.id), None, false)
+        .expect("Failed to create title");
+
+    let booked = internal_create_match(&mut conn, &MatchData {
+        show_id: show.id,
+        match_name: Some("Card Export Main Event".to_string()),
+        match_type: "Singles".to_string(),
+        match_stipulation: Some("Street Fight".to_string()),
+        scheduled_date: None,
+        match_order: Some(1),
+        is_title_match: true,
+        title_id: Some(title.id),
+    })
+    .expect("Failed to create match");
+    let mut wrestler_ids = Vec::new();
+    for (index, name) in wrestler_names.iter().enumerate() {
+        let wrestler = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create wrestler");
+        internal_add_wrestler_to_match(&mut conn, booked.id, wrestler.id, None, Some(index as i32 + 1))
+            .expect("Failed to add wrestler to match");
+        wrestler_ids.push(wrestler.id);
+    }
+    let referee = internal_create_wrestler(&mut conn, referee_name, "Male", 0, 0)
+        .expect("Failed to create referee");
+    internal_add_participant_with_role(&mut conn, booked.id, referee.id, None, Some(3), "Referee")
+        .expect("Failed to add referee to match");
+    internal_set_match_winner(&mut conn, booked.id, wrestler_ids[0]).expect("Failed to set winner");
+
+    let card = internal_export_show_card_markdown(&mut conn, show.id).expect("Failed to export card");
+    assert!(card.starts_with(&format!("# {}", show_name)));
+    assert!(card.contains("Card Export Main Event"));
+    assert!(card.contains("Card Export Champion, Card Export Challenger"));
+    assert!(card.contains("Street Fight"));
+    assert!(card.contains(title_name));
+    assert!(card.contains("Card Export Champion wins"));
+    // Only competitors are listed as participants
+    assert!(card.contains("- **Participants:** Card Export Champion, Card Export Challenger\n"));
+    assert!(!card.contains(referee_name));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+    test_data.cleanup_wrestlers(referee_name);
+}
+
+#[test]
+#[serial]
+fn test_validate_universe_reports_each_category() {
+    let test_data = TestData::new();
+    let show_name = "Health Check Show";
+    let title_name = "Health Check Championship";
+    let names = ["Health Busy", "Health Opponent", "Health Bench", "Health Drifted"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let clean = internal_validate_universe(&mut conn).expect("Failed to validate universe");
+    assert_eq!(clean.orphaned_title_holders.count, 0);
+    assert_eq!(clean.drifted_records.count, 0);
+
+    let show = internal_create_show(&mut conn, show_name, "Health testing")
+        .expect("Failed to create show");
+    let busy = internal_create_wrestler(&mut conn, names[0], "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let opponent = internal_create_wrestler(&mut conn, names[1], "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let bench = internal_create_wrestler(&mut conn, names[2], "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    // Claims a win that no match backs up
+    let drifted = internal_create_wrestler(&mut conn, names[3], "Male", 5, 0)
+        .expect("Failed to create wrestler");
+    internal_assign_wrestler_to_show(&mut conn, show.id, bench.id)
+        .expect("Failed to assign wrestler");
+
+    // The busy wrestler is booked twice on a single-match show
+    for match_name in ["Health Opener", "Health Main Event"] {
+        let booked = internal_create_match(&mut conn, &MatchData {
+            show_id: show.id,
+            match_name: Some(match_name.to_string()),
+            match_type: "Singles".to_string(),
+            match_stipulation: None,
+            scheduled_date: None,
+            match_order: None,
+            is_title_match: false,
+            title_id: None,
+        })
+        .expect("Failed to create match");
+        internal_add_wrestler_to_match(&mut conn, booked.id, busy.id, None, Some(1))
+            .expect("Failed to add wrestler to match");
+        internal_add_wrestler_to_match(&mut conn, booked.id, opponent.id, None, Some(2))
+            .expect("Failed to add wrestler to match");
+        if match_name == "Health Main Event" {
+            internal_set_match_winner(&mut conn, booked.id, busy.id)
+                .expect("Failed to set match winner");
+        }
+    }
+
+    // Two active reigns on a singles title, one of them for a wrestler that no longer exists
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    let now = chrono::Utc::now().naive_utc();
+    let orphan_id = diesel::insert_into(title_holders::table)
+        .values(&vec![
+            NewTitleHolder {
+                title_id: title.id,
+                wrestler_id: busy.id,
+                held_since: now,
+                event_name: None,
+                event_location: None,
+                change_method: None,
+            },
+            NewTitleHolder {
+                title_id: title.id,
+                wrestler_id: drifted.id + 1000,
+                held_since: now,
+                event_name: None,
+                event_location: None,
+                change_method: None,
+            },
+        ])
+        .execute(&mut conn)
+        .and_then(|_| {
+            title_holders::table
+                .filter(title_holders::wrestler_id.eq(drifted.id + 1000))
+                .select(title_holders::id)
+                .first::<i32>(&mut conn)
+        })
+        .expect("Failed to insert reigns");
+
+    let report = internal_validate_universe(&mut conn).expect("Failed to validate universe");
+    assert_eq!(report.orphaned_title_holders.count, 1);
+    assert_eq!(report.orphaned_title_holders.sample_ids, vec![orphan_id]);
+    assert_eq!(report.double_reigns.sample_ids, vec![title.id]);
+    assert_eq!(report.benchwarmers.sample_ids, vec![bench.id]);
+    assert_eq!(report.booking_conflicts.sample_ids, vec![busy.id, opponent.id]);
+    assert_eq!(report.drifted_records.sample_ids, vec![drifted.id]);
+
+    // Cleanup
+    diesel::delete(title_holders::table.filter(title_holders::title_id.eq(title.id)))
+        .execute(&mut conn)
+        .ok();
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
@@ -0,0 +1,70 @@
+use serial_test::serial;
+
+use wwe_universe_manager_lib::db::{
+    internal_get_roster_mode, internal_get_setting, internal_get_setting_bool, internal_get_setting_i32,
+    internal_set_setting,
+};
+use wwe_universe_manager_lib::error::AppError;
+
+mod test_helpers;
+use test_helpers::*;
+
+#[test]
+#[serial]
+fn test_setting_round_trip() {
+    let test_data = TestData::new();
+    let setting_key = "test_round_trip";
+
+    // Cleanup any existing test data
+    test_data.cleanup_settings(setting_key);
+
+    let mut conn = test_data.get_connection();
+    let stored = internal_set_setting(&mut conn, setting_key, "first").expect("Failed to store setting");
+    assert_eq!(stored.key, setting_key);
+    assert_eq!(stored.value, "first");
+
+    // Setting an existing key replaces its value
+    internal_set_setting(&mut conn, setting_key, "second").expect("Failed to replace setting");
+    let value = internal_get_setting(&mut conn, setting_key).expect("Failed to load setting");
+    assert_eq!(value, Some("second".to_string()));
+
+    let empty_key = internal_set_setting(&mut conn, "  ", "value");
+    assert!(matches!(empty_key, Err(AppError::Validation(_))));
+
+    // Cleanup
+    test_data.cleanup_settings(setting_key);
+}
+
+#[test]
+#[serial]
+fn test_typed_settings_and_missing_key_defaults() {
+    let test_data = TestData::new();
+    let bool_key = "test_bool_setting";
+    let int_key = "test_int_setting";
+
+    // Cleanup any existing test data
+    test_data.cleanup_settings(bool_key);
+    test_data.cleanup_settings(int_key);
+
+    let mut conn = test_data.get_connection();
+
+    // Missing keys fall back to the defaults
+    assert_eq!(internal_get_setting(&mut conn, bool_key).expect("Failed to load setting"), None);
+    assert!(internal_get_setting_bool(&mut conn, bool_key, true).expect("Failed to load bool"));
+    assert_eq!(internal_get_setting_i32(&mut conn, int_key, 12).expect("Failed to load integer"), 12);
+    assert_eq!(internal_get_roster_mode(&mut conn).expect("Failed to load roster mode"), "exclusive");
+
+    internal_set_setting(&mut conn, bool_key, "false").expect("Failed to store setting");
+    internal_set_setting(&mut conn, int_key, "19").expect("Failed to store setting");
+    assert!(!internal_get_setting_bool(&mut conn, bool_key, true).expect("Failed to load bool"));
+    assert_eq!(internal_get_setting_i32(&mut conn, int_key, 12).expect("Failed to load integer"), 19);
+
+    // Stored values of the wrong type are reported rather than replaced by the default
+    internal_set_setting(&mut conn, int_key, "nineteen").expect("Failed to store setting");
+    let invalid = internal_get_setting_i32(&mut conn, int_key, 12);
+    assert!(matches!(invalid, Err(AppError::Validation(_))));
+
+    // Cleanup
+    test_data.cleanup_settings(bool_key);
+    test_data.cleanup_settings(int_key);
+}
@@ -1,6 +1,9 @@
 use serial_test::serial;
 
-use wwe_universe_manager_lib::db::{internal_create_show, internal_get_shows, internal_get_wrestlers_for_show};
+use chrono::{Duration, Utc};
+use wwe_universe_manager_lib::db::{internal_assign_wrestler_to_show, internal_create_belt, internal_create_match, internal_create_show, internal_create_show_with_details, internal_create_wrestler, internal_get_show_by_id, internal_get_show_roster_detailed, internal_get_roster_balance, internal_get_wrestler_rank_in_show, internal_get_shows, internal_get_shows_with_upcoming_matches, internal_get_roster_mode, internal_set_roster_mode, internal_get_shows_for_wrestler, internal_update_show_metrics, internal_get_average_show_rating, internal_get_wrestlers_for_show, internal_remove_wrestler_from_show, internal_restore_roster_assignment, internal_update_show, internal_update_title_holder};
+use wwe_universe_manager_lib::error::AppError;
+use wwe_universe_manager_lib::models::{MatchData, ShowData};
 
 mod test_helpers;
 use test_helpers::*;
@@ -212,7 +215,7 @@ fn test_get_shows_for_wrestler() {
         .expect("Failed to create show 1");
     let show2 = internal_create_show(&mut conn, show2_name, show2_desc)
         .expect("Failed to create show 2");
-    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Mixed", 0, 0)
+    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Other", 0, 0)
         .expect("Failed to create wrestler");
 
     // Initially, wrestler should not be assigned to any shows
@@ -253,3 +256,472 @@ fn test_get_shows_for_wrestler() {
     test_data.cleanup_shows(show2_name);
     test_data.cleanup_wrestlers(wrestler_name);
 }
+
+#[test]
+#[serial]
+fn test_update_show_details() {
+    let test_data = TestData::new();
+    let show_name = "Details Show";
+    let updated_name = "Updated Details Show";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_shows(updated_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Original description")
+        .expect("Failed to create show");
+    assert_eq!(show.venue, None);
+    assert_eq!(show.show_day, None);
+
+    let update = ShowData {
+        name: updated_name.to_string(),
+        description: "Updated description".to_string(),
+        venue: Some("Madison Square Garden".to_string()),
+        show_day: Some("Monday".to_string()),
+    };
+    internal_update_show(&mut conn, show.id, &update).expect("Failed to update show");
+
+    let stored = internal_get_show_by_id(&mut conn, show.id)
+        .expect("Failed to fetch show")
+        .expect("Show should exist");
+    assert_eq!(stored.name, updated_name);
+    assert_eq!(stored.description, "Updated description");
+    assert_eq!(stored.venue, Some("Madison Square Garden".to_string()));
+    assert_eq!(stored.show_day, Some("Monday".to_string()));
+
+    // Unknown IDs return None rather than an error
+    let missing = internal_get_show_by_id(&mut conn, 99999).expect("Failed to query show");
+    assert!(missing.is_none());
+
+    // Cleanup
+    test_data.cleanup_shows(updated_name);
+}
+
+#[test]
+#[serial]
+fn test_create_show_with_details_stores_venue_and_day() {
+    let test_data = TestData::new();
+    let show_name = "Venue Show";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show_data = ShowData {
+        name: show_name.to_string(),
+        description: "Show created with a venue".to_string(),
+        venue: Some("Allstate Arena".to_string()),
+        show_day: Some("Friday".to_string()),
+    };
+    let show = internal_create_show_with_details(&mut conn, &show_data)
+        .expect("Failed to create show");
+
+    let stored = internal_get_show_by_id(&mut conn, show.id)
+        .expect("Failed to fetch show")
+        .expect("Show should exist");
+    assert_eq!(stored.name, show_name);
+    assert_eq!(stored.venue, Some("Allstate Arena".to_string()));
+    assert_eq!(stored.show_day, Some("Friday".to_string()));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+}
+
+#[test]
+#[serial]
+fn test_show_roster_detailed_includes_record_and_champion_flag() {
+    let test_data = TestData::new();
+    let show_name = "Detailed Roster Show";
+    let title_name = "Detailed Roster Championship";
+    let champ_name = "Detailed Roster Champion";
+    let challenger_name = "Detailed Roster Challenger";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(champ_name);
+    test_data.cleanup_wrestlers(challenger_name);
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Detailed roster testing")
+        .expect("Failed to create show");
+    let champ = internal_create_wrestler(&mut conn, champ_name, "Male", 12, 3)
+        .expect("Failed to create champion");
+    let challenger = internal_create_wrestler(&mut conn, challenger_name, "Male", 4, 9)
+        .expect("Failed to create challenger");
+    internal_assign_wrestler_to_show(&mut conn, show.id, champ.id)
+        .expect("Failed to assign champion");
+    internal_assign_wrestler_to_show(&mut conn, show.id, challenger.id)
+        .expect("Failed to assign challenger");
+
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", Some(show.id), None, false)
+        .expect("Failed to create title");
+    internal_update_title_holder(&mut conn, title.id, champ.id, None, None, None)
+        .expect("Failed to crown champion");
+
+    let roster = internal_get_show_roster_detailed(&mut conn, show.id)
+        .expect("Failed to load detailed roster");
+    assert_eq!(roster.len(), 2);
+
+    // Ordered by name: "Detailed Roster Challenger" before "Detailed Roster Champion"
+    assert_eq!(roster[0].wrestler.id, challenger.id);
+    assert_eq!((roster[0].wins, roster[0].losses), (4, 9));
+    assert!(!roster[0].is_champion);
+    assert_eq!(roster[1].wrestler.id, champ.id);
+    assert_eq!((roster[1].wins, roster[1].losses), (12, 3));
+    assert!(roster[1].is_champion);
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(champ_name);
+    test_data.cleanup_wrestlers(challenger_name);
+}
+
+#[test]
+#[serial]
+fn test_shows_with_upcoming_matches() {
+    let test_data = TestData::new();
+    let busy_show_name = "Upcoming Busy Show";
+    let past_show_name = "Upcoming Past Show";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(busy_show_name);
+    test_data.cleanup_shows(past_show_name);
+
+    let mut conn = test_data.get_connection();
+    let busy_show = internal_create_show(&mut conn, busy_show_name, "Has upcoming matches")
+        .expect("Failed to create show");
+    let past_show = internal_create_show(&mut conn, past_show_name, "Only past and unscheduled matches")
+        .expect("Failed to create show");
+
+    let today = Utc::now().date_naive();
+    let bookings = [
+        (busy_show.id, Some(today)),
+        (busy_show.id, Some(today + Duration::days(7))),
+        (busy_show.id, Some(today - Duration::days(7))),
+        (past_show.id, Some(today - Duration::days(1))),
+        (past_show.id, None),
+    ];
+    for (index, (show_id, scheduled_date)) in bookings.into_iter().enumerate() {
+        internal_create_match(&mut conn, &MatchData {
+            show_id,
+            match_name: Some(format!("Upcoming Match {}", index)),
+            match_type: "Singles".to_string(),
+            match_stipulation: None,
+            scheduled_date: scheduled_date.map(|date| date.format("%Y-%m-%d").to_string()),
+            match_order: Some(index as i32),
+            is_title_match: false,
+            title_id: None,
+        }).expect("Failed to create match");
+    }
+
+    let upcoming = internal_get_shows_with_upcoming_matches(&mut conn, today)
+        .expect("Failed to load shows with upcoming matches");
+    assert_eq!(upcoming.len(), 1);
+    assert_eq!(upcoming[0].0.id, busy_show.id);
+    assert_eq!(upcoming[0].1, 2);
+
+    // Cleanup
+    test_data.cleanup_shows(busy_show_name);
+    test_data.cleanup_shows(past_show_name);
+}
+
+#[test]
+#[serial]
+fn test_restore_roster_assignment_keeps_other_shows() {
+    let test_data = TestData::new();
+    let first_show_name = "Restore First Show";
+    let second_show_name = "Restore Second Show";
+    let wrestler_name = "Restore Roster Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(first_show_name);
+    test_data.cleanup_shows(second_show_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+
+    let mut conn = test_data.get_connection();
+    let first_show = internal_create_show(&mut conn, first_show_name, "Original roster")
+        .expect("Failed to create show");
+    let second_show = internal_create_show(&mut conn, second_show_name, "New roster")
+        .expect("Failed to create show");
+    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+
+    // Never assigned to the second show, so there is nothing to restore
+    let missing = internal_restore_roster_assignment(&mut conn, second_show.id, wrestler.id);
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+
+    // Transferring deactivates the first assignment
+    internal_assign_wrestler_to_show(&mut conn, first_show.id, wrestler.id)
+        .expect("Failed to assign wrestler");
+    internal_assign_wrestler_to_show(&mut conn, second_show.id, wrestler.id)
+        .expect("Failed to transfer wrestler");
+
+    let restored = internal_restore_roster_assignment(&mut conn, first_show.id, wrestler.id)
+        .expect("Failed to restore assignment");
+    assert!(restored.is_active);
+    assert_eq!(restored.show_id, first_show.id);
+
+    // Both rosters now include the wrestler
+    for show_id in [first_show.id, second_show.id] {
+        let roster = internal_get_wrestlers_for_show(&mut conn, show_id)
+            .expect("Failed to load roster");
+        assert!(roster.iter().any(|w| w.id == wrestler.id));
+    }
+
+    // Restoring an active assignment is a no-op
+    internal_remove_wrestler_from_show(&mut conn, second_show.id, wrestler.id)
+        .expect("Failed to remove wrestler");
+    let again = internal_restore_roster_assignment(&mut conn, first_show.id, wrestler.id)
+        .expect("Failed to restore assignment");
+    assert_eq!(again.id, restored.id);
+
+    // Cleanup
+    test_data.cleanup_shows(first_show_name);
+    test_data.cleanup_shows(second_show_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+}
+
+#[test]
+#[serial]
+fn test_roster_mode_controls_exclusive_assignment() {
+    let test_data = TestData::new();
+    let first_show_name = "Roster Mode First Show";
+    let second_show_name = "Roster Mode Second Show";
+    let exclusive_name = "Roster Mode Exclusive Wrestler";
+    let shared_name = "Roster Mode Shared Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(first_show_name);
+    test_data.cleanup_shows(second_show_name);
+    test_data.cleanup_wrestlers(exclusive_name);
+    test_data.cleanup_wrestlers(shared_name);
+
+    let mut conn = test_data.get_connection();
+    let first_show = internal_create_show(&mut conn, first_show_name, "Roster mode testing")
+        .expect("Failed to create show");
+    let second_show = internal_create_show(&mut conn, second_show_name, "Roster mode testing")
+        .expect("Failed to create show");
+    let exclusive = internal_create_wrestler(&mut conn, exclusive_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let shared = internal_create_wrestler(&mut conn, shared_name, "Female", 0, 0)
+        .expect("Failed to create wrestler");
+
+    // Exclusive is the default and transfers the wrestler
+    assert_eq!(internal_get_roster_mode(&mut conn).expect("Failed to get roster mode"), "exclusive");
+    internal_assign_wrestler_to_show(&mut conn, first_show.id, exclusive.id)
+        .expect("Failed to assign wrestler");
+    internal_assign_wrestler_to_show(&mut conn, second_show.id, exclusive.id)
+        .expect("Failed to transfer wrestler");
+    let exclusive_shows = internal_get_shows_for_wrestler(&mut conn, exclusive.id)
+        .expect("Failed to get shows for wrestler");
+    assert_eq!(exclusive_shows.len(), 1);
+    assert_eq!(exclusive_shows[0].id, second_show.id);
+
+    // Multi mode keeps the existing assignment
+    internal_set_roster_mode(&mut conn, "multi").expect("Failed to set roster mode");
+    assert_eq!(internal_get_roster_mode(&mut conn).expect("Failed to get roster mode"), "multi");
+    internal_assign_wrestler_to_show(&mut conn, first_show.id, shared.id)
+        .expect("Failed to assign wrestler");
+    internal_assign_wrestler_to_show(&mut conn, second_show.id, shared.id)
+        .expect("Failed to assign wrestler to second show");
+    let shared_shows = internal_get_shows_for_wrestler(&mut conn, shared.id)
+        .expect("Failed to get shows for wrestler");
+    assert_eq!(shared_shows.len(), 2);
+
+    let invalid = internal_set_roster_mode(&mut conn, "shared");
+    assert!(matches!(invalid, Err(AppError::Validation(_))));
+    assert_eq!(internal_get_roster_mode(&mut conn).expect("Failed to get roster mode"), "multi");
+
+    // Cleanup
+    test_data.cleanup_shows(first_show_name);
+    test_data.cleanup_shows(second_show_name);
+    test_data.cleanup_wrestlers(exclusive_name);
+    test_data.cleanup_wrestlers(shared_name);
+}
+
+#[test]
+#[serial]
+fn test_show_metrics_validation_boundaries() {
+    let test_data = TestData::new();
+    let show_name = "Metrics Weekly";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Metrics testing").expect("Failed to create show");
+
+    // Both ends of the rating range are accepted
+    for rating in [0.0, 5.0] {
+        let updated = internal_update_show_metrics(&mut conn, show.id, Some(0), Some(rating))
+            .expect("Failed to update show metrics");
+        assert_eq!(updated.show_rating, Some(rating));
+        assert_eq!(updated.attendance, Some(0));
+    }
+
+    for rating in [-0.1, 5.1] {
+        let result = internal_update_show_metrics(&mut conn, show.id, None, Some(rating));
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+    let negative = internal_update_show_metrics(&mut conn, show.id, Some(-1), None);
+    assert!(matches!(negative, Err(AppError::Validation(_))));
+
+    let missing = internal_update_show_metrics(&mut conn, 99999, None, Some(3.0));
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+
+    // Clearing the metrics
+    let cleared = internal_update_show_metrics(&mut conn, show.id, None, None)
+        .expect("Failed to clear show metrics");
+    assert_eq!(cleared.show_rating, None);
+    assert_eq!(cleared.attendance, None);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+}
+
+#[test]
+#[serial]
+fn test_average_show_rating_across_series() {
+    let test_data = TestData::new();
+    let series_name = "Metrics Series";
+    let other_name = "Metrics Other Series";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(series_name);
+    test_data.cleanup_shows(other_name);
+
+    let mut conn = test_data.get_connection();
+    let mut episodes = Vec::new();
+    for (index, rating) in [Some(3.0), Some(4.5), None].into_iter().enumerate() {
+        let episode = internal_create_show(&mut conn, series_name, &format!("Episode {}", index))
+            .expect("Failed to create show");
+        internal_update_show_metrics(&mut conn, episode.id, Some(10000), rating)
+            .expect("Failed to update show metrics");
+        episodes.push(episode);
+    }
+    let other = internal_create_show(&mut conn, other_name, "Different program")
+        .expect("Failed to create show");
+    internal_update_show_metrics(&mut conn, other.id, None, Some(1.0)).expect("Failed to update show metrics");
+
+    let average = internal_get_average_show_rating(&mut conn, episodes[2].id)
+        .expect("Failed to average show rating");
+    assert_eq!(average, Some(3.75));
+
+    let unrated = internal_create_show(&mut conn, "Metrics Unrated Series", "Never rated")
+        .expect("Failed to create show");
+    assert_eq!(internal_get_average_show_rating(&mut conn, unrated.id).expect("Failed to average show rating"), None);
+
+    // Cleanup
+    test_data.cleanup_shows(series_name);
+    test_data.cleanup_shows(other_name);
+    test_data.cleanup_shows("Metrics Unrated Series");
+}
+
+#[test]
+#[serial]
+fn test_roster_balance_counts_active_roster_by_gender() {
+    let test_data = TestData::new();
+    let show_name = "Balance Show";
+    let roster = [
+        ("Balance Male One", "Male"),
+        ("Balance Male Two", "Male"),
+        ("Balance Female", "Female"),
+        ("Balance Released", "Female"),
+    ];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for (name, _) in roster {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Roster balance testing")
+        .expect("Failed to create show");
+    for (name, gender) in roster {
+        let wrestler = internal_create_wrestler(&mut conn, name, gender, 0, 0)
+            .expect("Failed to create wrestler");
+        internal_assign_wrestler_to_show(&mut conn, show.id, wrestler.id)
+            .expect("Failed to assign wrestler to show");
+        if name == "Balance Released" {
+            internal_remove_wrestler_from_show(&mut conn, show.id, wrestler.id)
+                .expect("Failed to remove wrestler from show");
+        }
+    }
+
+    let balance = internal_get_roster_balance(&mut conn, show.id)
+        .expect("Failed to load roster balance");
+    assert_eq!(balance.show_id, show.id);
+    assert_eq!(balance.total, 3);
+    assert_eq!(
+        balance.gender_split,
+        vec![("Female".to_string(), 1), ("Male".to_string(), 2)]
+    );
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for (name, _) in roster {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_wrestler_rank_in_show_by_win_rate() {
+    let test_data = TestData::new();
+    let show_name = "Rank Show";
+    // (name, wins, losses)
+    let roster = [
+        ("Rank Contender", 6, 4),
+        ("Rank Ace", 9, 1),
+        ("Rank Tied", 3, 2),
+        ("Rank Rookie", 0, 0),
+    ];
+    let outsider_name = "Rank Outsider";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for (name, _, _) in roster {
+        test_data.cleanup_wrestlers(name);
+    }
+    test_data.cleanup_wrestlers(outsider_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Roster ranking testing")
+        .expect("Failed to create show");
+    let mut ids = Vec::new();
+    for (name, wins, losses) in roster {
+        let wrestler = internal_create_wrestler(&mut conn, name, "Male", wins, losses)
+            .expect("Failed to create wrestler");
+        internal_assign_wrestler_to_show(&mut conn, show.id, wrestler.id)
+            .expect("Failed to assign wrestler to show");
+        ids.push(wrestler.id);
+    }
+    let outsider = internal_create_wrestler(&mut conn, outsider_name, "Male", 10, 0)
+        .expect("Failed to create outsider");
+
+    let rank_of = |conn: &mut _, wrestler_id| {
+        internal_get_wrestler_rank_in_show(conn, show.id, wrestler_id)
+            .expect("Failed to rank wrestler")
+            .map(|rank| (rank.position, rank.total))
+    };
+    assert_eq!(rank_of(&mut conn, ids[1]), Some((1, 4)));
+    // 60% ties with 60%, so both are the #2 contender
+    assert_eq!(rank_of(&mut conn, ids[0]), Some((2, 4)));
+    assert_eq!(rank_of(&mut conn, ids[2]), Some((2, 4)));
+    assert_eq!(rank_of(&mut conn, ids[3]), Some((4, 4)));
+    assert_eq!(rank_of(&mut conn, outsider.id), None);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for (name, _, _) in roster {
+        test_data.cleanup_wrestlers(name);
+    }
+    test_data.cleanup_wrestlers(outsider_name);
+}
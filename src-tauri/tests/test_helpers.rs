@@ -95,7 +95,9 @@ fn run_consolidated_migrations(conn: &mut SqliteConnection) {
             biography TEXT,
             is_user_created BOOLEAN DEFAULT FALSE,
             created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
-            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            status TEXT NOT NULL DEFAULT 'Active',
+            home_promotion_id INTEGER NULL
         )
     "#).execute(conn).expect("Failed to create wrestlers table");
 
@@ -106,7 +108,8 @@ fn run_consolidated_migrations(conn: &mut SqliteConnection) {
             move_name TEXT NOT NULL,
             move_type TEXT NOT NULL,
             created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
-            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            is_active BOOLEAN NOT NULL DEFAULT TRUE
         )
     "#).execute(conn).expect("Failed to create signature_moves table");
 
@@ -120,7 +123,13 @@ fn run_consolidated_migrations(conn: &mut SqliteConnection) {
             name TEXT NOT NULL,
             description TEXT NOT NULL,
             created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
-            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            venue TEXT NULL,
+            show_day TEXT NULL,
+            promotion_id INTEGER NULL,
+            allow_multi_match BOOLEAN NOT NULL DEFAULT FALSE,
+            attendance INTEGER NULL,
+            show_rating REAL NULL
         )
     "#).execute(conn).expect("Failed to create shows table");
 
@@ -137,7 +146,8 @@ fn run_consolidated_migrations(conn: &mut SqliteConnection) {
             is_active BOOLEAN NOT NULL DEFAULT TRUE,
             is_user_created BOOLEAN DEFAULT FALSE,
             created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
-            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            display_order INTEGER NOT NULL DEFAULT 0
         )
     "#).execute(conn).expect("Failed to create titles table");
 
@@ -180,7 +190,10 @@ fn run_consolidated_migrations(conn: &mut SqliteConnection) {
             is_title_match BOOLEAN NOT NULL DEFAULT FALSE,
             title_id INTEGER NULL,
             created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
-            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            notes TEXT NULL,
+            is_main_event BOOLEAN NOT NULL DEFAULT FALSE,
+            result_type TEXT NULL CHECK (result_type IN ('Win', 'NoContest', 'Draw'))
         )
     "#).execute(conn).expect("Failed to create matches table");
 
@@ -191,9 +204,41 @@ fn run_consolidated_migrations(conn: &mut SqliteConnection) {
             wrestler_id INTEGER NOT NULL,
             team_number INTEGER NULL,
             entrance_order INTEGER NULL,
-            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            role TEXT NOT NULL DEFAULT 'Competitor'
         )
     "#).execute(conn).expect("Failed to create match_participants table");
+
+    // Migration 9: Create promotions
+    diesel::sql_query(r#"
+        CREATE TABLE promotions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+            name TEXT NOT NULL UNIQUE,
+            description TEXT NULL,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+        )
+    "#).execute(conn).expect("Failed to create promotions table");
+
+    // Migration 15: Create settings
+    diesel::sql_query(r#"
+        CREATE TABLE settings (
+            key TEXT PRIMARY KEY NOT NULL,
+            value TEXT NOT NULL
+        )
+    "#).execute(conn).expect("Failed to create settings table");
+
+    // Migration 18: Create audit log
+    diesel::sql_query(r#"
+        CREATE TABLE audit_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+            timestamp TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            operation TEXT NOT NULL,
+            entity TEXT NOT NULL,
+            entity_id INTEGER,
+            details TEXT
+        )
+    "#).execute(conn).expect("Failed to create audit_log table");
     
     // Verify tables were created successfully
     println!("✓ All test database tables created successfully");
@@ -276,6 +321,15 @@ impl TestData {
             .ok();
     }
 
+    #[allow(dead_code)]
+    pub fn cleanup_promotions(&self, promotion_name: &str) {
+        use wwe_universe_manager_lib::schema::promotions::dsl::*;
+        let mut conn = self.get_connection();
+        diesel::delete(promotions.filter(name.eq(promotion_name)))
+            .execute(&mut conn)
+            .ok();
+    }
+
     #[allow(dead_code)]
     pub fn cleanup_signature_moves(&self, target_wrestler_id: i32) {
         use wwe_universe_manager_lib::schema::signature_moves::dsl::*;
@@ -284,6 +338,15 @@ impl TestData {
             .execute(&mut conn)
             .ok();
     }
+
+    #[allow(dead_code)]
+    pub fn cleanup_settings(&self, setting_key: &str) {
+        use wwe_universe_manager_lib::schema::settings::dsl::*;
+        let mut conn = self.get_connection();
+        diesel::delete(settings.filter(key.eq(setting_key)))
+            .execute(&mut conn)
+            .ok();
+    }
 }
 
 #[allow(dead_code)]
@@ -350,6 +413,7 @@ pub fn create_test_title() -> NewTitle {
         show_id: None,
         is_active: true,
         is_user_created: Some(false),
+        display_order: 0,
     }
 }
 
@@ -358,5 +422,7 @@ pub fn create_test_show() -> NewShow {
     NewShow {
         name: "Test Show".to_string(),
         description: "A test show for testing purposes".to_string(),
+        venue: None,
+        show_day: None,
     }
 }
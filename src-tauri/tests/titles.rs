@@ -1,6 +1,16 @@
+use chrono::{Duration, NaiveDateTime, Utc};
+use diesel::prelude::*;
 use serial_test::serial;
 
-use wwe_universe_manager_lib::db::{internal_create_belt, internal_create_wrestler};
+use wwe_universe_manager_lib::db::{
+    internal_create_belt, internal_create_title_set, internal_create_wrestler, internal_get_current_titles_for_wrestler,
+    internal_get_champion_for_title, internal_get_dominant_champions, internal_get_change_method_stats, internal_get_title_statistics, internal_undo_last_title_change, internal_update_title_holder,
+    internal_get_inaugural_champion, internal_get_titles_with_duplicate_reigns,
+    internal_process_ppv_results, internal_repair_duplicate_reigns, internal_get_wrestler_accolades, internal_get_title_timeline, internal_get_vacancy_history, internal_get_most_defended_reign, internal_create_show, internal_create_match, internal_get_holder_at_match, internal_get_audit_log, internal_get_wrestler_title_timeline, internal_get_average_reigns, internal_move_all_titles, internal_get_undefended_titles, internal_get_titles_for_show, internal_get_titles_for_division, internal_count_active_reigns, internal_get_misaligned_champions, internal_assign_wrestler_to_show, internal_get_champions_flat, internal_vacate_title, internal_get_titles, internal_get_titles_for_wrestler_gender, internal_get_distinct_divisions, internal_reorder_titles, internal_search_titles, validate_event_fields, MAX_EVENT_FIELD_LENGTH,
+};
+use wwe_universe_manager_lib::error::AppError;
+use wwe_universe_manager_lib::models::{MatchData, NewTitleHolder};
+use wwe_universe_manager_lib::schema::{matches, title_holders};
 
 mod test_helpers;
 use test_helpers::*;
@@ -209,3 +219,1631 @@ fn test_title_holder_relationship() {
     test_data.cleanup_titles(title_name);
     test_data.cleanup_wrestlers(wrestler_name);
 }
+
+#[test]
+#[serial]
+fn test_undo_last_title_change_restores_previous_champion() {
+    let test_data = TestData::new();
+    let title_name = "Undo Test Title";
+    let first_champ_name = "First Undo Champion";
+    let second_champ_name = "Second Undo Champion";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_champ_name);
+    test_data.cleanup_wrestlers(second_champ_name);
+
+    let mut conn = test_data.get_connection();
+
+    let first_champ = internal_create_wrestler(&mut conn, first_champ_name, "Male", 10, 2)
+        .expect("Failed to create first champion");
+    let second_champ = internal_create_wrestler(&mut conn, second_champ_name, "Male", 8, 4)
+        .expect("Failed to create second champion");
+
+    let title = internal_create_belt(
+        &mut conn,
+        title_name,
+        "Singles",
+        "World",
+        "Male",
+        None,
+        None,
+        false // is_user_created
+    ).expect("Failed to create title");
+
+    // Nothing to undo before any change, and a single reign has nothing to restore
+    assert!(internal_undo_last_title_change(&mut conn, title.id).is_err());
+    internal_update_title_holder(&mut conn, title.id, first_champ.id, None, None, None)
+        .expect("Failed to crown first champion");
+    assert!(internal_undo_last_title_change(&mut conn, title.id).is_err());
+
+    internal_update_title_holder(&mut conn, title.id, second_champ.id, None, None, None)
+        .expect("Failed to crown second champion");
+
+    let restored = internal_undo_last_title_change(&mut conn, title.id)
+        .expect("Failed to undo title change");
+    assert_eq!(restored.len(), 1);
+    assert_eq!(restored[0].wrestler_id, first_champ.id);
+    assert_eq!(restored[0].held_until, None);
+
+    let first_titles = internal_get_current_titles_for_wrestler(&mut conn, first_champ.id)
+        .expect("Failed to fetch first champion titles");
+    assert_eq!(first_titles.len(), 1);
+    assert_eq!(first_titles[0].id, title.id);
+
+    let second_titles = internal_get_current_titles_for_wrestler(&mut conn, second_champ.id)
+        .expect("Failed to fetch second champion titles");
+    assert!(second_titles.is_empty());
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_champ_name);
+    test_data.cleanup_wrestlers(second_champ_name);
+}
+
+#[test]
+#[serial]
+fn test_undo_last_title_change_restores_whole_tag_team() {
+    let test_data = TestData::new();
+    let title_name = "Undo Tag Title";
+    let wrestler_names = ["Undo Tag Champion A", "Undo Tag Champion B", "Undo Tag Challenger A", "Undo Tag Challenger B"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let wrestlers: Vec<_> = wrestler_names
+        .iter()
+        .map(|name| internal_create_wrestler(&mut conn, name, "Male", 0, 0).expect("Failed to create wrestler"))
+        .collect();
+    let title = internal_create_belt(&mut conn, title_name, "Tag Team", "Tag Team", "Male", None, None, false)
+        .expect("Failed to create title");
+
+    // Both champions lose the belts to both challengers at the same moment
+    let now = Utc::now().naive_utc();
+    let first_change = now - Duration::days(60);
+    let second_change = now - Duration::days(10);
+    for (wrestler, since, until) in [
+        (&wrestlers[0], first_change, Some(second_change)),
+        (&wrestlers[1], first_change, Some(second_change)),
+        (&wrestlers[2], second_change, None),
+        (&wrestlers[3], second_change, None),
+    ] {
+        insert_reign_between(&mut conn, title.id, wrestler.id, since, until);
+    }
+
+    let restored = internal_undo_last_title_change(&mut conn, title.id)
+        .expect("Failed to undo title change");
+    let mut restored_ids: Vec<i32> = restored.iter().map(|reign| reign.wrestler_id).collect();
+    restored_ids.sort();
+    assert_eq!(restored_ids, vec![wrestlers[0].id, wrestlers[1].id]);
+
+    let active_holders: Vec<i32> = title_holders::table
+        .filter(title_holders::title_id.eq(title.id))
+        .filter(title_holders::held_until.is_null())
+        .select(title_holders::wrestler_id)
+        .order(title_holders::wrestler_id.asc())
+        .load(&mut conn)
+        .expect("Failed to load active holders");
+    assert_eq!(active_holders, vec![wrestlers[0].id, wrestlers[1].id]);
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_undo_last_title_change_rejects_vacant_title() {
+    let test_data = TestData::new();
+    let title_name = "Undo Vacant Title";
+    let first_champ_name = "Undo Vacant First Champion";
+    let second_champ_name = "Undo Vacant Second Champion";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_champ_name);
+    test_data.cleanup_wrestlers(second_champ_name);
+
+    let mut conn = test_data.get_connection();
+
+    let first_champ = internal_create_wrestler(&mut conn, first_champ_name, "Male", 0, 0)
+        .expect("Failed to create first champion");
+    let second_champ = internal_create_wrestler(&mut conn, second_champ_name, "Male", 0, 0)
+        .expect("Failed to create second champion");
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+
+    // Two reigns separated by a vacancy, and the title vacated again now
+    let now = Utc::now().naive_utc();
+    insert_reign_between(&mut conn, title.id, first_champ.id, now - Duration::days(90), Some(now - Duration::days(60)));
+    insert_reign_between(&mut conn, title.id, second_champ.id, now - Duration::days(30), Some(now - Duration::days(5)));
+
+    let result = internal_undo_last_title_change(&mut conn, title.id);
+    assert!(matches!(result, Err(AppError::Validation(_))));
+
+    // Nothing was deleted or reopened
+    let ended_reigns = title_holders::table
+        .filter(title_holders::title_id.eq(title.id))
+        .filter(title_holders::held_until.is_not_null())
+        .count()
+        .get_result::<i64>(&mut conn)
+        .expect("Failed to count reigns");
+    assert_eq!(ended_reigns, 2);
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_champ_name);
+    test_data.cleanup_wrestlers(second_champ_name);
+}
+
+/// Inserts a reign with exact timestamps so consecutive reigns line up
+fn insert_reign_between(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+    wrestler_id: i32,
+    held_since: NaiveDateTime,
+    held_until: Option<NaiveDateTime>,
+) {
+    let reign_id = diesel::insert_into(title_holders::table)
+        .values(NewTitleHolder {
+            title_id,
+            wrestler_id,
+            held_since,
+            event_name: None,
+            event_location: None,
+            change_method: None,
+        })
+        .returning(title_holders::id)
+        .get_result::<i32>(conn)
+        .expect("Failed to insert reign");
+
+    diesel::update(title_holders::table.find(reign_id))
+        .set(title_holders::held_until.eq(held_until))
+        .execute(conn)
+        .expect("Failed to set reign end");
+}
+
+/// Inserts a reign with explicit dates so its length is known
+fn insert_reign(
+    conn: &mut SqliteConnection,
+    title_id: i32,
+    wrestler_id: i32,
+    started_days_ago: i64,
+    ended_days_ago: Option<i64>,
+) {
+    let now = Utc::now().naive_utc();
+    let reign_id = diesel::insert_into(title_holders::table)
+        .values(NewTitleHolder {
+            title_id,
+            wrestler_id,
+            held_since: now - Duration::days(started_days_ago),
+            event_name: None,
+            event_location: None,
+            change_method: None,
+        })
+        .returning(title_holders::id)
+        .get_result::<i32>(conn)
+        .expect("Failed to insert reign");
+
+    if let Some(ended) = ended_days_ago {
+        diesel::update(title_holders::table.find(reign_id))
+            .set(title_holders::held_until.eq(Some(now - Duration::days(ended))))
+            .execute(conn)
+            .expect("Failed to end reign");
+    }
+}
+
+#[test]
+#[serial]
+fn test_title_statistics_with_reigns() {
+    let test_data = TestData::new();
+    let title_name = "Stats Test Title";
+    let first_champ_name = "First Stats Champion";
+    let second_champ_name = "Second Stats Champion";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_champ_name);
+    test_data.cleanup_wrestlers(second_champ_name);
+
+    let mut conn = test_data.get_connection();
+
+    let first_champ = internal_create_wrestler(&mut conn, first_champ_name, "Male", 0, 0)
+        .expect("Failed to create first champion");
+    let second_champ = internal_create_wrestler(&mut conn, second_champ_name, "Male", 0, 0)
+        .expect("Failed to create second champion");
+    let title = internal_create_belt(
+        &mut conn,
+        title_name,
+        "Singles",
+        "World",
+        "Male",
+        None,
+        None,
+        false // is_user_created
+    ).expect("Failed to create title");
+
+    // 10 day reign, 20 day reign, then a current 6 day reign by the first champion
+    insert_reign(&mut conn, title.id, first_champ.id, 36, Some(26));
+    insert_reign(&mut conn, title.id, second_champ.id, 26, Some(6));
+    insert_reign(&mut conn, title.id, first_champ.id, 6, None);
+
+    let stats = internal_get_title_statistics(&mut conn, title.id)
+        .expect("Failed to fetch title statistics");
+    assert_eq!(stats.total_reigns, 3);
+    assert_eq!(stats.distinct_champions, 2);
+    assert_eq!(stats.longest_reign_days, Some(20));
+    assert_eq!(stats.current_reign_days, Some(6));
+    assert_eq!(stats.average_reign_days, Some(12.0));
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_champ_name);
+    test_data.cleanup_wrestlers(second_champ_name);
+}
+
+#[test]
+#[serial]
+fn test_title_statistics_for_never_held_title() {
+    let test_data = TestData::new();
+    let title_name = "Unheld Stats Title";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+
+    let mut conn = test_data.get_connection();
+    let title = internal_create_belt(
+        &mut conn,
+        title_name,
+        "Singles",
+        "Intercontinental",
+        "Male",
+        None,
+        None,
+        false // is_user_created
+    ).expect("Failed to create title");
+
+    let stats = internal_get_title_statistics(&mut conn, title.id)
+        .expect("Failed to fetch title statistics");
+    assert_eq!(stats.total_reigns, 0);
+    assert_eq!(stats.distinct_champions, 0);
+    assert_eq!(stats.longest_reign_days, None);
+    assert_eq!(stats.current_reign_days, None);
+    assert_eq!(stats.average_reign_days, None);
+    assert_eq!(stats.most_defenses_in_a_reign, None);
+    assert_eq!(stats.most_defended_champion, None);
+    assert!(internal_get_most_defended_reign(&mut conn, title.id)
+        .expect("Failed to fetch most defended reign")
+        .is_none());
+
+    // A title that does not exist is reported rather than given empty statistics
+    let result = internal_get_title_statistics(&mut conn, 99999);
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+}
+
+#[test]
+#[serial]
+fn test_title_statistics_counts_tag_team_reign_once() {
+    let test_data = TestData::new();
+    let title_name = "Tag Stats Title";
+    let first_partner_name = "Tag Stats Partner A";
+    let second_partner_name = "Tag Stats Partner B";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_partner_name);
+    test_data.cleanup_wrestlers(second_partner_name);
+
+    let mut conn = test_data.get_connection();
+
+    let first_partner = internal_create_wrestler(&mut conn, first_partner_name, "Male", 0, 0)
+        .expect("Failed to create first partner");
+    let second_partner = internal_create_wrestler(&mut conn, second_partner_name, "Male", 0, 0)
+        .expect("Failed to create second partner");
+    let title = internal_create_belt(&mut conn, title_name, "Tag Team", "Tag Team", "Male", None, None, false)
+        .expect("Failed to create title");
+
+    // The team held the belts together once and are champions again now
+    let now = Utc::now().naive_utc();
+    for partner in [&first_partner, &second_partner] {
+        insert_reign_between(&mut conn, title.id, partner.id, now - Duration::days(40), Some(now - Duration::days(20)));
+        insert_reign_between(&mut conn, title.id, partner.id, now - Duration::days(10), None);
+    }
+
+    let stats = internal_get_title_statistics(&mut conn, title.id)
+        .expect("Failed to fetch title statistics");
+    assert_eq!(stats.total_reigns, 2);
+    assert_eq!(stats.distinct_champions, 2);
+    assert_eq!(stats.longest_reign_days, Some(20));
+    assert_eq!(stats.current_reign_days, Some(10));
+    assert_eq!(stats.average_reign_days, Some(15.0));
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_partner_name);
+    test_data.cleanup_wrestlers(second_partner_name);
+}
+
+#[test]
+#[serial]
+fn test_get_champion_for_title() {
+    let test_data = TestData::new();
+    let title_name = "Champion Lookup Title";
+    let champ_name = "Champion Lookup Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(champ_name);
+
+    let mut conn = test_data.get_connection();
+
+    let champ = internal_create_wrestler(&mut conn, champ_name, "Female", 0, 0)
+        .expect("Failed to create champion");
+    let title = internal_create_belt(
+        &mut conn,
+        title_name,
+        "Singles",
+        "Women's World",
+        "Female",
+        None,
+        None,
+        false // is_user_created
+    ).expect("Failed to create title");
+
+    let vacant = internal_get_champion_for_title(&mut conn, title.id)
+        .expect("Failed to fetch champion for vacant title");
+    assert!(vacant.is_empty());
+
+    internal_update_title_holder(&mut conn, title.id, champ.id, None, None, None)
+        .expect("Failed to crown champion");
+
+    let holders = internal_get_champion_for_title(&mut conn, title.id)
+        .expect("Failed to fetch champion");
+    assert_eq!(holders.len(), 1);
+    assert_eq!(holders[0].wrestler_name, champ_name);
+    assert_eq!(holders[0].wrestler_gender, "Female");
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(champ_name);
+}
+
+#[test]
+#[serial]
+fn test_change_method_stats() {
+    let test_data = TestData::new();
+    let title_name = "Change Method Title";
+    let champ_names = ["Method Champ One", "Method Champ Two", "Method Champ Three", "Method Champ Four"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let title = internal_create_belt(
+        &mut conn,
+        title_name,
+        "Singles",
+        "World",
+        "Male",
+        None,
+        None,
+        false // is_user_created
+    ).expect("Failed to create title");
+
+    for (name, method) in champ_names.into_iter().zip([Some("Pinfall"), Some("Submission"), Some("Pinfall"), None]) {
+        let champ = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create champion");
+        internal_update_title_holder(&mut conn, title.id, champ.id, None, None, method)
+            .expect("Failed to change champion");
+    }
+
+    let stats = internal_get_change_method_stats(&mut conn, false)
+        .expect("Failed to fetch change method stats");
+    assert_eq!(stats, vec![("Pinfall".to_string(), 2), ("Submission".to_string(), 1)]);
+
+    let stats = internal_get_change_method_stats(&mut conn, true)
+        .expect("Failed to fetch change method stats");
+    assert!(stats.contains(&("Unknown".to_string(), 1)));
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+fn test_validate_event_fields_at_length_limit() {
+    let at_limit = "a".repeat(MAX_EVENT_FIELD_LENGTH);
+    let over_limit = "a".repeat(MAX_EVENT_FIELD_LENGTH + 1);
+
+    assert!(validate_event_fields(None, None, None).is_ok());
+    assert!(validate_event_fields(Some(&at_limit), Some(&at_limit), Some(&at_limit)).is_ok());
+
+    let err = validate_event_fields(Some(&over_limit), None, None).unwrap_err();
+    assert_eq!(err.to_string(), "Event name too long");
+    let err = validate_event_fields(None, Some(&over_limit), None).unwrap_err();
+    assert_eq!(err.to_string(), "Event location too long");
+    let err = validate_event_fields(None, None, Some(&over_limit)).unwrap_err();
+    assert_eq!(err.to_string(), "Change method too long");
+}
+
+#[test]
+#[serial]
+fn test_get_inaugural_champion() {
+    let test_data = TestData::new();
+    let title_name = "Inaugural Title";
+    let first_name = "Inaugural First Champ";
+    let second_name = "Inaugural Second Champ";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_name);
+    test_data.cleanup_wrestlers(second_name);
+
+    let mut conn = test_data.get_connection();
+    let title = internal_create_belt(
+        &mut conn,
+        title_name,
+        "Singles",
+        "United States",
+        "Male",
+        None,
+        None,
+        false // is_user_created
+    ).expect("Failed to create title");
+
+    let none_yet = internal_get_inaugural_champion(&mut conn, title.id)
+        .expect("Failed to fetch inaugural champion");
+    assert!(none_yet.is_none());
+
+    let first = internal_create_wrestler(&mut conn, first_name, "Male", 0, 0)
+        .expect("Failed to create first champion");
+    let second = internal_create_wrestler(&mut conn, second_name, "Male", 0, 0)
+        .expect("Failed to create second champion");
+
+    // Insert the later reign first so row order can't decide the result
+    insert_reign(&mut conn, title.id, second.id, 30, None);
+    insert_reign(&mut conn, title.id, first.id, 100, Some(30));
+
+    let (champion, held_since) = internal_get_inaugural_champion(&mut conn, title.id)
+        .expect("Failed to fetch inaugural champion")
+        .expect("Title should have an inaugural champion");
+    assert_eq!(champion.id, first.id);
+    assert!(held_since < Utc::now().naive_utc() - Duration::days(99));
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_name);
+    test_data.cleanup_wrestlers(second_name);
+}
+
+#[test]
+#[serial]
+fn test_reorder_titles_within_tier() {
+    let test_data = TestData::new();
+    let first_name = "Order Alpha Championship";
+    let second_name = "Order Bravo Championship";
+    let secondary_name = "Order Secondary Championship";
+
+    // Cleanup any existing test data
+    for name in [first_name, second_name, secondary_name] {
+        test_data.cleanup_titles(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let [alpha, bravo] = [first_name, second_name].map(|name| {
+        internal_create_belt(&mut conn, name, "Singles", "World", "Male", None, None, false)
+            .expect("Failed to create title")
+    });
+    let secondary = internal_create_belt(
+        &mut conn,
+        secondary_name,
+        "Singles",
+        "Intercontinental",
+        "Male",
+        None,
+        None,
+        false // is_user_created
+    ).expect("Failed to create title");
+
+    let order_of = |conn: &mut SqliteConnection| -> Vec<i32> {
+        internal_get_titles(conn)
+            .expect("Failed to fetch titles")
+            .into_iter()
+            .map(|t| t.title.id)
+            .collect()
+    };
+    assert_eq!(order_of(&mut conn), vec![alpha.id, bravo.id, secondary.id]);
+
+    // A lower display order can't lift a title above a more prestigious tier
+    internal_reorder_titles(&mut conn, &[(bravo.id, 0), (alpha.id, 1), (secondary.id, -5)])
+        .expect("Failed to reorder titles");
+    assert_eq!(order_of(&mut conn), vec![bravo.id, alpha.id, secondary.id]);
+
+    // An unknown title rolls back the whole reorder
+    assert!(matches!(
+        internal_reorder_titles(&mut conn, &[(alpha.id, -1), (secondary.id + 1000, 0)]),
+        Err(AppError::NotFound(_))
+    ));
+    assert_eq!(order_of(&mut conn), vec![bravo.id, alpha.id, secondary.id]);
+
+    // Cleanup
+    for name in [first_name, second_name, secondary_name] {
+        test_data.cleanup_titles(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_create_standard_title_set() {
+    let test_data = TestData::new();
+    let mut conn = test_data.get_connection();
+
+    assert!(matches!(
+        internal_create_title_set(&mut conn, None, "cruiserweight"),
+        Err(AppError::Validation(_))
+    ));
+
+    let created = internal_create_title_set(&mut conn, None, "standard")
+        .expect("Failed to create title set");
+    assert_eq!(created.len(), 4);
+    assert!(created.iter().all(|title| title.is_user_created == Some(true)));
+
+    // Applying the template again skips every existing name
+    let again = internal_create_title_set(&mut conn, None, "standard")
+        .expect("Failed to reapply title set");
+    assert!(again.is_empty());
+
+    // Cleanup
+    for title in created {
+        test_data.cleanup_titles(&title.name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_search_titles_by_name_and_division() {
+    let test_data = TestData::new();
+    let heavyweight_name = "Searchable Heavyweight Championship";
+    let crown_name = "Searchable Crown";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(heavyweight_name);
+    test_data.cleanup_titles(crown_name);
+
+    let mut conn = test_data.get_connection();
+    let heavyweight = internal_create_belt(&mut conn, heavyweight_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    let crown = internal_create_belt(&mut conn, crown_name, "Singles", "Hardcore", "Mixed", None, None, false)
+        .expect("Failed to create title");
+
+    // Partial, case-insensitive match on the name
+    let results = internal_search_titles(&mut conn, "HEAVYweight").expect("Failed to search titles");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].title.id, heavyweight.id);
+
+    // Partial match on the division only
+    let results = internal_search_titles(&mut conn, "hardc").expect("Failed to search titles");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].title.id, crown.id);
+
+    // Shared text matches both, in prestige order
+    let results = internal_search_titles(&mut conn, "searchable").expect("Failed to search titles");
+    let ids: Vec<i32> = results.iter().map(|t| t.title.id).collect();
+    assert_eq!(ids, vec![heavyweight.id, crown.id]);
+
+    // Wildcards are matched literally
+    let results = internal_search_titles(&mut conn, "%").expect("Failed to search titles");
+    assert!(results.is_empty());
+
+    // Cleanup
+    test_data.cleanup_titles(heavyweight_name);
+    test_data.cleanup_titles(crown_name);
+}
+
+#[test]
+#[serial]
+fn test_wrestler_accolades() {
+    let test_data = TestData::new();
+    let title_name = "Accolades Championship";
+    let wrestler_name = "Accolades Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+
+    let mut conn = test_data.get_connection();
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Male", 6, 2)
+        .expect("Failed to create wrestler");
+
+    let accolades = internal_get_wrestler_accolades(&mut conn, wrestler.id)
+        .expect("Failed to fetch accolades");
+    assert_eq!(accolades.total_reigns, 0);
+    assert!(accolades.titles_held_names.is_empty());
+    assert_eq!(accolades.longest_reign_days, None);
+
+    // Two reigns with the same title count once by name
+    insert_reign(&mut conn, title.id, wrestler.id, 200, Some(150));
+    insert_reign(&mut conn, title.id, wrestler.id, 100, Some(20));
+
+    let accolades = internal_get_wrestler_accolades(&mut conn, wrestler.id)
+        .expect("Failed to fetch accolades");
+    assert_eq!(accolades.total_reigns, 2);
+    assert_eq!(accolades.titles_held_names, vec![title_name.to_string()]);
+    assert_eq!(accolades.longest_reign_days, Some(80));
+    assert_eq!((accolades.match_wins, accolades.match_losses), (6, 2));
+    assert_eq!(accolades.win_rate, Some(0.75));
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+}
+
+#[test]
+#[serial]
+fn test_repair_duplicate_reigns() {
+    let test_data = TestData::new();
+    let title_name = "Duplicate Reign Title";
+    let older_name = "Duplicate Older Champ";
+    let newer_name = "Duplicate Newer Champ";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(older_name);
+    test_data.cleanup_wrestlers(newer_name);
+
+    let mut conn = test_data.get_connection();
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    let older = internal_create_wrestler(&mut conn, older_name, "Male", 0, 0)
+        .expect("Failed to create older champion");
+    let newer = internal_create_wrestler(&mut conn, newer_name, "Male", 0, 0)
+        .expect("Failed to create newer champion");
+
+    // Two open reigns on a singles title
+    insert_reign(&mut conn, title.id, older.id, 50, None);
+    insert_reign(&mut conn, title.id, newer.id, 10, None);
+    assert_eq!(
+        internal_get_titles_with_duplicate_reigns(&mut conn).expect("Failed to check reigns"),
+        vec![title.id]
+    );
+
+    let fixed = internal_repair_duplicate_reigns(&mut conn, title.id)
+        .expect("Failed to repair duplicate reigns");
+    assert_eq!(fixed, 1);
+
+    let champions = internal_get_champion_for_title(&mut conn, title.id)
+        .expect("Failed to fetch champion");
+    assert_eq!(champions.len(), 1);
+    assert_eq!(champions[0].holder.wrestler_id, newer.id);
+    assert!(internal_get_titles_with_duplicate_reigns(&mut conn)
+        .expect("Failed to check reigns")
+        .is_empty());
+
+    // Running the repair again has nothing to fix
+    assert_eq!(internal_repair_duplicate_reigns(&mut conn, title.id).expect("Failed to repair"), 0);
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(older_name);
+    test_data.cleanup_wrestlers(newer_name);
+}
+
+#[test]
+#[serial]
+fn test_title_timeline_with_consecutive_reigns() {
+    let test_data = TestData::new();
+    let title_name = "Timeline Championship";
+    let champ_names = ["Timeline Champ One", "Timeline Champ Two", "Timeline Champ Three"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Female", None, None, false)
+        .expect("Failed to create title");
+
+    let mut champ_ids = Vec::new();
+    for name in champ_names {
+        let champ = internal_create_wrestler(&mut conn, name, "Female", 0, 0)
+            .expect("Failed to create champion");
+        internal_update_title_holder(&mut conn, title.id, champ.id, None, None, Some("Pinfall"))
+            .expect("Failed to change champion");
+        champ_ids.push(champ.id);
+    }
+
+    let timeline = internal_get_title_timeline(&mut conn, title.id)
+        .expect("Failed to fetch title timeline");
+    assert_eq!(timeline.len(), 3);
+    assert_eq!(
+        timeline.iter().map(|segment| segment.wrestler_id).collect::<Vec<_>>(),
+        champ_ids
+    );
+    assert_eq!(timeline[0].wrestler_name, champ_names[0]);
+
+    // Each reign ends exactly where the next begins
+    for pair in timeline.windows(2) {
+        assert_eq!(pair[0].held_until, Some(pair[1].held_since));
+    }
+    assert_eq!(timeline[2].held_until, None);
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_process_ppv_results_is_atomic() {
+    let test_data = TestData::new();
+    let mens_title_name = "PPV Mens Championship";
+    let womens_title_name = "PPV Womens Championship";
+    let wrestler_names = ["PPV Mens Winner", "PPV Womens Winner", "PPV Second Mens Winner"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(mens_title_name);
+    test_data.cleanup_titles(womens_title_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let mens_title = internal_create_belt(&mut conn, mens_title_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    let womens_title = internal_create_belt(&mut conn, womens_title_name, "Singles", "Women's World", "Female", None, None, false)
+        .expect("Failed to create title");
+    let mens_winner = internal_create_wrestler(&mut conn, wrestler_names[0], "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let womens_winner = internal_create_wrestler(&mut conn, wrestler_names[1], "Female", 0, 0)
+        .expect("Failed to create wrestler");
+    let second_mens_winner = internal_create_wrestler(&mut conn, wrestler_names[2], "Male", 0, 0)
+        .expect("Failed to create wrestler");
+
+    let applied = internal_process_ppv_results(
+        &mut conn,
+        Some("SummerSlam"),
+        Some("MetLife Stadium"),
+        &[
+            (mens_title.id, mens_winner.id, Some("Pinfall".to_string())),
+            (womens_title.id, womens_winner.id, Some("Submission".to_string())),
+        ],
+    ).expect("Failed to process event results");
+    assert_eq!(applied, 2);
+
+    let womens_champion = internal_get_champion_for_title(&mut conn, womens_title.id)
+        .expect("Failed to fetch champion");
+    assert_eq!(womens_champion[0].holder.wrestler_id, womens_winner.id);
+    assert_eq!(womens_champion[0].holder.event_name.as_deref(), Some("SummerSlam"));
+
+    // The valid first change is rolled back along with the invalid second one
+    assert!(matches!(
+        internal_process_ppv_results(
+            &mut conn,
+            Some("Survivor Series"),
+            None,
+            &[
+                (mens_title.id, second_mens_winner.id, None),
+                (womens_title.id, second_mens_winner.id, None),
+            ],
+        ),
+        Err(AppError::Validation(_))
+    ));
+
+    let mens_champion = internal_get_champion_for_title(&mut conn, mens_title.id)
+        .expect("Failed to fetch champion");
+    assert_eq!(mens_champion.len(), 1);
+    assert_eq!(mens_champion[0].holder.wrestler_id, mens_winner.id);
+
+    // A title listed twice would silently keep only the last holder, so it is rejected
+    assert!(matches!(
+        internal_process_ppv_results(
+            &mut conn,
+            Some("Royal Rumble"),
+            None,
+            &[
+                (mens_title.id, second_mens_winner.id, None),
+                (mens_title.id, mens_winner.id, None),
+            ],
+        ),
+        Err(AppError::Validation(_))
+    ));
+
+    let mens_champion = internal_get_champion_for_title(&mut conn, mens_title.id)
+        .expect("Failed to fetch champion");
+    assert_eq!(mens_champion.len(), 1);
+    assert_eq!(mens_champion[0].holder.wrestler_id, mens_winner.id);
+    assert_eq!(mens_champion[0].holder.event_name.as_deref(), Some("SummerSlam"));
+
+    // Cleanup
+    test_data.cleanup_titles(mens_title_name);
+    test_data.cleanup_titles(womens_title_name);
+    for name in wrestler_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_dominant_champions_sum_every_reign() {
+    let test_data = TestData::new();
+    let title_names = ["Dominant World Title", "Dominant Secondary Title"];
+    let two_reign_name = "Dominant Two Reigns";
+    let one_reign_name = "Dominant One Reign";
+
+    // Cleanup any existing test data
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    test_data.cleanup_wrestlers(two_reign_name);
+    test_data.cleanup_wrestlers(one_reign_name);
+
+    let mut conn = test_data.get_connection();
+    let world = internal_create_belt(&mut conn, title_names[0], "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    let secondary = internal_create_belt(&mut conn, title_names[1], "Singles", "Intercontinental", "Male", None, None, false)
+        .expect("Failed to create title");
+    let two_reigns = internal_create_wrestler(&mut conn, two_reign_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let one_reign = internal_create_wrestler(&mut conn, one_reign_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+
+    // 60 + 50 days beats a single 100 day reign
+    insert_reign(&mut conn, world.id, two_reigns.id, 300, Some(240));
+    insert_reign(&mut conn, secondary.id, two_reigns.id, 50, None);
+    insert_reign(&mut conn, world.id, one_reign.id, 200, Some(100));
+
+    let leaders = internal_get_dominant_champions(&mut conn, 10)
+        .expect("Failed to fetch dominant champions");
+    assert_eq!(leaders.len(), 2);
+    assert_eq!(leaders[0].0.id, two_reigns.id);
+    assert_eq!(leaders[0].1, 110);
+    assert_eq!(leaders[1].0.id, one_reign.id);
+    assert_eq!(leaders[1].1, 100);
+
+    let top = internal_get_dominant_champions(&mut conn, 1)
+        .expect("Failed to fetch dominant champions");
+    assert_eq!(top.len(), 1);
+
+    // Cleanup
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    test_data.cleanup_wrestlers(two_reign_name);
+    test_data.cleanup_wrestlers(one_reign_name);
+}
+
+#[test]
+#[serial]
+fn test_title_gender_is_normalized() {
+    let test_data = TestData::new();
+    let mixed_title_name = "Normalized Mixed Championship";
+    let male_title_name = "Normalized Male Championship";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(mixed_title_name);
+    test_data.cleanup_titles(male_title_name);
+
+    let mut conn = test_data.get_connection();
+    let mixed_title = internal_create_belt(&mut conn, mixed_title_name, "Singles", "Hardcore", "mixed", None, None, false)
+        .expect("Failed to create mixed title");
+    assert_eq!(mixed_title.gender, "Mixed");
+    let male_title = internal_create_belt(&mut conn, male_title_name, "Singles", "Hardcore", "m", None, None, false)
+        .expect("Failed to create male title");
+    assert_eq!(male_title.gender, "Male");
+
+    let result = internal_create_belt(&mut conn, "Unknown Gender Championship", "Singles", "Hardcore", "", None, None, false);
+    assert!(matches!(result, Err(AppError::Validation(_))));
+
+    // Lowercase and shorthand filters match the canonical stored values
+    let for_female = internal_get_titles_for_wrestler_gender(&mut conn, "F")
+        .expect("Failed to load titles for female wrestler");
+    assert!(for_female.iter().any(|t| t.title.id == mixed_title.id));
+    assert!(!for_female.iter().any(|t| t.title.id == male_title.id));
+
+    // Cleanup
+    test_data.cleanup_titles(mixed_title_name);
+    test_data.cleanup_titles(male_title_name);
+}
+
+#[test]
+#[serial]
+fn test_distinct_divisions_are_sorted_and_unique() {
+    let test_data = TestData::new();
+    let titles = [
+        ("Distinct Hardcore Title", "Hardcore"),
+        ("Distinct Second Hardcore Title", "Hardcore"),
+        ("Distinct Cruiserweight Title", "Cruiserweight"),
+    ];
+
+    // Cleanup any existing test data
+    for (name, _) in titles {
+        test_data.cleanup_titles(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    for (name, division) in titles {
+        internal_create_belt(&mut conn, name, "Singles", division, "Mixed", None, None, false)
+            .expect("Failed to create title");
+    }
+
+    let divisions = internal_get_distinct_divisions(&mut conn).expect("Failed to load divisions");
+    assert_eq!(divisions, vec!["Cruiserweight".to_string(), "Hardcore".to_string()]);
+
+    // Cleanup
+    for (name, _) in titles {
+        test_data.cleanup_titles(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_vacancy_history_records_vacated_reigns() {
+    let test_data = TestData::new();
+    let title_name = "Vacancy Championship";
+    let champ_names = ["Vacancy Champ One", "Vacancy Champ Two"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    for name in champ_names {
+        let champ = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create champion");
+        internal_update_title_holder(&mut conn, title.id, champ.id, None, None, Some("Pinfall"))
+            .expect("Failed to change champion");
+    }
+
+    // A straight title change is not a vacancy
+    assert!(internal_get_vacancy_history(&mut conn, title.id)
+        .expect("Failed to fetch vacancy history")
+        .is_empty());
+
+    internal_vacate_title(&mut conn, title.id, None, None, Some("Injury"))
+        .expect("Failed to vacate title");
+
+    let history = internal_get_vacancy_history(&mut conn, title.id)
+        .expect("Failed to fetch vacancy history");
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].reason.as_deref(), Some("Injury"));
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_most_defended_reign_across_reigns() {
+    let test_data = TestData::new();
+    let show_name = "Defense Record Show";
+    let title_name = "Defense Record Championship";
+    let first_champ_name = "Defense Record First";
+    let second_champ_name = "Defense Record Second";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_champ_name);
+    test_data.cleanup_wrestlers(second_champ_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Defense record testing")
+        .expect("Failed to create show");
+    let first_champ = internal_create_wrestler(&mut conn, first_champ_name, "Male", 0, 0)
+        .expect("Failed to create first champion");
+    let second_champ = internal_create_wrestler(&mut conn, second_champ_name, "Male", 0, 0)
+        .expect("Failed to create second champion");
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+
+    insert_reign(&mut conn, title.id, first_champ.id, 100, Some(50));
+    insert_reign(&mut conn, title.id, second_champ.id, 50, None);
+
+    // Books a decided title match the given number of days ago
+    let mut record_result = |winner_id: i32, days_ago: i64, is_title_match: bool| {
+        let booked = internal_create_match(&mut conn, &MatchData {
+            show_id: show.id,
+            match_name: Some(format!("Defense Record Match {}", days_ago)),
+            match_type: "Singles".to_string(),
+            match_stipulation: None,
+            scheduled_date: Some((Utc::now() - Duration::days(days_ago)).format("%Y-%m-%d").to_string()),
+            match_order: None,
+            is_title_match,
+            title_id: is_title_match.then_some(title.id),
+        })
+        .expect("Failed to create match");
+        diesel::update(matches::table.find(booked.id))
+            .set(matches::winner_id.eq(winner_id))
+            .execute(&mut conn)
+            .expect("Failed to set winner");
+    };
+
+    // Two defenses for the first reign
+    record_result(first_champ.id, 90, true);
+    record_result(first_champ.id, 80, true);
+    // Three for the second; the earlier win and the non-title match don't count
+    record_result(second_champ.id, 60, true);
+    record_result(second_champ.id, 40, true);
+    record_result(second_champ.id, 30, true);
+    record_result(second_champ.id, 20, true);
+    record_result(second_champ.id, 10, false);
+
+    let (reign, defenses) = internal_get_most_defended_reign(&mut conn, title.id)
+        .expect("Failed to fetch most defended reign")
+        .expect("Title should have a defended reign");
+    assert_eq!(reign.holder.wrestler_id, second_champ.id);
+    assert_eq!(defenses, 3);
+
+    let stats = internal_get_title_statistics(&mut conn, title.id)
+        .expect("Failed to fetch title statistics");
+    assert_eq!(stats.most_defenses_in_a_reign, Some(3));
+    assert_eq!(stats.most_defended_champion.as_deref(), Some(second_champ_name));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(first_champ_name);
+    test_data.cleanup_wrestlers(second_champ_name);
+}
+
+#[test]
+#[serial]
+fn test_holder_at_match_ignores_later_title_changes() {
+    let test_data = TestData::new();
+    let show_name = "Historical Card Show";
+    let title_name = "Historical Card Championship";
+    let old_champ_name = "Historical Card Old Champ";
+    let new_champ_name = "Historical Card New Champ";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(old_champ_name);
+    test_data.cleanup_wrestlers(new_champ_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Historical card testing")
+        .expect("Failed to create show");
+    let old_champ = internal_create_wrestler(&mut conn, old_champ_name, "Male", 0, 0)
+        .expect("Failed to create old champion");
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    insert_reign(&mut conn, title.id, old_champ.id, 30, None);
+
+    let booking = |days_ago: Option<i64>, is_title_match: bool| MatchData {
+        show_id: show.id,
+        match_name: Some("Historical Card Match".to_string()),
+        match_type: "Singles".to_string(),
+        match_stipulation: None,
+        scheduled_date: days_ago
+            .map(|days| (Utc::now() - Duration::days(days)).format("%Y-%m-%d").to_string()),
+        match_order: None,
+        is_title_match,
+        title_id: is_title_match.then_some(title.id),
+    };
+    let past_title_match = internal_create_match(&mut conn, &booking(Some(10), true))
+        .expect("Failed to create title match");
+    let undated_title_match = internal_create_match(&mut conn, &booking(None, true))
+        .expect("Failed to create undated match");
+    let non_title_match = internal_create_match(&mut conn, &booking(Some(10), false))
+        .expect("Failed to create non-title match");
+
+    // The title changes hands after the match was booked
+    let new_champ = internal_create_wrestler(&mut conn, new_champ_name, "Male", 0, 0)
+        .expect("Failed to create new champion");
+    internal_update_title_holder(&mut conn, title.id, new_champ.id, None, None, None)
+        .expect("Failed to change champion");
+
+    let holders = internal_get_holder_at_match(&mut conn, past_title_match.id)
+        .expect("Failed to fetch holder at match")
+        .expect("Title match should have a holder lookup");
+    assert_eq!(holders.len(), 1);
+    assert_eq!(holders[0].holder.wrestler_id, old_champ.id);
+
+    assert!(internal_get_holder_at_match(&mut conn, undated_title_match.id)
+        .expect("Failed to fetch holder at match")
+        .is_none());
+    assert!(internal_get_holder_at_match(&mut conn, non_title_match.id)
+        .expect("Failed to fetch holder at match")
+        .is_none());
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(old_champ_name);
+    test_data.cleanup_wrestlers(new_champ_name);
+}
+
+#[test]
+#[serial]
+fn test_title_change_is_recorded_in_audit_log() {
+    let test_data = TestData::new();
+    let title_name = "Audit Log Championship";
+    let champ_name = "Audit Log Champion";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(champ_name);
+
+    let mut conn = test_data.get_connection();
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    let champ = internal_create_wrestler(&mut conn, champ_name, "Male", 0, 0)
+        .expect("Failed to create champion");
+
+    internal_update_title_holder(&mut conn, title.id, champ.id, None, None, Some("Pinfall"))
+        .expect("Failed to change champion");
+
+    let entries = internal_get_audit_log(&mut conn, 10).expect("Failed to load audit log");
+    let entry = entries
+        .iter()
+        .find(|entry| entry.operation == "title_change" && entry.entity_id == Some(title.id))
+        .expect("Title change should be logged");
+    assert_eq!(entry.entity, "title");
+    assert!(entry.details.as_deref().is_some_and(|details| details.contains(&champ.id.to_string())));
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(champ_name);
+}
+
+#[test]
+#[serial]
+fn test_wrestler_title_timeline_spans_titles() {
+    let test_data = TestData::new();
+    let world_title_name = "Career Timeline World Championship";
+    let midcard_title_name = "Career Timeline Midcard Championship";
+    let champ_name = "Career Timeline Champion";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(world_title_name);
+    test_data.cleanup_titles(midcard_title_name);
+    test_data.cleanup_wrestlers(champ_name);
+
+    let mut conn = test_data.get_connection();
+    let champ = internal_create_wrestler(&mut conn, champ_name, "Male", 0, 0)
+        .expect("Failed to create champion");
+    let world_title = internal_create_belt(&mut conn, world_title_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create world title");
+    let midcard_title = internal_create_belt(&mut conn, midcard_title_name, "Singles", "Intercontinental", "Male", None, None, false)
+        .expect("Failed to create midcard title");
+
+    // Midcard run first, then two world title reigns with a gap between them
+    insert_reign(&mut conn, midcard_title.id, champ.id, 120, Some(100));
+    insert_reign(&mut conn, world_title.id, champ.id, 80, Some(50));
+    insert_reign(&mut conn, world_title.id, champ.id, 10, None);
+
+    let timeline = internal_get_wrestler_title_timeline(&mut conn, champ.id)
+        .expect("Failed to fetch wrestler title timeline");
+    assert_eq!(
+        timeline.iter().map(|reign| (reign.title_id, reign.days)).collect::<Vec<_>>(),
+        vec![(midcard_title.id, 20), (world_title.id, 30), (world_title.id, 10)]
+    );
+    assert_eq!(timeline[0].title_name, midcard_title_name);
+    assert!(timeline[2].held_until.is_none());
+
+    // Cleanup
+    test_data.cleanup_titles(world_title_name);
+    test_data.cleanup_titles(midcard_title_name);
+    test_data.cleanup_wrestlers(champ_name);
+}
+
+#[test]
+#[serial]
+fn test_average_reigns_per_title() {
+    let test_data = TestData::new();
+    let hot_potato_name = "Average Reign Hot Potato Championship";
+    let stable_name = "Average Reign Stable Championship";
+    let champ_name = "Average Reign Champion";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(hot_potato_name);
+    test_data.cleanup_titles(stable_name);
+    test_data.cleanup_wrestlers(champ_name);
+
+    let mut conn = test_data.get_connection();
+    let champ = internal_create_wrestler(&mut conn, champ_name, "Male", 0, 0)
+        .expect("Failed to create champion");
+    let hot_potato = internal_create_belt(&mut conn, hot_potato_name, "Singles", "Hardcore", "Male", None, None, false)
+        .expect("Failed to create title");
+    let stable = internal_create_belt(&mut conn, stable_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+
+    // 2 and 4 day reigns average 3; 100 days ended plus 20 ongoing average 60
+    insert_reign(&mut conn, hot_potato.id, champ.id, 10, Some(8));
+    insert_reign(&mut conn, hot_potato.id, champ.id, 6, Some(2));
+    insert_reign(&mut conn, stable.id, champ.id, 120, Some(20));
+    insert_reign(&mut conn, stable.id, champ.id, 20, None);
+
+    let averages = internal_get_average_reigns(&mut conn).expect("Failed to fetch average reigns");
+    let ours: Vec<(&str, f64)> = averages
+        .iter()
+        .filter(|(name, _)| name == hot_potato_name || name == stable_name)
+        .map(|(name, days)| (name.as_str(), *days))
+        .collect();
+    assert_eq!(ours, vec![(hot_potato_name, 3.0), (stable_name, 60.0)]);
+
+    // Cleanup
+    test_data.cleanup_titles(hot_potato_name);
+    test_data.cleanup_titles(stable_name);
+    test_data.cleanup_wrestlers(champ_name);
+}
+
+#[test]
+#[serial]
+fn test_move_all_titles_between_shows() {
+    let test_data = TestData::new();
+    let old_show_name = "Merger Old Brand";
+    let new_show_name = "Merger New Brand";
+    let title_names = ["Merger World Championship", "Merger Tag Championship"];
+
+    // Cleanup any existing test data
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    test_data.cleanup_shows(old_show_name);
+    test_data.cleanup_shows(new_show_name);
+
+    let mut conn = test_data.get_connection();
+    let old_show = internal_create_show(&mut conn, old_show_name, "Brand being merged")
+        .expect("Failed to create old show");
+    let new_show = internal_create_show(&mut conn, new_show_name, "Surviving brand")
+        .expect("Failed to create new show");
+    for name in title_names {
+        internal_create_belt(&mut conn, name, "Singles", "World", "Male", Some(old_show.id), None, false)
+            .expect("Failed to create title");
+    }
+
+    let moved = internal_move_all_titles(&mut conn, old_show.id, new_show.id)
+        .expect("Failed to move titles");
+    assert_eq!(moved, 2);
+    assert!(internal_get_titles_for_show(&mut conn, old_show.id)
+        .expect("Failed to get titles")
+        .is_empty());
+    assert_eq!(
+        internal_get_titles_for_show(&mut conn, new_show.id)
+            .expect("Failed to get titles")
+            .len(),
+        2
+    );
+
+    // Unknown shows are rejected without moving anything
+    let missing = internal_move_all_titles(&mut conn, new_show.id, -1);
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+
+    // Cleanup
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    test_data.cleanup_shows(old_show_name);
+    test_data.cleanup_shows(new_show_name);
+}
+
+#[test]
+#[serial]
+fn test_undefended_titles_flags_idle_reigns() {
+    let test_data = TestData::new();
+    let show_name = "Undefended Show";
+    let defended_name = "Undefended Test Defended Championship";
+    let idle_name = "Undefended Test Idle Championship";
+    let unheld_name = "Undefended Test Unheld Championship";
+    let champ_name = "Undefended Test Champion";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in [defended_name, idle_name, unheld_name] {
+        test_data.cleanup_titles(name);
+    }
+    test_data.cleanup_wrestlers(champ_name);
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Undefended title testing")
+        .expect("Failed to create show");
+    let champ = internal_create_wrestler(&mut conn, champ_name, "Male", 0, 0)
+        .expect("Failed to create champion");
+    let defended = internal_create_belt(&mut conn, defended_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    let idle = internal_create_belt(&mut conn, idle_name, "Singles", "Intercontinental", "Male", None, None, false)
+        .expect("Failed to create title");
+    let unheld = internal_create_belt(&mut conn, unheld_name, "Singles", "United States", "Male", None, None, false)
+        .expect("Failed to create title");
+    insert_reign(&mut conn, defended.id, champ.id, 30, None);
+    insert_reign(&mut conn, idle.id, champ.id, 30, None);
+
+    // One successful defense of the defended title, a week into the reign
+    let defense = internal_create_match(&mut conn, &MatchData {
+        show_id: show.id,
+        match_name: Some("Undefended Test Defense".to_string()),
+        match_type: "Singles".to_string(),
+        match_stipulation: None,
+        scheduled_date: Some((Utc::now() - Duration::days(23)).format("%Y-%m-%d").to_string()),
+        match_order: None,
+        is_title_match: true,
+        title_id: Some(defended.id),
+    })
+    .expect("Failed to create match");
+    diesel::update(matches::table.find(defense.id))
+        .set(matches::winner_id.eq(champ.id))
+        .execute(&mut conn)
+        .expect("Failed to set winner");
+
+    let flagged: Vec<i32> = internal_get_undefended_titles(&mut conn)
+        .expect("Failed to fetch undefended titles")
+        .iter()
+        .map(|title| title.title.id)
+        .collect();
+    assert!(!flagged.contains(&defended.id));
+    assert!(flagged.contains(&idle.id));
+    assert!(flagged.contains(&unheld.id));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in [defended_name, idle_name, unheld_name] {
+        test_data.cleanup_titles(name);
+    }
+    test_data.cleanup_wrestlers(champ_name);
+}
+
+#[test]
+#[serial]
+fn test_titles_for_division_filters_and_loads_holders() {
+    let test_data = TestData::new();
+    let world_names = ["Division World Title", "Division Women's World Title"];
+    let midcard_name = "Division Midcard Title";
+    let champ_name = "Division Champion";
+
+    // Cleanup any existing test data
+    for name in world_names {
+        test_data.cleanup_titles(name);
+    }
+    test_data.cleanup_titles(midcard_name);
+    test_data.cleanup_wrestlers(champ_name);
+
+    let mut conn = test_data.get_connection();
+
+    let champ = internal_create_wrestler(&mut conn, champ_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let world = internal_create_belt(&mut conn, world_names[0], "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    let womens_world = internal_create_belt(&mut conn, world_names[1], "Singles", "World", "Female", None, None, false)
+        .expect("Failed to create title");
+    let midcard = internal_create_belt(&mut conn, midcard_name, "Singles", "Intercontinental", "Male", None, None, false)
+        .expect("Failed to create title");
+    internal_update_title_holder(&mut conn, world.id, champ.id, None, None, None)
+        .expect("Failed to crown champion");
+
+    let world_titles = internal_get_titles_for_division(&mut conn, "World")
+        .expect("Failed to fetch titles for division");
+    let world_ids: Vec<i32> = world_titles.iter().map(|title| title.title.id).collect();
+    assert!(world_ids.contains(&world.id));
+    assert!(world_ids.contains(&womens_world.id));
+    assert!(!world_ids.contains(&midcard.id));
+
+    let crowned = world_titles
+        .iter()
+        .find(|title| title.title.id == world.id)
+        .expect("World title should be listed");
+    assert_eq!(crowned.current_holders.len(), 1);
+    assert_eq!(crowned.current_holders[0].holder.wrestler_id, champ.id);
+
+    assert!(internal_get_titles_for_division(&mut conn, "No Such Division")
+        .expect("Failed to fetch titles for division")
+        .is_empty());
+
+    // Cleanup
+    for name in world_names {
+        test_data.cleanup_titles(name);
+    }
+    test_data.cleanup_titles(midcard_name);
+    test_data.cleanup_wrestlers(champ_name);
+}
+
+#[test]
+#[serial]
+fn test_count_active_reigns() {
+    let test_data = TestData::new();
+    let title_names = ["Reign Count Title One", "Reign Count Title Two"];
+    let champ_names = ["Reign Count First", "Reign Count Second"];
+
+    // Cleanup any existing test data
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let before = internal_count_active_reigns(&mut conn).expect("Failed to count active reigns");
+
+    let first = internal_create_wrestler(&mut conn, champ_names[0], "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let second = internal_create_wrestler(&mut conn, champ_names[1], "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let title_one = internal_create_belt(&mut conn, title_names[0], "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    let title_two = internal_create_belt(&mut conn, title_names[1], "Singles", "United States", "Male", None, None, false)
+        .expect("Failed to create title");
+
+    internal_update_title_holder(&mut conn, title_one.id, first.id, None, None, None)
+        .expect("Failed to crown champion");
+    internal_update_title_holder(&mut conn, title_two.id, first.id, None, None, None)
+        .expect("Failed to crown champion");
+    assert_eq!(internal_count_active_reigns(&mut conn).expect("Failed to count active reigns"), before + 2);
+
+    // A title change ends one reign and starts another
+    internal_update_title_holder(&mut conn, title_one.id, second.id, None, None, None)
+        .expect("Failed to change champion");
+    assert_eq!(internal_count_active_reigns(&mut conn).expect("Failed to count active reigns"), before + 2);
+
+    // Cleanup
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_misaligned_champions_skip_cross_brand_titles() {
+    let test_data = TestData::new();
+    let show_names = ["Misaligned Raw", "Misaligned SmackDown"];
+    let title_names = ["Misaligned Raw Title", "Misaligned Home Title", "Misaligned Cross-Brand Title"];
+    let champ_names = ["Misaligned Drafted", "Misaligned Loyal"];
+
+    // Cleanup any existing test data
+    for name in show_names {
+        test_data.cleanup_shows(name);
+    }
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let raw = internal_create_show(&mut conn, show_names[0], "Misaligned testing")
+        .expect("Failed to create show");
+    let smackdown = internal_create_show(&mut conn, show_names[1], "Misaligned testing")
+        .expect("Failed to create show");
+    let drafted = internal_create_wrestler(&mut conn, champ_names[0], "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let loyal = internal_create_wrestler(&mut conn, champ_names[1], "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    internal_assign_wrestler_to_show(&mut conn, smackdown.id, drafted.id)
+        .expect("Failed to assign wrestler");
+    internal_assign_wrestler_to_show(&mut conn, raw.id, loyal.id)
+        .expect("Failed to assign wrestler");
+
+    let raw_title = internal_create_belt(&mut conn, title_names[0], "Singles", "World", "Male", Some(raw.id), None, false)
+        .expect("Failed to create title");
+    let home_title = internal_create_belt(&mut conn, title_names[1], "Singles", "United States", "Male", Some(raw.id), None, false)
+        .expect("Failed to create title");
+    let cross_brand = internal_create_belt(&mut conn, title_names[2], "Singles", "Intercontinental", "Male", None, None, false)
+        .expect("Failed to create title");
+
+    // The drafted champion holds a Raw title from SmackDown
+    internal_update_title_holder(&mut conn, raw_title.id, drafted.id, None, None, None)
+        .expect("Failed to crown champion");
+    internal_update_title_holder(&mut conn, home_title.id, loyal.id, None, None, None)
+        .expect("Failed to crown champion");
+    internal_update_title_holder(&mut conn, cross_brand.id, drafted.id, None, None, None)
+        .expect("Failed to crown champion");
+
+    let misaligned: Vec<(i32, i32)> = internal_get_misaligned_champions(&mut conn)
+        .expect("Failed to find misaligned champions")
+        .iter()
+        .map(|(title, wrestler)| (title.id, wrestler.id))
+        .collect();
+    assert_eq!(misaligned, vec![(raw_title.id, drafted.id)]);
+
+    // Cleanup
+    for name in show_names {
+        test_data.cleanup_shows(name);
+    }
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_champions_flat_splits_tag_teams_and_skips_vacant() {
+    let test_data = TestData::new();
+    let title_names = ["Ticker Tag Titles", "Ticker World Title", "Ticker Vacant Title"];
+    let champ_names = ["Ticker Tag One", "Ticker Tag Two", "Ticker World Champ"];
+
+    // Cleanup any existing test data
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let mut champ_ids = Vec::new();
+    for name in champ_names {
+        champ_ids.push(
+            internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+                .expect("Failed to create wrestler")
+                .id,
+        );
+    }
+    let tag = internal_create_belt(&mut conn, title_names[0], "Tag Team", "World Tag Team", "Male", None, None, false)
+        .expect("Failed to create title");
+    let world = internal_create_belt(&mut conn, title_names[1], "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    internal_create_belt(&mut conn, title_names[2], "Singles", "United States", "Male", None, None, false)
+        .expect("Failed to create title");
+
+    insert_reign(&mut conn, tag.id, champ_ids[0], 10, None);
+    insert_reign(&mut conn, tag.id, champ_ids[1], 10, None);
+    insert_reign(&mut conn, world.id, champ_ids[2], 30, None);
+
+    let ticker: Vec<(String, String, i32)> = internal_get_champions_flat(&mut conn)
+        .expect("Failed to list champions")
+        .into_iter()
+        .filter(|entry| entry.title_name.starts_with("Ticker"))
+        .map(|entry| (entry.title_name, entry.wrestler_name, entry.days_held))
+        .collect();
+    assert_eq!(ticker.len(), 3);
+
+    // The world title outranks the tag titles, and the vacant title is skipped
+    assert_eq!(ticker[0], (title_names[1].to_string(), champ_names[2].to_string(), 30));
+    let mut tag_holders: Vec<&str> = ticker[1..]
+        .iter()
+        .map(|(title_name, wrestler_name, days)| {
+            assert_eq!(title_name, title_names[0]);
+            assert_eq!(*days, 10);
+            wrestler_name.as_str()
+        })
+        .collect();
+    tag_holders.sort();
+    assert_eq!(tag_holders, vec![champ_names[0], champ_names[1]]);
+
+    // Cleanup
+    for name in title_names {
+        test_data.cleanup_titles(name);
+    }
+    for name in champ_names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
@@ -1,6 +1,10 @@
+use diesel::prelude::*;
 use serial_test::serial;
 
-use wwe_universe_manager_lib::db::{internal_create_wrestler, internal_create_enhanced_wrestler, internal_create_signature_move, internal_get_wrestlers};
+use wwe_universe_manager_lib::db::{internal_create_wrestler, internal_create_enhanced_wrestler, internal_create_signature_move, internal_get_wrestlers, internal_get_wrestler_by_id, internal_update_wrestler_full, internal_get_signature_moves_for_wrestler, internal_set_signature_move_active, internal_update_wrestler_power_ratings, internal_create_belt, internal_update_title_holder, internal_get_free_agents, internal_create_wrestler_with_moves, internal_set_wrestler_status, internal_get_inactive_wrestlers, internal_create_show, internal_create_match, internal_add_wrestler_to_match, internal_get_rating_averages, internal_clone_wrestler, internal_get_deletion_impact, internal_assign_wrestler_to_show, internal_get_never_champions, internal_get_experience_extremes, internal_update_wrestler_basic_stats, internal_compare_wrestlers, internal_get_busiest_wrestlers, internal_get_benchwarmers, internal_get_wrestlers_by_height_range, internal_get_frequent_opponents, internal_get_wrestlers_above_rating};
+use wwe_universe_manager_lib::error::AppError;
+use wwe_universe_manager_lib::models::{parse_height_inches, EnhancedWrestlerData, MatchData, Wrestler};
+use wwe_universe_manager_lib::types::{normalize_title_gender, normalize_wrestler_gender, Gender};
 
 mod test_helpers;
 use test_helpers::*;
@@ -110,7 +114,7 @@ fn test_create_wrestler_with_different_genders() {
 
 #[test]
 #[serial]
-fn test_create_wrestler_negative_stats_should_work() {
+fn test_create_wrestler_negative_stats_rejected() {
     let test_data = TestData::new();
     let wrestler_name = "Negative Stats Wrestler";
 
@@ -119,18 +123,55 @@ fn test_create_wrestler_negative_stats_should_work() {
 
     let mut conn = test_data.get_connection();
 
-    // Note: Depending on your business logic, you might want to prevent negative stats
-    // This test assumes they are allowed, adjust as needed
-    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Male", -1, -1)
-        .expect("Failed to create wrestler with negative stats");
+    let result = internal_create_wrestler(&mut conn, wrestler_name, "Male", -1, 0);
+    assert!(matches!(result, Err(AppError::Validation(ref msg)) if msg.starts_with("wins")));
 
-    assert_eq!(wrestler.wins, -1);
-    assert_eq!(wrestler.losses, -1);
+    let result = internal_create_wrestler(&mut conn, wrestler_name, "Male", 0, -1);
+    assert!(matches!(result, Err(AppError::Validation(ref msg)) if msg.starts_with("losses")));
 
     // Cleanup
     test_data.cleanup_wrestlers(wrestler_name);
 }
 
+#[test]
+#[serial]
+fn test_create_wrestler_empty_name_rejected() {
+    let test_data = TestData::new();
+    let mut conn = test_data.get_connection();
+
+    let result = internal_create_wrestler(&mut conn, "", "Male", 0, 0);
+    assert!(matches!(result, Err(AppError::Validation(ref msg)) if msg.starts_with("name")));
+}
+
+#[test]
+#[serial]
+fn test_create_wrestler_whitespace_name_rejected() {
+    let test_data = TestData::new();
+    let mut conn = test_data.get_connection();
+
+    let result = internal_create_wrestler(&mut conn, "   \t  ", "Female", 0, 0);
+    assert!(matches!(result, Err(AppError::Validation(ref msg)) if msg.starts_with("name")));
+
+    // Surrounding whitespace is trimmed from otherwise valid names
+    let wrestler = internal_create_wrestler(&mut conn, "  Trimmed Wrestler  ", "Female", 0, 0)
+        .expect("Failed to create wrestler");
+    assert_eq!(wrestler.name, "Trimmed Wrestler");
+
+    // Cleanup
+    test_data.cleanup_wrestlers("Trimmed Wrestler");
+}
+
+#[test]
+#[serial]
+fn test_create_wrestler_overlong_name_rejected() {
+    let test_data = TestData::new();
+    let mut conn = test_data.get_connection();
+
+    let long_name = "A".repeat(101);
+    let result = internal_create_wrestler(&mut conn, &long_name, "Male", 0, 0);
+    assert!(matches!(result, Err(AppError::Validation(ref msg)) if msg.starts_with("name")));
+}
+
 #[test]
 #[serial]
 fn test_create_enhanced_wrestler_success() {
@@ -397,3 +438,918 @@ fn test_fetch_wrestlers() {
     test_data.cleanup_wrestlers(wrestler2_name);
     test_data.cleanup_wrestlers(wrestler3_name);
 }
+
+#[test]
+#[serial]
+fn test_update_wrestler_full_persists_all_fields() {
+    let test_data = TestData::new();
+    let wrestler_name = "Full Update Wrestler";
+    let updated_name = "Fully Updated Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_wrestlers(wrestler_name);
+    test_data.cleanup_wrestlers(updated_name);
+
+    let mut conn = test_data.get_connection();
+
+    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Male", 7, 4)
+        .expect("Failed to create wrestler");
+
+    let update = EnhancedWrestlerData {
+        name: updated_name.to_string(),
+        gender: Gender::Female,
+        real_name: Some("Jane Update".to_string()),
+        nickname: Some("The Editor".to_string()),
+        height: Some("5'9\"".to_string()),
+        weight: Some("150 lbs".to_string()),
+        debut_year: Some(2015),
+        strength: Some(4),
+        speed: Some(8),
+        agility: Some(9),
+        stamina: Some(7),
+        charisma: Some(10),
+        technique: Some(6),
+        biography: Some("Updated in a single save.".to_string()),
+    };
+
+    internal_update_wrestler_full(&mut conn, wrestler.id, &update)
+        .expect("Failed to update wrestler");
+
+    let stored = internal_get_wrestler_by_id(&mut conn, wrestler.id)
+        .expect("Failed to fetch wrestler")
+        .expect("Wrestler should exist");
+
+    assert_eq!(stored.name, updated_name);
+    assert_eq!(stored.gender, "Female");
+    assert_eq!(stored.real_name, update.real_name);
+    assert_eq!(stored.nickname, update.nickname);
+    assert_eq!(stored.height, update.height);
+    assert_eq!(stored.weight, update.weight);
+    assert_eq!(stored.debut_year, update.debut_year);
+    assert_eq!(stored.strength, update.strength);
+    assert_eq!(stored.speed, update.speed);
+    assert_eq!(stored.agility, update.agility);
+    assert_eq!(stored.stamina, update.stamina);
+    assert_eq!(stored.charisma, update.charisma);
+    assert_eq!(stored.technique, update.technique);
+    assert_eq!(stored.biography, update.biography);
+    // Record is not part of the profile update
+    assert_eq!(stored.wins, 7);
+    assert_eq!(stored.losses, 4);
+
+    // Cleanup
+    test_data.cleanup_wrestlers(updated_name);
+}
+
+#[test]
+#[serial]
+fn test_retired_signature_moves_hidden_by_default() {
+    let test_data = TestData::new();
+    let wrestler_name = "Retiring Finisher Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_wrestlers(wrestler_name);
+
+    let mut conn = test_data.get_connection();
+
+    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let old_finisher = internal_create_signature_move(&mut conn, wrestler.id, "Old Finisher", "primary")
+        .expect("Failed to create old finisher");
+    internal_create_signature_move(&mut conn, wrestler.id, "New Finisher", "primary")
+        .expect("Failed to create new finisher");
+    assert!(old_finisher.is_active);
+
+    let retired = internal_set_signature_move_active(&mut conn, old_finisher.id.unwrap(), false)
+        .expect("Failed to retire move");
+    assert!(!retired.is_active);
+
+    let active_moves = internal_get_signature_moves_for_wrestler(&mut conn, wrestler.id, false)
+        .expect("Failed to fetch active moves");
+    assert_eq!(active_moves.len(), 1);
+    assert_eq!(active_moves[0].move_name, "New Finisher");
+
+    let all_moves = internal_get_signature_moves_for_wrestler(&mut conn, wrestler.id, true)
+        .expect("Failed to fetch all moves");
+    assert_eq!(all_moves.len(), 2);
+
+    // Cleanup
+    test_data.cleanup_signature_moves(wrestler.id);
+    test_data.cleanup_wrestlers(wrestler_name);
+}
+
+#[test]
+#[serial]
+fn test_free_agents_include_record_and_titles() {
+    let test_data = TestData::new();
+    let star_name = "Free Agent Star";
+    let prospect_name = "Free Agent Prospect";
+    let title_name = "Free Agent Championship";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(star_name);
+    test_data.cleanup_wrestlers(prospect_name);
+
+    let mut conn = test_data.get_connection();
+
+    let star = internal_create_wrestler(&mut conn, star_name, "Male", 15, 5)
+        .expect("Failed to create star");
+    let prospect = internal_create_wrestler(&mut conn, prospect_name, "Male", 0, 0)
+        .expect("Failed to create prospect");
+    internal_update_wrestler_power_ratings(&mut conn, star.id, Some(9), Some(8), Some(9), Some(8), Some(10), Some(10))
+        .expect("Failed to rate star");
+    internal_update_wrestler_power_ratings(&mut conn, prospect.id, Some(5), Some(6), Some(5), Some(6), Some(5), Some(6))
+        .expect("Failed to rate prospect");
+
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "World", "Male", None, None, false)
+        .expect("Failed to create title");
+    internal_update_title_holder(&mut conn, title.id, star.id, None, None, None)
+        .expect("Failed to crown star");
+
+    let free_agents = internal_get_free_agents(&mut conn).expect("Failed to fetch free agents");
+    let star_pos = free_agents.iter().position(|fa| fa.wrestler.id == star.id)
+        .expect("Star should be a free agent");
+    let prospect_pos = free_agents.iter().position(|fa| fa.wrestler.id == prospect.id)
+        .expect("Prospect should be a free agent");
+    assert!(star_pos < prospect_pos);
+
+    let star_agent = &free_agents[star_pos];
+    assert_eq!(star_agent.win_rate, Some(0.75));
+    assert_eq!(star_agent.overall_rating, Some(9.0));
+    assert_eq!(star_agent.current_titles, vec![title_name.to_string()]);
+
+    let prospect_agent = &free_agents[prospect_pos];
+    assert_eq!(prospect_agent.win_rate, None);
+    assert!(prospect_agent.current_titles.is_empty());
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_wrestlers(star_name);
+    test_data.cleanup_wrestlers(prospect_name);
+}
+
+#[test]
+#[serial]
+fn test_create_wrestler_with_invalid_move_creates_nothing() {
+    let test_data = TestData::new();
+    let wrestler_name = "Atomic Moves Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_wrestlers(wrestler_name);
+
+    let mut conn = test_data.get_connection();
+
+    let wrestler_data = EnhancedWrestlerData {
+        name: wrestler_name.to_string(),
+        gender: Gender::Male,
+        real_name: None,
+        nickname: None,
+        height: None,
+        weight: None,
+        debut_year: None,
+        strength: None,
+        speed: None,
+        agility: None,
+        stamina: None,
+        charisma: None,
+        technique: None,
+        biography: None,
+    };
+    let moves = vec![
+        ("Atomic Driver".to_string(), "primary".to_string()),
+        ("Atomic Kick".to_string(), "tertiary".to_string()),
+    ];
+
+    let result = internal_create_wrestler_with_moves(&mut conn, &wrestler_data, &moves);
+    assert!(matches!(result, Err(AppError::Validation(_))));
+
+    let wrestlers = internal_get_wrestlers(&mut conn).expect("Failed to fetch wrestlers");
+    assert!(wrestlers.iter().all(|w| w.name != wrestler_name));
+
+    // With valid moves both the wrestler and the moves are created
+    let moves = vec![
+        ("Atomic Driver".to_string(), "Primary".to_string()),
+        ("Atomic Kick".to_string(), "SECONDARY".to_string()),
+    ];
+    let (wrestler, signature_moves) = internal_create_wrestler_with_moves(&mut conn, &wrestler_data, &moves)
+        .expect("Failed to create wrestler with moves");
+    assert_eq!(signature_moves.len(), 2);
+    assert!(signature_moves.iter().all(|m| m.wrestler_id == wrestler.id));
+
+    // Mixed-case move types are stored in their canonical lowercase form
+    assert_eq!(signature_moves[0].move_type, "primary");
+    assert_eq!(signature_moves[1].move_type, "secondary");
+
+    // Cleanup
+    test_data.cleanup_signature_moves(wrestler.id);
+    test_data.cleanup_wrestlers(wrestler_name);
+}
+
+#[test]
+#[serial]
+fn test_inactive_wrestlers_excludes_recent_and_retired() {
+    let test_data = TestData::new();
+    let show_name = "Inactivity Show";
+    let recent_name = "Recently Booked Wrestler";
+    let idle_name = "Long Idle Wrestler";
+    let retired_name = "Retired Idle Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in [recent_name, idle_name, retired_name] {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let show = internal_create_show(&mut conn, show_name, "Inactivity testing")
+        .expect("Failed to create show");
+    let recent = internal_create_wrestler(&mut conn, recent_name, "Male", 0, 0)
+        .expect("Failed to create recent wrestler");
+    let idle = internal_create_wrestler(&mut conn, idle_name, "Male", 0, 0)
+        .expect("Failed to create idle wrestler");
+    let retired = internal_create_wrestler(&mut conn, retired_name, "Male", 0, 0)
+        .expect("Failed to create retired wrestler");
+    internal_set_wrestler_status(&mut conn, retired.id, "Retired")
+        .expect("Failed to retire wrestler");
+    assert!(internal_set_wrestler_status(&mut conn, retired.id, "Suspended").is_err());
+
+    let today = chrono::Utc::now().date_naive();
+    for (wrestler_id, days_ago) in [(recent.id, 2), (idle.id, 60)] {
+        let booked = internal_create_match(&mut conn, &MatchData {
+            show_id: show.id,
+            match_name: Some(format!("Inactivity Match {}", days_ago)),
+            match_type: "Singles".to_string(),
+            match_stipulation: None,
+            scheduled_date: Some((today - chrono::Duration::days(days_ago)).format("%Y-%m-%d").to_string()),
+            match_order: Some(1),
+            is_title_match: false,
+            title_id: None,
+        }).expect("Failed to create match");
+        internal_add_wrestler_to_match(&mut conn, booked.id, wrestler_id, None, Some(1))
+            .expect("Failed to add wrestler to match");
+    }
+
+    let inactive = internal_get_inactive_wrestlers(&mut conn, 30)
+        .expect("Failed to fetch inactive wrestlers");
+    assert!(inactive.iter().any(|w| w.id == idle.id));
+    assert!(inactive.iter().all(|w| w.id != recent.id));
+    assert!(inactive.iter().all(|w| w.id != retired.id));
+
+    // A window longer than any calendar flags nobody who has wrestled
+    let inactive = internal_get_inactive_wrestlers(&mut conn, i64::MAX)
+        .expect("Failed to fetch inactive wrestlers");
+    assert!(inactive.iter().all(|w| w.id != idle.id && w.id != recent.id));
+
+    assert!(matches!(
+        internal_get_inactive_wrestlers(&mut conn, -1),
+        Err(AppError::Validation(_))
+    ));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in [recent_name, idle_name, retired_name] {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_rating_averages_ignore_missing_ratings() {
+    use wwe_universe_manager_lib::schema::wrestlers;
+
+    let test_data = TestData::new();
+    let names = ["Average One", "Average Two", "Average Three"];
+
+    // Cleanup any existing test data
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let ratings = [
+        [Some(4), Some(6), Some(8), Some(2), Some(10), Some(1)],
+        [Some(8), Some(6), Some(4), Some(4), Some(10), Some(2)],
+        [None, Some(9), Some(3), Some(6), Some(10), None],
+    ];
+    for (name, [strength, speed, agility, stamina, charisma, technique]) in names.into_iter().zip(ratings) {
+        let wrestler = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create wrestler");
+        diesel::update(wrestlers::table.find(wrestler.id))
+            .set((
+                wrestlers::strength.eq(strength),
+                wrestlers::speed.eq(speed),
+                wrestlers::agility.eq(agility),
+                wrestlers::stamina.eq(stamina),
+                wrestlers::charisma.eq(charisma),
+                wrestlers::technique.eq(technique),
+            ))
+            .execute(&mut conn)
+            .expect("Failed to set ratings");
+    }
+
+    let averages = internal_get_rating_averages(&mut conn).expect("Failed to compute averages");
+    assert_eq!(averages.strength, Some(6.0));
+    assert_eq!(averages.speed, Some(7.0));
+    assert_eq!(averages.agility, Some(5.0));
+    assert_eq!(averages.stamina, Some(4.0));
+    assert_eq!(averages.charisma, Some(10.0));
+    assert_eq!(averages.technique, Some(1.5));
+
+    // Cleanup
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_clone_wrestler_copies_ratings_but_not_record() {
+    let test_data = TestData::new();
+    let source_name = "Clone Source Wrestler";
+    let clone_name = "Clone Copy Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_wrestlers(source_name);
+    test_data.cleanup_wrestlers(clone_name);
+
+    let mut conn = test_data.get_connection();
+
+    let source = internal_create_wrestler(&mut conn, source_name, "Female", 25, 7)
+        .expect("Failed to create source wrestler");
+    internal_update_wrestler_power_ratings(&mut conn, source.id, Some(3), Some(9), Some(8), Some(7), Some(10), Some(6))
+        .expect("Failed to rate source wrestler");
+    internal_create_signature_move(&mut conn, source.id, "Template Finisher", "primary")
+        .expect("Failed to create source move");
+
+    let clone = internal_clone_wrestler(&mut conn, source.id, clone_name, true)
+        .expect("Failed to clone wrestler");
+
+    assert_ne!(clone.id, source.id);
+    assert_eq!(clone.name, clone_name);
+    assert_eq!(clone.gender, "Female");
+    assert_eq!((clone.wins, clone.losses), (0, 0));
+    assert_eq!(clone.is_user_created, Some(true));
+    assert_eq!(
+        (clone.strength, clone.speed, clone.agility, clone.stamina, clone.charisma, clone.technique),
+        (Some(3), Some(9), Some(8), Some(7), Some(10), Some(6))
+    );
+
+    let clone_moves = internal_get_signature_moves_for_wrestler(&mut conn, clone.id, false)
+        .expect("Failed to fetch cloned moves");
+    assert_eq!(clone_moves.len(), 1);
+    assert_eq!(clone_moves[0].move_name, "Template Finisher");
+
+    assert!(matches!(
+        internal_clone_wrestler(&mut conn, -1, "Clone Of Nobody", false),
+        Err(AppError::NotFound(_))
+    ));
+
+    // Cleanup
+    test_data.cleanup_signature_moves(source.id);
+    test_data.cleanup_signature_moves(clone.id);
+    test_data.cleanup_wrestlers(source_name);
+    test_data.cleanup_wrestlers(clone_name);
+}
+
+#[test]
+#[serial]
+fn test_gender_is_normalized_on_create() {
+    let test_data = TestData::new();
+    let wrestler_name = "Normalized Gender Wrestler";
+    let enhanced_name = "Normalized Gender Enhanced Wrestler";
+
+    // Cleanup any existing test data
+    test_data.cleanup_wrestlers(wrestler_name);
+    test_data.cleanup_wrestlers(enhanced_name);
+
+    for (input, expected) in [("male", "Male"), ("MALE", "Male"), ("M", "Male"), (" f ", "Female"), ("other", "Other")] {
+        assert_eq!(normalize_wrestler_gender(input).expect("Failed to normalize gender"), expected);
+    }
+    assert!(matches!(normalize_wrestler_gender(""), Err(AppError::Validation(_))));
+    assert!(matches!(normalize_wrestler_gender("robot"), Err(AppError::Validation(_))));
+    assert!(matches!(normalize_wrestler_gender("Mixed"), Err(AppError::Validation(_))));
+
+    // Titles use Mixed in place of Other
+    for (input, expected) in [("male", "Male"), ("F", "Female"), ("MIXED", "Mixed")] {
+        assert_eq!(normalize_title_gender(input).expect("Failed to normalize title gender"), expected);
+    }
+    assert!(matches!(normalize_title_gender("Other"), Err(AppError::Validation(_))));
+
+    let mut conn = test_data.get_connection();
+    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "FEMALE", 0, 0)
+        .expect("Failed to create wrestler");
+    assert_eq!(wrestler.gender, "Female");
+
+    let result = internal_create_wrestler(&mut conn, "Unknown Gender Wrestler", "robot", 0, 0);
+    assert!(matches!(result, Err(AppError::Validation(_))));
+
+    // The enhanced creation path stores the same canonical value
+    let enhanced = internal_create_enhanced_wrestler(
+        &mut conn, enhanced_name, "Real Name", "Nickname", "male", 0, 0,
+        "6'0\"", "220 lbs", 2020, 5, 5, 5, 5, 5, 5, "Biography", false,
+    ).expect("Failed to create enhanced wrestler");
+    assert_eq!(enhanced.gender, "Male");
+
+    // Cleanup
+    test_data.cleanup_wrestlers(wrestler_name);
+    test_data.cleanup_wrestlers(enhanced_name);
+}
+
+#[test]
+#[serial]
+fn test_deletion_impact_counts_associations() {
+    let test_data = TestData::new();
+    let wrestler_name = "Deletion Impact Wrestler";
+    let show_name = "Deletion Impact Show";
+    let title_name = "Deletion Impact Championship";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+
+    let mut conn = test_data.get_connection();
+    let wrestler = internal_create_wrestler(&mut conn, wrestler_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+
+    let empty = internal_get_deletion_impact(&mut conn, wrestler.id)
+        .expect("Failed to get deletion impact");
+    assert_eq!((empty.match_count, empty.active_reigns, empty.roster_assignments, empty.signature_moves), (0, 0, 0, 0));
+
+    let show = internal_create_show(&mut conn, show_name, "Deletion impact testing")
+        .expect("Failed to create show");
+    internal_assign_wrestler_to_show(&mut conn, show.id, wrestler.id)
+        .expect("Failed to assign wrestler to show");
+    for index in 0..3 {
+        let booked = internal_create_match(&mut conn, &MatchData {
+            show_id: show.id,
+            match_name: Some(format!("Deletion Impact Match {}", index)),
+            match_type: "Singles".to_string(),
+            match_stipulation: None,
+            scheduled_date: None,
+            match_order: Some(index + 1),
+            is_title_match: false,
+            title_id: None,
+        }).expect("Failed to create match");
+        internal_add_wrestler_to_match(&mut conn, booked.id, wrestler.id, None, Some(1))
+            .expect("Failed to add wrestler to match");
+    }
+    internal_create_signature_move(&mut conn, wrestler.id, "Impact Driver", "primary")
+        .expect("Failed to create signature move");
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "Hardcore", "Male", None, None, false)
+        .expect("Failed to create title");
+    internal_update_title_holder(&mut conn, title.id, wrestler.id, None, None, None)
+        .expect("Failed to crown champion");
+
+    let impact = internal_get_deletion_impact(&mut conn, wrestler.id)
+        .expect("Failed to get deletion impact");
+    assert_eq!(impact.match_count, 3);
+    assert_eq!(impact.active_reigns, 1);
+    assert_eq!(impact.roster_assignments, 1);
+    assert_eq!(impact.signature_moves, 1);
+
+    // Unknown wrestlers are reported rather than previewed as empty
+    assert!(internal_get_deletion_impact(&mut conn, 99999).is_err());
+
+    // Cleanup
+    test_data.cleanup_signature_moves(wrestler.id);
+    test_data.cleanup_titles(title_name);
+    test_data.cleanup_shows(show_name);
+    test_data.cleanup_wrestlers(wrestler_name);
+}
+
+#[test]
+#[serial]
+fn test_never_champions_excludes_former_champions() {
+    let test_data = TestData::new();
+    let title_name = "Never Champion Test Title";
+    let former_name = "Never Champion Former";
+    let prospect_name = "Never Champion Prospect";
+    let female_name = "Never Champion Female";
+    let retired_name = "Never Champion Retired";
+
+    // Cleanup any existing test data
+    test_data.cleanup_titles(title_name);
+    for name in [former_name, prospect_name, female_name, retired_name] {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let former = internal_create_wrestler(&mut conn, former_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let prospect = internal_create_wrestler(&mut conn, prospect_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let female = internal_create_wrestler(&mut conn, female_name, "Female", 0, 0)
+        .expect("Failed to create wrestler");
+    let retired = internal_create_wrestler(&mut conn, retired_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    internal_set_wrestler_status(&mut conn, retired.id, "Retired").expect("Failed to retire wrestler");
+
+    // A former champion stays excluded after losing the title
+    let title = internal_create_belt(&mut conn, title_name, "Singles", "Hardcore", "Mixed", None, None, false)
+        .expect("Failed to create title");
+    internal_update_title_holder(&mut conn, title.id, former.id, None, None, None)
+        .expect("Failed to crown champion");
+    internal_update_title_holder(&mut conn, title.id, female.id, None, None, None)
+        .expect("Failed to change champion");
+
+    let ids = |wrestlers: Vec<Wrestler>| -> Vec<i32> {
+        wrestlers.into_iter().map(|w| w.id).collect()
+    };
+
+    let all = internal_get_never_champions(&mut conn, None, false).expect("Failed to load never champions");
+    assert_eq!(ids(all), vec![prospect.id, retired.id]);
+
+    let active_only = internal_get_never_champions(&mut conn, Some("male"), true)
+        .expect("Failed to load never champions");
+    assert_eq!(ids(active_only), vec![prospect.id]);
+
+    let female_only = internal_get_never_champions(&mut conn, Some("Female"), false)
+        .expect("Failed to load never champions");
+    assert!(female_only.is_empty());
+
+    // Cleanup
+    test_data.cleanup_titles(title_name);
+    for name in [former_name, prospect_name, female_name, retired_name] {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_experience_extremes_use_debut_year() {
+    let test_data = TestData::new();
+    let roster = [
+        ("Experience Veteran", Some(1995), "Active"),
+        ("Experience Midcarder", Some(2010), "Active"),
+        ("Experience Rookie", Some(2023), "Active"),
+        ("Experience Legend", Some(1980), "Retired"),
+        ("Experience Unknown", None, "Active"),
+    ];
+
+    // Cleanup any existing test data
+    for (name, _, _) in roster {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+
+    let empty = internal_get_experience_extremes(&mut conn).expect("Failed to get experience extremes");
+    assert!(empty.most_experienced.is_none());
+    assert!(empty.least_experienced.is_none());
+
+    for (name, debut_year, status) in roster {
+        let wrestler = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create wrestler");
+        internal_update_wrestler_basic_stats(&mut conn, wrestler.id, None, None, debut_year, 0, 0)
+            .expect("Failed to set debut year");
+        internal_set_wrestler_status(&mut conn, wrestler.id, status).expect("Failed to set status");
+    }
+
+    // The retired legend and the wrestler without a debut year are ignored
+    let extremes = internal_get_experience_extremes(&mut conn).expect("Failed to get experience extremes");
+    let most = extremes.most_experienced.expect("Expected a most experienced wrestler");
+    let least = extremes.least_experienced.expect("Expected a least experienced wrestler");
+    assert_eq!(most.name, "Experience Veteran");
+    assert_eq!(most.debut_year, Some(1995));
+    assert_eq!(least.name, "Experience Rookie");
+    assert_eq!(least.debut_year, Some(2023));
+
+    // Cleanup
+    for (name, _, _) in roster {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_compare_wrestlers_deltas() {
+    let test_data = TestData::new();
+    let a_name = "Comparison Powerhouse";
+    let b_name = "Comparison Highflyer";
+
+    // Cleanup any existing test data
+    test_data.cleanup_wrestlers(a_name);
+    test_data.cleanup_wrestlers(b_name);
+
+    let mut conn = test_data.get_connection();
+    let a = internal_create_wrestler(&mut conn, a_name, "Male", 6, 2)
+        .expect("Failed to create wrestler");
+    let b = internal_create_wrestler(&mut conn, b_name, "Male", 3, 3)
+        .expect("Failed to create wrestler");
+    internal_update_wrestler_power_ratings(&mut conn, a.id, Some(9), Some(4), Some(5), Some(8), Some(7), None)
+        .expect("Failed to set ratings");
+    internal_update_wrestler_power_ratings(&mut conn, b.id, Some(5), Some(9), Some(9), Some(6), Some(7), Some(8))
+        .expect("Failed to set ratings");
+
+    let comparison = internal_compare_wrestlers(&mut conn, a.id, b.id).expect("Failed to compare wrestlers");
+    assert_eq!(comparison.wrestler_a.id, a.id);
+    assert_eq!(comparison.wrestler_b.id, b.id);
+    assert_eq!(comparison.rating_deltas.strength, Some(4));
+    assert_eq!(comparison.rating_deltas.speed, Some(-5));
+    assert_eq!(comparison.rating_deltas.charisma, Some(0));
+    // Missing ratings are skipped rather than treated as 0
+    assert_eq!(comparison.rating_deltas.technique, None);
+    assert_eq!(comparison.wins_delta, 3);
+    assert_eq!(comparison.losses_delta, -1);
+    assert_eq!(comparison.win_rate_delta, Some(0.25));
+
+    assert!(internal_compare_wrestlers(&mut conn, a.id, 99999).is_err());
+
+    // Cleanup
+    test_data.cleanup_wrestlers(a_name);
+    test_data.cleanup_wrestlers(b_name);
+}
+
+#[test]
+#[serial]
+fn test_busiest_wrestlers_ranked_by_bookings() {
+    let test_data = TestData::new();
+    let show_name = "Busiest Show";
+    let workhorse_name = "Busiest Workhorse";
+    let regular_name = "Busiest Regular";
+    let idle_name = "Busiest Idle";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in [workhorse_name, regular_name, idle_name] {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Workload testing").expect("Failed to create show");
+    let workhorse = internal_create_wrestler(&mut conn, workhorse_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let regular = internal_create_wrestler(&mut conn, regular_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    internal_create_wrestler(&mut conn, idle_name, "Male", 0, 0).expect("Failed to create wrestler");
+
+    for index in 0..3 {
+        let booked = internal_create_match(&mut conn, &MatchData {
+            show_id: show.id,
+            match_name: Some(format!("Busiest Match {}", index)),
+            match_type: "Singles".to_string(),
+            match_stipulation: None,
+            scheduled_date: None,
+            match_order: Some(index + 1),
+            is_title_match: false,
+            title_id: None,
+        }).expect("Failed to create match");
+        internal_add_wrestler_to_match(&mut conn, booked.id, workhorse.id, None, Some(1))
+            .expect("Failed to add wrestler to match");
+        if index == 0 {
+            internal_add_wrestler_to_match(&mut conn, booked.id, regular.id, None, Some(2))
+                .expect("Failed to add wrestler to match");
+        }
+    }
+
+    let busiest = internal_get_busiest_wrestlers(&mut conn, 10).expect("Failed to rank wrestlers");
+    let ranking: Vec<(i32, i64)> = busiest.iter().map(|(w, count)| (w.id, *count)).collect();
+    assert_eq!(ranking, vec![(workhorse.id, 3), (regular.id, 1)]);
+
+    let top = internal_get_busiest_wrestlers(&mut conn, 1).expect("Failed to rank wrestlers");
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].0.id, workhorse.id);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in [workhorse_name, regular_name, idle_name] {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_benchwarmers_are_rostered_but_unbooked() {
+    let test_data = TestData::new();
+    let show_name = "Bench Show";
+    let booked_name = "Bench Booked";
+    let benched_name = "Bench Warmer";
+    let free_agent_name = "Bench Free Agent";
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in [booked_name, benched_name, free_agent_name] {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Benchwarmer testing").expect("Failed to create show");
+    let booked = internal_create_wrestler(&mut conn, booked_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let benched = internal_create_wrestler(&mut conn, benched_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    let free_agent = internal_create_wrestler(&mut conn, free_agent_name, "Male", 0, 0)
+        .expect("Failed to create wrestler");
+    for wrestler_id in [booked.id, benched.id] {
+        internal_assign_wrestler_to_show(&mut conn, show.id, wrestler_id)
+            .expect("Failed to assign wrestler to show");
+    }
+
+    let bench_match = internal_create_match(&mut conn, &MatchData {
+        show_id: show.id,
+        match_name: Some("Bench Match".to_string()),
+        match_type: "Singles".to_string(),
+        match_stipulation: None,
+        scheduled_date: None,
+        match_order: Some(1),
+        is_title_match: false,
+        title_id: None,
+    }).expect("Failed to create match");
+    internal_add_wrestler_to_match(&mut conn, bench_match.id, booked.id, None, Some(1))
+        .expect("Failed to add wrestler to match");
+
+    let bench_ids: Vec<i32> = internal_get_benchwarmers(&mut conn)
+        .expect("Failed to fetch benchwarmers")
+        .iter()
+        .map(|w| w.id)
+        .collect();
+    assert!(bench_ids.contains(&benched.id));
+    assert!(!bench_ids.contains(&booked.id));
+    assert!(!bench_ids.contains(&free_agent.id));
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in [booked_name, benched_name, free_agent_name] {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+fn test_parse_height_inches_formats() {
+    // Feet and inches notations
+    assert_eq!(parse_height_inches("6'5\""), Some(77));
+    assert_eq!(parse_height_inches("6' 5"), Some(77));
+    assert_eq!(parse_height_inches("6'5''"), Some(77));
+    assert_eq!(parse_height_inches("6\u{2019}5\u{201D}"), Some(77));
+    assert_eq!(parse_height_inches("6ft 5in"), Some(77));
+    assert_eq!(parse_height_inches("6 feet 5 inches"), Some(77));
+    assert_eq!(parse_height_inches("7'"), Some(84));
+
+    // Metric heights round to the nearest inch
+    assert_eq!(parse_height_inches("196 cm"), Some(77));
+    assert_eq!(parse_height_inches("180CM"), Some(71));
+
+    // Anything ambiguous or malformed is rejected
+    assert_eq!(parse_height_inches(""), None);
+    assert_eq!(parse_height_inches("77"), None);
+    assert_eq!(parse_height_inches("6'13\""), None);
+    assert_eq!(parse_height_inches("tall"), None);
+    assert_eq!(parse_height_inches("-5 cm"), None);
+    assert_eq!(parse_height_inches("inf cm"), None);
+
+    // Feet large enough to overflow are rejected instead of panicking
+    assert_eq!(parse_height_inches("999999999'0"), None);
+}
+
+#[test]
+#[serial]
+fn test_wrestlers_by_height_range_skips_unparseable() {
+    let test_data = TestData::new();
+    let roster = [
+        ("Height Range Giant", "7'0\""),
+        ("Height Range Metric", "188 cm"),
+        ("Height Range Cruiser", "5'8\""),
+        ("Height Range Unknown", "Very tall"),
+    ];
+
+    // Cleanup any existing test data
+    for (name, _) in roster {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let mut ids = Vec::new();
+    for (name, height) in roster {
+        let wrestler = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create wrestler");
+        internal_update_wrestler_basic_stats(&mut conn, wrestler.id, Some(height.to_string()), None, None, 0, 0)
+            .expect("Failed to set height");
+        ids.push(wrestler.id);
+    }
+
+    // 6'0" to 7'0" covers the giant (84) and the metric wrestler (74)
+    let in_range: Vec<i32> = internal_get_wrestlers_by_height_range(&mut conn, 72, 84)
+        .expect("Failed to filter by height")
+        .iter()
+        .map(|w| w.id)
+        .filter(|id| ids.contains(id))
+        .collect();
+    assert_eq!(in_range, vec![ids[1], ids[0]]);
+
+    let inverted = internal_get_wrestlers_by_height_range(&mut conn, 84, 72);
+    assert!(matches!(inverted, Err(AppError::Validation(_))));
+
+    // Cleanup
+    for (name, _) in roster {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_frequent_opponents_skip_tag_partners() {
+    let test_data = TestData::new();
+    let show_name = "Rivalry Show";
+    let names = ["Rivalry Hero", "Rivalry Nemesis", "Rivalry Partner", "Rivalry Occasional"];
+
+    // Cleanup any existing test data
+    test_data.cleanup_shows(show_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let show = internal_create_show(&mut conn, show_name, "Rivalry testing").expect("Failed to create show");
+    let mut ids = Vec::new();
+    for name in names {
+        ids.push(
+            internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+                .expect("Failed to create wrestler")
+                .id,
+        );
+    }
+    let (hero, nemesis, partner, occasional) = (ids[0], ids[1], ids[2], ids[3]);
+
+    // Books a match of the given type with (wrestler, team) entries
+    let mut book = |match_type: &str, entries: &[(i32, Option<i32>)]| {
+        let booked = internal_create_match(&mut conn, &MatchData {
+            show_id: show.id,
+            match_name: Some("Rivalry Match".to_string()),
+            match_type: match_type.to_string(),
+            match_stipulation: None,
+            scheduled_date: None,
+            match_order: None,
+            is_title_match: false,
+            title_id: None,
+        }).expect("Failed to create match");
+        for (order, (wrestler_id, team)) in entries.iter().enumerate() {
+            internal_add_wrestler_to_match(&mut conn, booked.id, *wrestler_id, *team, Some(order as i32 + 1))
+                .expect("Failed to add wrestler to match");
+        }
+    };
+
+    book("Singles", &[(hero, None), (nemesis, None)]);
+    book("Singles", &[(hero, None), (nemesis, None)]);
+    book("Singles", &[(hero, None), (occasional, None)]);
+    // The partner is only ever on the hero's team
+    book("Tag Team", &[(hero, Some(1)), (partner, Some(1)), (nemesis, Some(2)), (occasional, Some(2))]);
+
+    let opponents: Vec<(i32, i64)> = internal_get_frequent_opponents(&mut conn, hero, 10)
+        .expect("Failed to find frequent opponents")
+        .iter()
+        .map(|(w, count)| (w.id, *count))
+        .collect();
+    assert_eq!(opponents, vec![(nemesis, 3), (occasional, 2)]);
+
+    let top = internal_get_frequent_opponents(&mut conn, hero, 1).expect("Failed to find frequent opponents");
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].0.id, nemesis);
+
+    // Cleanup
+    test_data.cleanup_shows(show_name);
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+}
+
+#[test]
+#[serial]
+fn test_wrestlers_above_rating_filters_on_charisma() {
+    let test_data = TestData::new();
+    let names = ["Scouting Showman", "Scouting Talker", "Scouting Quiet", "Scouting Unrated"];
+
+    // Cleanup any existing test data
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+
+    let mut conn = test_data.get_connection();
+    let mut ids = Vec::new();
+    for (name, charisma) in names.iter().zip([Some(10), Some(8), Some(5), None]) {
+        let wrestler = internal_create_wrestler(&mut conn, name, "Male", 0, 0)
+            .expect("Failed to create wrestler");
+        internal_update_wrestler_power_ratings(&mut conn, wrestler.id, Some(9), None, None, None, charisma, None)
+            .expect("Failed to rate wrestler");
+        ids.push(wrestler.id);
+    }
+
+    let scouted: Vec<i32> = internal_get_wrestlers_above_rating(&mut conn, "charisma", 8)
+        .expect("Failed to filter by charisma")
+        .iter()
+        .map(|wrestler| wrestler.id)
+        .filter(|id| ids.contains(id))
+        .collect();
+    assert_eq!(scouted, vec![ids[0], ids[1]]);
+
+    let invalid = internal_get_wrestlers_above_rating(&mut conn, "mic_skills", 8);
+    assert!(matches!(invalid, Err(AppError::Validation(_))));
+
+    // Cleanup
+    for name in names {
+        test_data.cleanup_wrestlers(name);
+    }
+}